@@ -9,7 +9,10 @@ use quote::quote;
 
 use syn::{punctuated::Punctuated, DeriveInput, Ident};
 
-use alloc::{string::ToString, vec::Vec};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use self::attribute_parsing::GenParamIgnorance;
 
@@ -115,6 +118,8 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> syn::Result<TokenStr
         comma_term: Ident::new("COMMA_TERM", Span::call_site()),
         ds,
         delimiters: &delimiters,
+        renames: &config.renames,
+        field_fmtargs: &config.field_fmtargs,
         match_prefix,
     };
 
@@ -238,6 +243,8 @@ struct ArgsForInherentImpl<'a> {
     comma_term: Ident,
     ds: &'a DataStructure<'a>,
     delimiters: &'a [TokenStream2],
+    renames: &'a [Option<String>],
+    field_fmtargs: &'a [Vec<Option<syn::Expr>>],
     match_prefix: TokenStream2,
 }
 
@@ -251,6 +258,8 @@ fn emit_inherent_impl(
         comma_term,
         ds,
         delimiters,
+        renames,
+        field_fmtargs,
         match_prefix,
     }: &ArgsForInherentImpl<'_>,
 ) -> TokenStream2 {
@@ -258,7 +267,10 @@ fn emit_inherent_impl(
 
     let branches = ds.variants.iter().enumerate().map(|(v_index, v)| {
         let vname = v.name;
-        let vsname = vname.to_string();
+        let vsname = match &renames[v_index] {
+            Some(rename) => rename.clone(),
+            None => vname.to_string(),
+        };
 
         if v.fields.is_empty() {
             quote!(
@@ -287,18 +299,26 @@ fn emit_inherent_impl(
                     let fname = ::alloc::format!("{}: ", f.ident);
 
                     quote!(
-                        &[__cp_bCj7dq3Pud::PanicVal::write_str(#fname)],
+                        &[__cp_bCj7dq3Pud::__::FieldName(#fname).to_panicval(fmtarg)],
                     )
                 } else {
                     TokenStream2::new()
                 };
 
+                // the field's `FmtArg` override keeps the layout of `fmtarg`
+                let field_fmtarg = match &field_fmtargs[v_index][f.index.pos] {
+                    Some(expr) => quote!(
+                        __cp_bCj7dq3Pud::__::merge_field_fmtarg(fmtarg, #expr)
+                    ),
+                    None => quote!(fmtarg),
+                };
+
                 quote!(
                     #field_name_colon
                     &__cp_bCj7dq3Pud::PanicFmt::PROOF
                         .infer(#field_patib)
                         .coerce(#field_patib)
-                        .to_panicvals(fmtarg),
+                        .to_panicvals(#field_fmtarg),
                     &__cp_bCj7dq3Pud::fmt::#comma
                         .to_panicvals(fmtarg),
                 )
@@ -310,8 +330,8 @@ fn emit_inherent_impl(
 
                     __cp_bCj7dq3Pud::__::flatten_panicvals::<{#get_pv_count}>(&[
                         &[
-                            __cp_bCj7dq3Pud::PanicVal::write_str(#vsname),
-                            open.to_panicval(fmtarg)
+                            __cp_bCj7dq3Pud::__::StructName(#vsname).to_panicval(fmtarg),
+                            __cp_bCj7dq3Pud::__::StructOpen(open).to_panicval(fmtarg)
                         ],
                         #( #field_fmt )*
                         &close.to_panicvals(fmtarg.unindent()),
@@ -321,6 +341,14 @@ fn emit_inherent_impl(
         }
     });
 
+    // references to empty enums are considered inhabited,
+    // so they can only be matched through a dereference
+    let matched = if ds.variants.is_empty() {
+        quote!(*self)
+    } else {
+        quote!(self)
+    };
+
     quote!(
         #impl_header
         {
@@ -330,7 +358,7 @@ fn emit_inherent_impl(
             ) -> [__cp_bCj7dq3Pud::PanicVal<'_>; #get_pv_count] {
                 fmtarg = fmtarg.indent();
 
-                match self {
+                match #matched {
                     #(#branches)*
                 }
             }
@@ -12,13 +12,15 @@ use syn::{
 
 use quote::{quote, ToTokens};
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use core::marker::PhantomData;
 
 mod keyword {
     syn::custom_keyword!(debug_print);
+    syn::custom_keyword!(fmtarg);
     syn::custom_keyword!(ignore);
+    syn::custom_keyword!(rename);
 }
 
 #[derive(Copy, Clone)]
@@ -34,6 +36,8 @@ struct ParsedAttributes<'a> {
     impls: Vec<ImplHeader>,
     gen_params_props: Vec<GenParamProps<'a>>,
     type_const_params: Vec<Ident>,
+    renames: Vec<Option<String>>,
+    field_fmtargs: Vec<Vec<Option<syn::Expr>>>,
     _marker: PhantomData<&'a ()>,
 }
 
@@ -42,6 +46,12 @@ pub(super) struct Configuration<'a> {
     pub(super) crate_path: syn::Path,
     pub(super) impls: Vec<ImplHeader>,
     pub(super) gen_params_props: Vec<GenParamProps<'a>>,
+    /// The name that's written for each variant (or for the struct),
+    /// `None` if it's not renamed.
+    pub(super) renames: Vec<Option<String>>,
+    /// The `FmtArg` expression that each field (indexed by variant, then position)
+    /// is formatted with, `None` if it uses the `FmtArg` passed to `to_panicvals`.
+    pub(super) field_fmtargs: Vec<Vec<Option<syn::Expr>>>,
     _marker: PhantomData<&'a ()>,
 }
 
@@ -76,6 +86,12 @@ pub(super) fn parse_attributes<'a>(ds: &'a DataStructure<'a>) -> syn::Result<Con
                 GenericParam::Const(x) => Some(x.ident.clone()),
             })
             .collect(),
+        renames: ds.variants.iter().map(|_| None).collect(),
+        field_fmtargs: ds
+            .variants
+            .iter()
+            .map(|v| v.fields.iter().map(|_| None).collect())
+            .collect(),
         _marker: PhantomData,
     };
 
@@ -186,6 +202,33 @@ fn parse_helper_attribute<'a>(
                 contents.parse::<syn::Token!(,)>()?;
             }
         }
+    } else if let Some(kw) = input.peek_parse(keyword::rename)? {
+        let index = match ctx {
+            ParseCtx::Container if ds.data_variant == DataVariant::Struct => 0,
+            ParseCtx::Variant(index, _) => index,
+            ParseCtx::Container | ParseCtx::Field(_) => {
+                return Err(syn::Error::new(
+                    kw.span,
+                    "Can only use this attribute above structs and enum variants",
+                ))
+            }
+        };
+
+        input.parse::<Token!(=)>()?;
+        this.renames[index] = Some(input.parse::<syn::LitStr>()?.value());
+    } else if let Some(kw) = input.peek_parse(keyword::fmtarg)? {
+        let index = match ctx {
+            ParseCtx::Field(f) => f.index,
+            ParseCtx::Container | ParseCtx::Variant(..) => {
+                return Err(syn::Error::new(
+                    kw.span,
+                    "Can only use this attribute above fields",
+                ))
+            }
+        };
+
+        input.parse::<Token!(=)>()?;
+        this.field_fmtargs[index.variant][index.pos] = Some(input.parse::<syn::Expr>()?);
     } else {
         let span = input.parse::<syn::Ident>()?.span();
         return Err(syn::Error::new(span, "Invalid attribute"));
@@ -203,6 +246,8 @@ fn finish<'a>(
         impls,
         gen_params_props,
         type_const_params: _,
+        renames,
+        field_fmtargs,
         _marker,
     } = this;
 
@@ -211,6 +256,8 @@ fn finish<'a>(
         crate_path,
         impls,
         gen_params_props,
+        renames,
+        field_fmtargs,
         _marker,
     })
 }
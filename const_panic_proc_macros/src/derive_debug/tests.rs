@@ -179,3 +179,119 @@ fn impl_attribute_typename_error() {
 
     assert!(err.consecutive_unspace(&["expected `Fooo`"]), "\n{}\n", err,);
 }
+
+#[test]
+fn rename_attribute() {
+    let s = process_str(
+        r#"
+        #[pfmt(rename = "Renamed")]
+        pub struct Fooo {
+            pub x: u32,
+        }
+    "#,
+    )
+    .unwrap();
+
+    assert!(
+        s.consecutive_unspace(&[r#"StructName("Renamed")"#]),
+        "\n{}\n",
+        s
+    );
+    assert!(!s.contains(r#""Fooo""#), "\n{}\n", s);
+
+    let s = process_str(
+        r#"
+        pub enum Fooo {
+            #[pfmt(rename = "unit")]
+            Unit,
+            #[pfmt(rename = "tuple")]
+            Tuple(u32),
+            Braced { x: u32 },
+        }
+    "#,
+    )
+    .unwrap();
+
+    assert!(
+        s.consecutive_unspace(&[
+            r#"write_str("unit")"#,
+            r#"StructName("tuple")"#,
+            r#"StructName("Braced")"#,
+        ]),
+        "\n{}\n",
+        s
+    );
+}
+
+#[test]
+fn rename_attribute_error() {
+    for case in [
+        r#"
+            #[pfmt(rename = "Bar")]
+            pub enum Fooo {
+                A,
+            }
+        "#,
+        r#"
+            pub struct Fooo {
+                #[pfmt(rename = "y")]
+                pub x: u32,
+            }
+        "#,
+    ] {
+        let err = process_str(case).unwrap_err();
+
+        assert!(
+            err.consecutive_unspace(&["above structs and enum variants"]),
+            "\n{}\n",
+            err,
+        );
+    }
+}
+
+#[test]
+fn fmtarg_attribute() {
+    let s = process_str(
+        r#"
+        pub struct Fooo {
+            #[pfmt(fmtarg = FMTS[0])]
+            pub x: u32,
+            pub y: u32,
+        }
+    "#,
+    )
+    .unwrap();
+
+    assert!(
+        s.consecutive_unspace(&[
+            "__cp_bCj7dq3Pud::__::merge_field_fmtarg(fmtarg, FMTS[0])",
+            r#"FieldName("y: ")"#,
+            ".to_panicvals(fmtarg),",
+            "COMMA_TERM",
+        ]),
+        "\n{}\n",
+        s
+    );
+}
+
+#[test]
+fn fmtarg_attribute_error() {
+    for case in [
+        r#"
+            #[pfmt(fmtarg = FmtArg::HEX)]
+            pub struct Fooo {
+                pub x: u32,
+            }
+        "#,
+        r#"
+            pub enum Fooo {
+                #[pfmt(fmtarg = FmtArg::HEX)]
+                A(u32),
+            }
+        "#,
+    ] {
+        let err = process_str(case).unwrap_err();
+
+        assert!(err.consecutive_unspace(&["above fields"]), "\n{}\n", err,);
+    }
+}
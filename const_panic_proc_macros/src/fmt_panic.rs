@@ -0,0 +1,312 @@
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+
+use quote::{format_ident, quote, quote_spanned};
+
+use syn::{
+    parse::{ParseStream, Parser},
+    Expr, Ident, LitStr, Token,
+};
+
+use alloc::{format, string::String, vec::Vec};
+
+#[cfg(test)]
+mod tests;
+
+// The parsed input of `fmt_panic`, which is passed as
+// `(<path to const_panic>) "format string", positional_args..., named = args...`
+struct FmtPanicInput {
+    crate_path: TokenStream2,
+    fmt_str: LitStr,
+    positional: Vec<Expr>,
+    named: Vec<(Ident, Expr)>,
+}
+
+// How an argument is referred to in the format string
+enum ArgRef {
+    Next,
+    Index(usize),
+    Name(String),
+}
+
+// The literal text before an argument of the format string,
+// `arg` is the argument and the formatting keyword passed to `concat_panic`.
+struct FmtStrPiece {
+    literal: String,
+    arg: Option<(ArgRef, Ident)>,
+}
+
+enum Piece {
+    Literal(String),
+    Arg { binding: Ident, keyword: Ident },
+}
+
+pub(crate) fn fmt_panic_impl(input: TokenStream2) -> syn::Result<TokenStream2> {
+    let FmtPanicInput {
+        crate_path,
+        fmt_str,
+        positional,
+        named,
+    } = parse_input.parse2(input)?;
+
+    let span = fmt_str.span();
+    let pieces = parse_fmt_str(&fmt_str.value(), span)?;
+
+    let mut next_arg = 0usize;
+    let mut used_positional = alloc::vec![false; positional.len()];
+    let mut used_named = alloc::vec![false; named.len()];
+    // variables captured from the surrounding scope, eg: `{foo}`
+    let mut captured = Vec::<Ident>::new();
+
+    let mut out_pieces = Vec::<Piece>::new();
+
+    for FmtStrPiece { literal, arg } in pieces {
+        if !literal.is_empty() {
+            out_pieces.push(Piece::Literal(literal));
+        }
+
+        let (arg_ref, keyword) = match arg {
+            Some(x) => x,
+            None => continue,
+        };
+
+        let index = match arg_ref {
+            ArgRef::Next => {
+                next_arg += 1;
+                next_arg - 1
+            }
+            ArgRef::Index(i) => i,
+            ArgRef::Name(name) => {
+                let binding = if let Some(pos) = named.iter().position(|(x, _)| *x == name) {
+                    used_named[pos] = true;
+                    format_ident!("__cp_named_{}", name, span = Span::call_site())
+                } else {
+                    let ident = syn::parse_str::<Ident>(&name)
+                        .map(|mut x| {
+                            x.set_span(span);
+                            x
+                        })
+                        .map_err(|_| {
+                            syn::Error::new(span, format!("invalid argument name: `{}`", name))
+                        })?;
+                    if !captured.contains(&ident) {
+                        captured.push(ident);
+                    }
+                    format_ident!("__cp_captured_{}", name, span = Span::call_site())
+                };
+
+                out_pieces.push(Piece::Arg { binding, keyword });
+                continue;
+            }
+        };
+
+        if index >= positional.len() {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "invalid reference to positional argument {} ({})",
+                    index,
+                    match positional.len() {
+                        0 => String::from("no arguments were given"),
+                        1 => String::from("there is 1 argument"),
+                        n => format!("there are {} arguments", n),
+                    },
+                ),
+            ));
+        }
+        used_positional[index] = true;
+        let binding = format_ident!("__cp_arg_{}", index);
+
+        out_pieces.push(Piece::Arg { binding, keyword });
+    }
+
+    let mut res = Ok(());
+    for (expr, used) in positional.iter().zip(&used_positional) {
+        if !used {
+            crate::utils::SynResultExt::combine_err(
+                &mut res,
+                Err::<(), _>(syn::Error::new_spanned(expr, "argument never used")),
+            );
+        }
+    }
+    for ((name, _), used) in named.iter().zip(&used_named) {
+        if !used {
+            crate::utils::SynResultExt::combine_err(
+                &mut res,
+                Err::<(), _>(syn::Error::new_spanned(name, "named argument never used")),
+            );
+        }
+    }
+    res?;
+
+    let exprs = positional
+        .iter()
+        .chain(named.iter().map(|(_, x)| x))
+        .map(|x| quote!(&(#x)))
+        .chain(captured.iter().map(|x| quote_spanned!(span=> &#x)));
+
+    let bindings = (0..positional.len())
+        .map(|i| format_ident!("__cp_arg_{}", i))
+        .chain(
+            named
+                .iter()
+                .map(|(x, _)| format_ident!("__cp_named_{}", x, span = Span::call_site())),
+        )
+        .chain(
+            captured
+                .iter()
+                .map(|x| format_ident!("__cp_captured_{}", x, span = Span::call_site())),
+        );
+
+    let out_pieces = out_pieces.iter().map(|piece| match piece {
+        Piece::Literal(lit) => {
+            let lit = LitStr::new(lit, span);
+            quote!(#lit,)
+        }
+        Piece::Arg { binding, keyword } => quote!(#keyword: *#binding,),
+    });
+
+    Ok(quote! {
+        match (#(#exprs,)*) {
+            (#(#bindings,)*) => #crate_path::concat_panic!(#(#out_pieces)*),
+        }
+    })
+}
+
+fn parse_input(input: ParseStream<'_>) -> syn::Result<FmtPanicInput> {
+    let crate_path = match input.parse::<TokenTree2>()? {
+        TokenTree2::Group(group) => group.stream(),
+        tt => {
+            return Err(syn::Error::new(
+                tt.span(),
+                "expected parenthesized crate path",
+            ))
+        }
+    };
+
+    let fmt_str = input.parse::<LitStr>()?;
+
+    let mut positional = Vec::new();
+    let mut named = Vec::<(Ident, Expr)>::new();
+
+    while !input.is_empty() {
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            break;
+        }
+
+        if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            let name = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            let expr = input.parse::<Expr>()?;
+
+            if named.iter().any(|(x, _)| *x == name) {
+                let msg = format!("duplicate argument named `{}`", name);
+                return Err(syn::Error::new_spanned(name, msg));
+            }
+
+            named.push((name, expr));
+        } else if !named.is_empty() {
+            return Err(input.error("positional arguments cannot follow named arguments"));
+        } else {
+            positional.push(input.parse::<Expr>()?);
+        }
+    }
+
+    Ok(FmtPanicInput {
+        crate_path,
+        fmt_str,
+        positional,
+        named,
+    })
+}
+
+// Splits the format string into the literal text before each argument,
+// and the argument (`None` for the text after the last argument).
+fn parse_fmt_str(fmt_str: &str, span: Span) -> syn::Result<Vec<FmtStrPiece>> {
+    let mut out = Vec::new();
+    let mut lit = String::new();
+    let mut chars = fmt_str.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                lit.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                lit.push('}');
+            }
+            '}' => {
+                return Err(syn::Error::new(
+                    span,
+                    "unmatched `}` found in format string, use `}}` to write a `}`",
+                ))
+            }
+            '{' => {
+                let mut arg = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => arg.push(c),
+                        None => {
+                            return Err(syn::Error::new(
+                                span,
+                                "unmatched `{` found in format string, use `{{` to write a `{`",
+                            ))
+                        }
+                    }
+                }
+
+                let (arg_ref, spec) = match arg.find(':') {
+                    Some(i) => (arg[..i].trim(), &arg[i + 1..]),
+                    None => (arg.trim(), ""),
+                };
+
+                let arg_ref = if arg_ref.is_empty() {
+                    ArgRef::Next
+                } else if let Ok(i) = arg_ref.parse::<usize>() {
+                    ArgRef::Index(i)
+                } else {
+                    ArgRef::Name(String::from(arg_ref))
+                };
+
+                let keyword = match spec {
+                    "" => "display",
+                    "#" => "alt_display",
+                    "?" => "debug",
+                    "#?" => "alt_debug",
+                    "b" => "bin",
+                    "#b" => "alt_bin",
+                    "X" => "hex",
+                    "#X" => "alt_hex",
+                    _ => {
+                        return Err(syn::Error::new(
+                            span,
+                            format!(
+                                "unsupported formatting: `{{:{}}}`, \
+                                 expected one of `{{}}`, `{{:#}}`, `{{:?}}`, `{{:#?}}`, \
+                                 `{{:b}}`, `{{:#b}}`, `{{:X}}`, or `{{:#X}}` \
+                                 (hexadecimal is always uppercase)",
+                                spec
+                            ),
+                        ))
+                    }
+                };
+
+                out.push(FmtStrPiece {
+                    literal: core::mem::take(&mut lit),
+                    arg: Some((arg_ref, Ident::new(keyword, span))),
+                });
+            }
+            c => lit.push(c),
+        }
+    }
+
+    out.push(FmtStrPiece {
+        literal: lit,
+        arg: None,
+    });
+
+    Ok(out)
+}
@@ -0,0 +1,80 @@
+use crate::test_utils::StrExt;
+
+use alloc::string::{String, ToString};
+
+fn process_str(s: &str) -> Result<String, String> {
+    syn::parse_str(s)
+        .and_then(crate::fmt_panic::fmt_panic_impl)
+        .map(|x| x.to_string())
+        .map_err(|e| e.to_compile_error().to_string())
+}
+
+#[test]
+fn positional_and_named_arguments() {
+    let s = process_str(r#"(::cpanic) "a{{{}}}b{1:?}c{foo:#X}d{bar}e{0:#?}", x, y + 1, foo = z,"#)
+        .unwrap();
+
+    assert!(
+        s.consecutive_unspace(&[
+            "match (&(x), &(y + 1), &(z), &bar,)",
+            "(__cp_arg_0, __cp_arg_1, __cp_named_foo, __cp_captured_bar,)",
+            "=> ::cpanic::concat_panic!(",
+            r#""a{", display: *__cp_arg_0, "}b", debug: *__cp_arg_1,"#,
+            r#""c", alt_hex: *__cp_named_foo, "d", display: *__cp_captured_bar,"#,
+            r#""e", alt_debug: *__cp_arg_0,"#,
+            ")",
+        ]),
+        "\n{}\n",
+        s,
+    );
+}
+
+#[test]
+fn no_arguments() {
+    let s = process_str(r#"(::cpanic) "hello {{world}}""#).unwrap();
+
+    assert!(
+        s.consecutive_unspace(&[
+            "match () {",
+            r#"() => ::cpanic::concat_panic!("hello {world}",)"#,
+        ]),
+        "\n{}\n",
+        s,
+    );
+}
+
+#[test]
+fn fmt_panic_errors() {
+    for (input, expected) in [
+        (
+            r#"(::cpanic) "{}""#,
+            "invalid reference to positional argument 0",
+        ),
+        (
+            r#"(::cpanic) "{1}", a"#,
+            "invalid reference to positional argument 1",
+        ),
+        (r#"(::cpanic) "{}", a, b"#, "argument never used"),
+        (r#"(::cpanic) "{}", a, b = 3"#, "named argument never used"),
+        (
+            r#"(::cpanic) "{b}", b = 3, c"#,
+            "positional arguments cannot follow",
+        ),
+        (
+            r#"(::cpanic) "{b}", b = 3, b = 4"#,
+            "duplicate argument named `b`",
+        ),
+        (r#"(::cpanic) "{""#, "unmatched `{`"),
+        (r#"(::cpanic) "}""#, "unmatched `}`"),
+        (r#"(::cpanic) "{:x}", a"#, "unsupported formatting: `{:x}`"),
+        (r#"(::cpanic) "{1a}", a"#, "invalid argument name: `1a`"),
+    ] {
+        let err = process_str(input).unwrap_err();
+        assert!(
+            err.consecutive_in_self(&[expected]),
+            "\n{}\n{}\n",
+            input,
+            err
+        );
+    }
+}
@@ -13,6 +13,8 @@ mod datastructure;
 
 mod derive_debug;
 
+mod fmt_panic;
+
 mod syntax;
 
 mod utils;
@@ -27,3 +29,11 @@ pub fn derive_const_debug(input: TokenStream1) -> TokenStream1 {
         .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __fmt_panic(input: TokenStream1) -> TokenStream1 {
+    fmt_panic::fmt_panic_impl(input.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
@@ -1,4 +1,4 @@
-use crate::{utils::RangedBytes, FmtArg, PanicFmt, PanicVal};
+use crate::{utils::RangedBytes, FmtArg, NotEnoughSpace, PanicFmt, PanicVal};
 
 use core::{
     cmp::PartialEq,
@@ -51,6 +51,26 @@ impl<const CAP: usize> ArrayString<CAP> {
         Self::concat(&[string])
     }
 
+    /// Constructs an `ArrayString` from a `&str`,
+    /// returning an error instead of panicking if it doesn't fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NotEnoughSpace` error if `string` is larger than `CAP`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{ArrayString, NotEnoughSpace};
+    ///
+    /// assert_eq!(ArrayString::<16>::try_new("Hello, world!").unwrap(), "Hello, world!");
+    ///
+    /// assert_eq!(ArrayString::<4>::try_new("Hello").unwrap_err(), NotEnoughSpace);
+    /// ```
+    pub const fn try_new(string: &str) -> Result<Self, NotEnoughSpace> {
+        Self::try_concat(&[string])
+    }
+
     /// Constructs an `ArrayString` by concatenating zero or more `&str`s
     ///
     /// # Panics
@@ -68,32 +88,103 @@ impl<const CAP: usize> ArrayString<CAP> {
     /// );
     /// ```
     pub const fn concat(strings: &[&str]) -> Self {
-        let mut len = 0u32;
-        let mut buffer = [0u8; CAP];
+        match Self::try_concat(strings) {
+            Ok(x) => x,
+            Err(NotEnoughSpace) => crate::concat_panic(&[&[
+                PanicVal::write_str("The input strings were longer than "),
+                PanicVal::from_usize(CAP, FmtArg::DISPLAY),
+                PanicVal::write_str(", concatenated length: "),
+                PanicVal::from_usize(add_up_lengths(strings), FmtArg::DISPLAY),
+                PanicVal::write_str(", strings: "),
+                PanicVal::from_slice_str(strings, FmtArg::DEBUG),
+            ]]),
+        }
+    }
+
+    /// Constructs an `ArrayString` by concatenating zero or more `&str`s,
+    /// returning an error instead of panicking if they don't fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NotEnoughSpace` error if the concatenated string
+    /// would be longer than `CAP`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{ArrayString, NotEnoughSpace};
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<16>::try_concat(&["foo", "bar", "baz"]).unwrap(),
+    ///     "foobarbaz",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<8>::try_concat(&["foo", "bar", "baz"]).unwrap_err(),
+    ///     NotEnoughSpace,
+    /// );
+    /// ```
+    pub const fn try_concat(strings: &[&str]) -> Result<Self, NotEnoughSpace> {
+        let this = Self {
+            len: 0,
+            buffer: [0u8; CAP],
+        };
+        this.try_extend(strings)
+    }
+
+    /// Appends `string` to the end of this `ArrayString`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NotEnoughSpace` error if the resulting string
+    /// would be longer than `CAP`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{ArrayString, NotEnoughSpace};
+    ///
+    /// const fn make_url(host: &str, path: &str) -> Result<ArrayString<32>, NotEnoughSpace> {
+    ///     let string = ArrayString::new("https://");
+    ///
+    ///     // `?` can't be used in const fns
+    ///     let string = match string.try_push_str(host) {
+    ///         Ok(x) => x,
+    ///         Err(e) => return Err(e),
+    ///     };
+    ///
+    ///     string.try_push_str(path)
+    /// }
+    ///
+    /// assert_eq!(make_url("example.com", "/foo").unwrap(), "https://example.com/foo");
+    ///
+    /// assert_eq!(
+    ///     make_url("example.com", "/a_path_that_is_too_long").unwrap_err(),
+    ///     NotEnoughSpace,
+    /// );
+    /// ```
+    pub const fn try_push_str(self, string: &str) -> Result<Self, NotEnoughSpace> {
+        self.try_extend(&[string])
+    }
+
+    const fn try_extend(mut self, strings: &[&str]) -> Result<Self, NotEnoughSpace> {
+        let new_len = self.len as usize + add_up_lengths(strings);
+        if new_len > CAP || new_len > u32::MAX as usize {
+            return Err(NotEnoughSpace);
+        }
 
-        let mut mstrings = strings;
-        while let [string, ref rem @ ..] = *mstrings {
-            mstrings = rem;
+        let mut strings = strings;
+        while let [string, ref rem @ ..] = *strings {
+            strings = rem;
             let mut bytes = string.as_bytes();
             while let [x, ref rem @ ..] = *bytes {
-                if len == u32::MAX || len as usize >= CAP {
-                    crate::concat_panic(&[&[
-                        PanicVal::write_str("The input strings were longer than "),
-                        PanicVal::from_usize(CAP, FmtArg::DISPLAY),
-                        PanicVal::write_str(", concatenated length: "),
-                        PanicVal::from_usize(add_up_lengths(strings), FmtArg::DISPLAY),
-                        PanicVal::write_str(", strings: "),
-                        PanicVal::from_slice_str(strings, FmtArg::DEBUG),
-                    ]])
-                }
-
                 bytes = rem;
-                buffer[len as usize] = x;
-                len += 1;
+                self.buffer[self.len as usize] = x;
+                self.len += 1;
             }
         }
 
-        Self { len, buffer }
+        Ok(self)
     }
 
     /// Constructs this string from a `&[&[PanicVal<'_>]]`.
@@ -172,6 +263,81 @@ impl<const CAP: usize> ArrayString<CAP> {
         self.len as usize
     }
 
+    /// How many lines the string has,
+    /// counting the same as [`str::lines`] does.
+    ///
+    /// A trailing line terminator doesn't start a new line,
+    /// and an empty string has no lines.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::ArrayString;
+    ///
+    /// assert_eq!(ArrayString::<16>::new("").line_count(), 0);
+    /// assert_eq!(ArrayString::<16>::new("foo").line_count(), 1);
+    /// assert_eq!(ArrayString::<16>::new("foo\nbar\nbaz").line_count(), 3);
+    /// assert_eq!(ArrayString::<16>::new("foo\nbar\n").line_count(), 2);
+    /// assert_eq!(ArrayString::<16>::new("foo\n\nbar").line_count(), 3);
+    /// ```
+    pub const fn line_count(&self) -> usize {
+        let len = self.len();
+        let mut count = 0;
+        let mut i = 0;
+        while i < len {
+            if self.buffer[i] == b'\n' {
+                count += 1;
+            }
+            i += 1;
+        }
+
+        if len != 0 && self.buffer[len - 1] != b'\n' {
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Replaces all occurrences of the `from` ASCII byte with the `to` ASCII byte.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `from` or `to` are not ASCII.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{ArrayString, FmtArg, flatten_panicvals};
+    ///
+    /// let ident = ArrayString::<32>::from_panicvals(
+    ///     &flatten_panicvals!(FmtArg::DISPLAY; "field of ", 3u8, " bytes")
+    /// ).unwrap();
+    ///
+    /// assert_eq!(ident.replace_ascii(b' ', b'_'), "field_of_3_bytes");
+    /// ```
+    #[track_caller]
+    pub const fn replace_ascii(mut self, from: u8, to: u8) -> Self {
+        if !from.is_ascii() || !to.is_ascii() {
+            crate::concat_panic(&[&[
+                PanicVal::write_str("expected ASCII bytes, found from: "),
+                PanicVal::from_u8(from, FmtArg::DEBUG),
+                PanicVal::write_str(", to: "),
+                PanicVal::from_u8(to, FmtArg::DEBUG),
+            ]]);
+        }
+
+        let len = self.len();
+        let mut i = 0;
+        while i < len {
+            if self.buffer[i] == from {
+                self.buffer[i] = to;
+            }
+            i += 1;
+        }
+
+        self
+    }
+
     /// Accesses the string as a byte slice.
     ///
     /// # Performance
@@ -1,13 +1,18 @@
 use crate::{
-    fmt::FmtKind,
+    fmt::{FmtArg, FmtKind},
     panic_val::{PanicClass, PanicVal, StrFmt},
-    utils::{string_cap, WasTruncated},
+    utils::{string_cap, RangedBytes, WasTruncated},
 };
 
 /// Panics by concatenating the argument slice.
 ///
 /// This is the function that the [`concat_panic`](macro@concat_panic) macro calls to panic.
 ///
+/// This function (and the macros that call it) can't save the panic message
+/// to a crash buffer, since there's no way to register one that a `const fn` can read.
+/// Runtime code that needs the message saved must call
+/// [`concat_panic_with_crash_buffer`] instead.
+///
 /// # Example
 ///
 /// Here's how to panic with formatting without using any macros:
@@ -44,16 +49,230 @@ use crate::{
 #[inline(never)]
 #[track_caller]
 pub const fn concat_panic(args: &[&[PanicVal<'_>]]) -> ! {
-    // The panic message capacity starts small and gets larger each time,
+    concat_panic_bounded::<MAX_PANIC_MSG_LEN>(args)
+}
+
+/// Panics by concatenating a single slice of [`PanicVal`]s.
+///
+/// This is like [`concat_panic`](fn@concat_panic),
+/// for when the arguments are already in one slice,
+/// eg: the array returned by a helper function that builds a message.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::{FmtArg, PanicVal, concat_panic_slice};
+///
+/// const fn out_of_bounds(index: usize, len: usize) -> [PanicVal<'static>; 4] {
+///     [
+///         PanicVal::write_str("index out of bounds: the len is "),
+///         PanicVal::from_usize(len, FmtArg::DISPLAY),
+///         PanicVal::write_str(" but the index is "),
+///         PanicVal::from_usize(index, FmtArg::DISPLAY),
+///     ]
+/// }
+///
+/// const _: () = concat_panic_slice(&out_of_bounds(5, 3));
+/// ```
+/// That fails to compile with this error message:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///   --> src/concat_panic_.rs:13:15
+///    |
+/// 13 | const _: () = concat_panic_slice(&out_of_bounds(5, 3));
+///    |               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'index out of bounds: the len is 3 but the index is 5', src/concat_panic_.rs:13:15
+/// ```
+///
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub const fn concat_panic_slice(args: &[PanicVal<'_>]) -> ! {
+    concat_panic(&[args])
+}
+
+/// Panics by concatenating the argument slice,
+/// truncating the message if it's longer than `MAX` bytes.
+///
+/// This is like [`concat_panic`](fn@concat_panic),
+/// but with a custom maximum message length instead of [`MAX_PANIC_MSG_LEN`].
+/// <br>A smaller `MAX` is useful for messages that should stay short,
+/// and a larger one for messages that could be longer than `MAX_PANIC_MSG_LEN`.
+///
+/// Truncated messages end with [`TRUNCATION_MARKER`], which counts towards `MAX`.
+///
+/// # Panics
+///
+/// Aside from the intended panic,
+/// this panics with a different message if `MAX` is smaller than
+/// the length of `TRUNCATION_MARKER`.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::{FmtArg, PanicVal, concat_panic_bounded};
+///
+/// const _: () = concat_panic_bounded::<24>(&[&[
+///     PanicVal::write_str("the value is "),
+///     PanicVal::from_u64(12345678901234, FmtArg::DISPLAY),
+/// ]]);
+/// ```
+/// That fails to compile with this error message:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/concat_panic_.rs:5:15
+///   |
+/// 5 |   const _: () = concat_panic_bounded::<24>(&[&[
+///   |  _______________^
+/// 6 | |     PanicVal::write_str("the value is "),
+/// 7 | |     PanicVal::from_u64(12345678901234, FmtArg::DISPLAY),
+/// 8 | | ]]);
+///   | |___^ the evaluated program panicked at 'the value ...[truncated]', src/concat_panic_.rs:5:15
+/// ```
+///
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub const fn concat_panic_bounded<const MAX: usize>(args: &[&[PanicVal<'_>]]) -> ! {
+    if MAX < TRUNCATION_MARKER.len() {
+        concat_panic(&[&[
+            PanicVal::write_str("the `MAX` argument of `concat_panic_bounded` must be at least "),
+            PanicVal::from_usize(TRUNCATION_MARKER.len(), FmtArg::DISPLAY),
+        ]])
+    }
+
+    panic_joined_bounded::<MAX>(TRUNCATION_MARKER, &[], args)
+}
+
+/// Panics by concatenating the argument slice,
+/// truncating the message to `MAX` bytes, ending it with `marker` if it's truncated.
+///
+/// This is like [`concat_panic_bounded`],
+/// but with a custom truncation marker instead of [`TRUNCATION_MARKER`].
+///
+/// The `marker` counts towards `MAX`.
+///
+/// # Panics
+///
+/// Aside from the intended panic,
+/// this panics with a different message if `MAX` is smaller than
+/// the length of `marker`.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::{FmtArg, PanicVal, concat_panic_bounded_with_marker};
+///
+/// const _: () = concat_panic_bounded_with_marker::<16>("...", &[&[
+///     PanicVal::write_str("the value is "),
+///     PanicVal::from_u64(12345678901234, FmtArg::DISPLAY),
+/// ]]);
+/// ```
+/// That fails to compile with this error message:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/concat_panic_.rs:5:15
+///   |
+/// 5 |   const _: () = concat_panic_bounded_with_marker::<16>("...", &[&[
+///   |  _______________^
+/// 6 | |     PanicVal::write_str("the value is "),
+/// 7 | |     PanicVal::from_u64(12345678901234, FmtArg::DISPLAY),
+/// 8 | | ]]);
+///   | |___^ the evaluated program panicked at 'the value is ...', src/concat_panic_.rs:5:15
+/// ```
+///
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub const fn concat_panic_bounded_with_marker<const MAX: usize>(
+    marker: &str,
+    args: &[&[PanicVal<'_>]],
+) -> ! {
+    if MAX < marker.len() {
+        panic!(
+            "the `MAX` argument of `concat_panic_bounded_with_marker` \
+             must be at least the length of `marker`"
+        )
+    }
+
+    panic_joined_bounded::<MAX>(marker, &[], args)
+}
+
+/// Panics by concatenating the `groups`, writing `separator` between each of them.
+///
+/// This is like [`concat_panic`](fn@concat_panic),
+/// for reporting multiple diagnostics at once,
+/// eg: writing each group on its own line with a `"\n"` separator.
+///
+/// The separator isn't written before the first group, nor after the last one.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::{FmtArg, PanicVal, concat_panic_joined};
+///
+/// const _: () = concat_panic_joined(&[PanicVal::write_str("\n")], &[
+///     &[PanicVal::write_str("port 0 is reserved")],
+///     &[
+///         PanicVal::write_str("port "),
+///         PanicVal::from_u32(70000, FmtArg::DISPLAY),
+///         PanicVal::write_str(" is out of range"),
+///     ],
+/// ]);
+/// ```
+/// That fails to compile with this error message:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/concat_panic_.rs:5:15
+///   |
+/// 5 |   const _: () = concat_panic_joined(&[PanicVal::write_str("\n")], &[
+///   |  _______________^
+/// 6 | |     &[PanicVal::write_str("port 0 is reserved")],
+/// 7 | |     &[
+/// 8 | |         PanicVal::write_str("port "),
+/// ...  |
+/// 11| |     ],
+/// 12| | ]);
+///   | |__^ the evaluated program panicked at 'port 0 is reserved
+/// port 70000 is out of range', src/concat_panic_.rs:5:15
+/// ```
+///
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub const fn concat_panic_joined(separator: &[PanicVal<'_>], groups: &[&[PanicVal<'_>]]) -> ! {
+    panic_joined_bounded::<MAX_PANIC_MSG_LEN>(TRUNCATION_MARKER, separator, groups)
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+const fn panic_joined_bounded<const MAX: usize>(
+    marker: &str,
+    separator: &[PanicVal<'_>],
+    args: &[&[PanicVal<'_>]],
+) -> ! {
+    // The panic message is first formatted into a small buffer,
     // so that platforms with smaller stacks can call this at runtime.
+    // Given that most(?) panic messages are smaller than 1024 bytes long,
+    // this only formats the message once in the common case.
     //
-    // Also, given that most(?) panic messages are smaller than 1024 bytes long,
-    // it's not going to be any less efficient in the common case.
-    if let Err(_) = panic_inner::<1024>(args) {}
+    // Longer messages have their length computed,
+    // to format them only once more, into the smallest capacity that they fit in.
+    //
+    // The "small_stack" feature skips the intermediate capacity,
+    // going straight from 1024 bytes to `MAX`.
+    if MAX > 1024 {
+        if let Err(_) = panic_inner::<1024, MAX>(marker, separator, args) {}
 
-    if let Err(_) = panic_inner::<{ 1024 * 6 }>(args) {}
+        if MAX > 1024 * 6
+            && !cfg!(feature = "small_stack")
+            && compute_joined_length(separator, args) <= 1024 * 6
+        {
+            if let Err(_) = panic_inner::<{ 1024 * 6 }, MAX>(marker, separator, args) {}
+        }
+    }
 
-    match panic_inner::<MAX_PANIC_MSG_LEN>(args) {
+    match panic_inner::<MAX, MAX>(marker, separator, args) {
         Ok(x) => match x {},
         Err(_) => panic!(
             "\
@@ -67,14 +286,29 @@ pub const fn concat_panic(args: &[&[PanicVal<'_>]]) -> ! {
 
 /// The maximum length of panic messages (in bytes),
 /// after which the message is truncated.
-// this should probably be smaller on platforms where this
-// const fn is called at runtime, and the stack is finy.
-pub const MAX_PANIC_MSG_LEN: usize = 32768;
+///
+/// Truncated panic messages end with [`TRUNCATION_MARKER`],
+/// which counts towards this length.
+///
+/// This is `32768` by default, and `512` with the `"small_stack"` feature,
+/// because [`concat_panic`](fn@concat_panic) can be called at runtime,
+/// where it stack-allocates a buffer of up to this length.
+pub const MAX_PANIC_MSG_LEN: usize = if cfg!(feature = "small_stack") {
+    512
+} else {
+    32768
+};
+
+/// The text that panic messages end with when they're truncated for being longer than
+/// [`MAX_PANIC_MSG_LEN`]
+/// (or the `MAX` argument of [`concat_panic_bounded`]).
+pub const TRUNCATION_MARKER: &str = "...[truncated]";
 
 // writes a single PanicVal to an array
 macro_rules! write_panicval {
     (
         $outer_label:lifetime,
+        $was_truncated:ident,
         $mout:ident, $lout:ident, $tct:expr,
         (
             $len:expr,
@@ -90,8 +324,12 @@ macro_rules! write_panicval {
         let StrFmt {
             leftpad: mut lpad,
             rightpad: mut rpad,
+            pad_byte,
             fmt_kind,
+            flags: _,
         } = strfmt;
+        let escape_control = strfmt.escape_control();
+        let quote = strfmt.quote();
 
         let ranged = match class {
             PanicClass::PreFmt(str) => str,
@@ -104,6 +342,7 @@ macro_rules! write_panicval {
                     $lout.ranged()
                 }
             }
+            PanicClass::Wrapped(_) => unreachable!(),
             #[cfg(feature = "non_basic")]
             PanicClass::Slice(_) => unreachable!(),
         };
@@ -111,45 +350,58 @@ macro_rules! write_panicval {
         let trunc_end = ranged.start + was_truncated.get_length(ranged.len());
 
         while lpad != 0 {
-            $write_buffer! {b' '}
+            $write_buffer! {pad_byte}
             lpad -= 1;
         }
 
-        if let FmtKind::Display = fmt_kind {
+        let is_debug = matches!(fmt_kind, FmtKind::Debug);
+
+        if !is_debug && !escape_control {
             let mut i = ranged.start;
             while i < trunc_end {
                 $write_buffer! {ranged.bytes[i]}
                 i += 1;
             }
         } else if rem_space != 0 {
-            $write_buffer! {b'"'}
-            let mut i = 0;
+            if is_debug {
+                $write_buffer! {quote}
+            }
+            let mut i = ranged.start;
             while i < trunc_end {
                 use crate::debug_str_fmt::{hex_as_ascii, ForEscaping};
 
                 let c = ranged.bytes[i];
                 let mut written_c = c;
-                if ForEscaping::is_escaped(c) {
+                let is_escaped = if is_debug {
+                    ForEscaping::is_escaped(c, quote)
+                } else {
+                    ForEscaping::is_control(c)
+                };
+                if is_escaped {
                     $write_buffer! {b'\\'}
                     if ForEscaping::is_backslash_escaped(c) {
                         written_c = ForEscaping::get_backslash_escape(c);
                     } else {
-                        $write_buffer! {b'x'}
-                        $write_buffer! {hex_as_ascii(c >> 4)}
-                        written_c = hex_as_ascii(c & 0b1111);
+                        $write_buffer! {b'u'}
+                        $write_buffer! {b'{'}
+                        if c >= 0x10 {
+                            $write_buffer! {hex_as_ascii(c >> 4)}
+                        }
+                        $write_buffer! {hex_as_ascii(c & 0b1111)}
+                        written_c = b'}';
                     };
                 }
                 $write_buffer! {written_c}
 
                 i += 1;
             }
-            if let WasTruncated::No = was_truncated {
-                $write_buffer_checked! {b'"'}
+            if let (true, WasTruncated::No) = (is_debug, was_truncated) {
+                $write_buffer_checked! {quote}
             }
         }
 
         while rpad != 0 {
-            $write_buffer! {b' '}
+            $write_buffer! {pad_byte}
             rpad -= 1;
         }
 
@@ -157,6 +409,7 @@ macro_rules! write_panicval {
             if $capacity < $max_capacity {
                 return $not_enough_space;
             } else {
+                $was_truncated = true;
                 break $outer_label;
             }
         }
@@ -166,22 +419,64 @@ macro_rules! write_panicval {
 macro_rules! write_to_buffer_inner {
     (
         $args:ident
+        [$separator:expr]
         (
             $len:expr,
             $capacity:expr,
             $($_rem:tt)*
         )
         $wptb_args:tt
-    ) => {
+    ) => {{
         let mut args = $args;
+        let separator: &[PanicVal<'_>] = $separator;
+        // whether the separator is written before the next slice in `args`
+        let mut at_separator = false;
 
         let mut mout;
         let mut lout;
 
-        'outer: while let [mut outer, ref nargs @ ..] = args {
+        let mut was_truncated = false;
+
+        'outer: while let [group, ref nargs @ ..] = args {
+            let mut outer = if at_separator { separator } else { group };
+
             while let [arg, nouter @ ..] = outer {
+                // skipping the empty strings that pad out `to_panicvals` arrays
+                if arg.is_elided() {
+                    outer = nouter;
+                    continue;
+                }
+
                 let tct = arg.to_class_truncated($capacity - $len);
                 match tct.1 {
+                    PanicClass::Wrapped(wrapped) => {
+                        let mut wrapped = wrapped;
+
+                        'wrapped: loop {
+                            let (line, newline, nwrapped) = wrapped.next();
+
+                            let line_trunc = crate::utils::truncated_str_len(line, $capacity - $len);
+                            let tct = (StrFmt::DISPLAY, PanicClass::PreFmt(line), line_trunc);
+                            write_panicval! {'outer, was_truncated, mout, lout, tct, $wptb_args}
+
+                            if newline {
+                                let newline = RangedBytes {
+                                    start: 0,
+                                    end: 1,
+                                    bytes: "\n".as_bytes(),
+                                };
+                                let newline_trunc =
+                                    crate::utils::truncated_str_len(newline, $capacity - $len);
+                                let tct = (StrFmt::DISPLAY, PanicClass::PreFmt(newline), newline_trunc);
+                                write_panicval! {'outer, was_truncated, mout, lout, tct, $wptb_args}
+                            }
+
+                            match nwrapped {
+                                Some(x) => wrapped = x,
+                                None => break 'wrapped,
+                            }
+                        }
+                    }
                     #[cfg(feature = "non_basic")]
                     #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
                     PanicClass::Slice(slice) => {
@@ -193,7 +488,34 @@ macro_rules! write_to_buffer_inner {
                             let mut two_args: &[_] = &two_args;
                             while let [arg, ntwo_args @ ..] = two_args {
                                 let tct = arg.to_class_truncated($capacity - $len);
-                                write_panicval! {'outer, mout, lout, tct, $wptb_args}
+                                match tct.1 {
+                                    // the elements of nested slices,
+                                    // which are themselves slices of non-slice values.
+                                    PanicClass::Slice(inner_slice) => {
+                                        let mut inner_iter = inner_slice.iter();
+
+                                        'inner_iter: loop {
+                                            let (inner_args, ninner_iter) = inner_iter.next();
+
+                                            let mut inner_args: &[_] = &inner_args;
+                                            while let [arg, ninner_args @ ..] = inner_args {
+                                                let tct = arg.to_class_truncated($capacity - $len);
+                                                write_panicval! {
+                                                    'outer, was_truncated, mout, lout, tct, $wptb_args
+                                                }
+                                                inner_args = ninner_args;
+                                            }
+
+                                            match ninner_iter {
+                                                Some(x) => inner_iter = x,
+                                                None => break 'inner_iter,
+                                            }
+                                        }
+                                    }
+                                    _ => {
+                                        write_panicval! {'outer, was_truncated, mout, lout, tct, $wptb_args}
+                                    }
+                                }
                                 two_args = ntwo_args;
                             }
 
@@ -204,21 +526,33 @@ macro_rules! write_to_buffer_inner {
                         }
                     }
                     _ => {
-                        write_panicval! {'outer, mout, lout, tct, $wptb_args}
+                        write_panicval! {'outer, was_truncated, mout, lout, tct, $wptb_args}
                     }
                 }
 
                 outer = nouter;
             }
-            args = nargs;
+
+            if at_separator {
+                at_separator = false;
+            } else {
+                args = nargs;
+                at_separator = !separator.is_empty() && !nargs.is_empty();
+            }
         }
-    };
+
+        was_truncated
+    }};
 }
 
 macro_rules! write_to_buffer {
     ($args:ident $wptb_args:tt) => {
+        write_to_buffer! {$args [&[]] $wptb_args}
+    };
+    ($args:ident [$separator:expr] $wptb_args:tt) => {
         write_to_buffer_inner! {
             $args
+            [$separator]
             $wptb_args
             $wptb_args
         }
@@ -243,18 +577,46 @@ macro_rules! make_buffer_writer_macros {
 #[cold]
 #[inline(never)]
 #[track_caller]
-const fn panic_inner<const LEN: usize>(args: &[&[PanicVal<'_>]]) -> Result<Never, NotEnoughSpace> {
+const fn panic_inner<const LEN: usize, const MAX: usize>(
+    marker: &str,
+    separator: &[PanicVal<'_>],
+    args: &[&[PanicVal<'_>]],
+) -> Result<Never, NotEnoughSpace> {
     let mut buffer = [0u8; LEN];
     let mut len = 0usize;
 
     make_buffer_writer_macros! {buffer, len}
 
-    write_to_buffer! {
+    let was_truncated = write_to_buffer! {
         args
+        [separator]
         (
-            len, LEN, MAX_PANIC_MSG_LEN, Err(NotEnoughSpace),
+            len, LEN, MAX, Err(NotEnoughSpace),
             write_buffer, write_buffer_checked,
         )
+    };
+
+    // The message is only truncated when it doesn't fit in `MAX` bytes,
+    // the end of it is then overwritten with `marker`.
+    if was_truncated {
+        let old_len = len;
+
+        len = crate::utils::min_usize(len, LEN - marker.len());
+        while len != 0 && len != LEN && !crate::utils::is_char_boundary(buffer[len]) {
+            len -= 1;
+        }
+
+        let mut marker = marker.as_bytes();
+        while let [b, ref rem @ ..] = *marker {
+            write_buffer! {b}
+            marker = rem;
+        }
+
+        let mut i = len;
+        while i < old_len {
+            buffer[i] = 0;
+            i += 1;
+        }
     }
 
     unsafe {
@@ -263,11 +625,330 @@ const fn panic_inner<const LEN: usize>(args: &[&[PanicVal<'_>]]) -> Result<Never
     }
 }
 
-#[doc(hidden)]
-#[derive(Debug)]
+/// Panics by concatenating the argument slice,
+/// first writing the message into `crash_buffer`.
+///
+/// This is for persisting the panic message somewhere that survives the panic,
+/// eg: a static buffer in battery-backed RAM that's read back after a reset.
+///
+/// This function is not a `const fn`, it can only be called at runtime.
+/// Because [`concat_panic`](fn@concat_panic) and the panicking macros
+/// must be callable in const contexts,
+/// they can't be made to write to a buffer registered at runtime,
+/// so this function is a separate entry point that must be called explicitly.
+/// Panics from other functions and macros in this crate don't write to any crash buffer,
+/// so persisting the message of existing panics requires switching those call sites
+/// to this function.
+///
+/// # Ordering
+///
+/// The message is fully written to `crash_buffer` before the panic starts,
+/// so it's already there when the panic handler (or panic hook) runs.
+///
+/// # Buffer contents
+///
+/// `crash_buffer` is a plain buffer that holds only the last panic message,
+/// it's not a ring buffer of the messages of previous panics.
+/// The message is written from the start of `crash_buffer`,
+/// and the bytes after it are zeroed, so that the message's end can be found
+/// even if a longer message was written to the same buffer before.
+///
+/// If the message is longer than `crash_buffer`, the saved message is truncated
+/// (on a `char` boundary, without [`TRUNCATION_MARKER`]),
+/// this doesn't affect the message that this panics with.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{concat_panic_with_crash_buffer, FmtArg, PanicVal};
+///
+/// let mut crash_buffer = [0xFFu8; 32];
+///
+/// let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+///     concat_panic_with_crash_buffer(&mut crash_buffer, &[&[
+///         PanicVal::write_str("sensor failed: "),
+///         PanicVal::from_u8(3, FmtArg::DISPLAY),
+///     ]])
+/// }));
+/// assert!(res.is_err());
+///
+/// assert_eq!(&crash_buffer[..16], b"sensor failed: 3");
+/// assert!(crash_buffer[16..].iter().all(|&b| b == 0));
+/// ```
+///
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn concat_panic_with_crash_buffer(crash_buffer: &mut [u8], args: &[&[PanicVal<'_>]]) -> ! {
+    let FormatOutcome { len, .. } = format_into_truncated(crash_buffer, args);
+
+    for b in &mut crash_buffer[len..] {
+        *b = 0;
+    }
+
+    concat_panic(args)
+}
+
+/// Formats the argument slice into `buffer`, returning the length of the message.
+///
+/// This is for when the caller already owns a buffer to write the message into,
+/// avoiding the stack-allocated buffers of
+/// [`try_concat_string`](crate::try_concat_string) and [`concat_panic`](fn@concat_panic).
+///
+/// This function is not a `const fn`, it can only be called at runtime.
+///
+/// # Errors
+///
+/// Returns `Err(NotEnoughSpace)` if the formatted arguments are longer than `buffer`,
+/// leaving the part of the message that was written in `buffer`.
+/// [`format_into_truncated`] can be used to truncate the message instead.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{format_into, FmtArg, NotEnoughSpace, PanicVal};
+///
+/// let args: &[&[PanicVal<'_>]] = &[&[
+///     PanicVal::write_str("voltage: "),
+///     PanicVal::from_u16(3300, FmtArg::DISPLAY),
+///     PanicVal::write_str("mV"),
+/// ]];
+///
+/// let mut buffer = [0u8; 32];
+///
+/// let len = format_into(&mut buffer, args).unwrap();
+/// assert_eq!(&buffer[..len], b"voltage: 3300mV");
+///
+/// assert_eq!(format_into(&mut buffer[..8], args), Err(NotEnoughSpace));
+/// ```
+pub fn format_into(buffer: &mut [u8], args: &[&[PanicVal<'_>]]) -> Result<usize, NotEnoughSpace> {
+    let capacity = buffer.len();
+    let mut len = 0usize;
+
+    make_buffer_writer_macros! {buffer, len}
+
+    write_to_buffer! {
+        args
+        (
+            len, capacity, capacity + 1, Err(NotEnoughSpace),
+            write_buffer, write_buffer_checked,
+        )
+    };
+
+    Ok(len)
+}
+
+/// Formats the argument slice into `buffer`, truncating the message to the buffer's length.
+///
+/// Unlike [`format_into`], this never fails,
+/// returning whether the message was truncated along with its length.
+/// Strings are truncated on a `char` boundary,
+/// while integers are either fully written or omitted.
+///
+/// This function is not a `const fn`, it can only be called at runtime.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{format_into_truncated, FmtArg, FormatOutcome, PanicVal};
+///
+/// let args: &[&[PanicVal<'_>]] = &[&[
+///     PanicVal::write_str("voltage: "),
+///     PanicVal::from_u16(3300, FmtArg::DISPLAY),
+///     PanicVal::write_str("mV"),
+/// ]];
+///
+/// let mut buffer = [0u8; 32];
+///
+/// let outcome = format_into_truncated(&mut buffer, args);
+/// assert_eq!(outcome, FormatOutcome { len: 15, truncated: false });
+/// assert_eq!(&buffer[..outcome.len], b"voltage: 3300mV");
+///
+/// let outcome = format_into_truncated(&mut buffer[..12], args);
+/// assert_eq!(outcome, FormatOutcome { len: 9, truncated: true });
+/// assert_eq!(&buffer[..outcome.len], b"voltage: ");
+/// ```
+pub fn format_into_truncated(buffer: &mut [u8], args: &[&[PanicVal<'_>]]) -> FormatOutcome {
+    let capacity = buffer.len();
+    let mut len = 0usize;
+
+    make_buffer_writer_macros! {buffer, len}
+
+    // `capacity == max_capacity` truncates the message instead of returning an error
+    let truncated = write_to_buffer! {
+        args
+        (
+            len, capacity, capacity, FormatOutcome { len, truncated: true },
+            write_buffer, write_buffer_checked,
+        )
+    };
+
+    FormatOutcome { len, truncated }
+}
+
+/// The length of a formatted message, and whether it was truncated.
+///
+/// Returned by [`format_into_truncated`](crate::format_into_truncated).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FormatOutcome {
+    /// The length of the written message, in bytes.
+    pub len: usize,
+    /// Whether the message was truncated to fit in the buffer.
+    pub truncated: bool,
+}
+
+/// Error returned when the formatted arguments don't fit in the passed-in capacity.
+///
+/// Returned by [`try_concat_string`](crate::try_concat_string),
+/// [`format_into`](crate::format_into),
+/// [`ArrayString::try_concat`](crate::ArrayString::try_concat),
+/// and [`ArrayString::try_push_str`](crate::ArrayString::try_push_str).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct NotEnoughSpace;
 enum Never {}
 
+/// Writes `args` into a `core::fmt::Formatter`.
+///
+/// This is for implementing `core::fmt::Display` (or `Debug`) for types that
+/// can be formatted with const_panic,
+/// so that the formatting code only needs to be written once.
+///
+/// Formatter flags (eg: width, fill) are ignored,
+/// the formatting is determined by the [`FmtArg`](crate::FmtArg) passed to
+/// whatever produced the `PanicVal`s.
+///
+/// # Example
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{fmt::display_via_panicvals, impl_panicfmt, FmtArg};
+///
+/// use std::fmt::{self, Display};
+///
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// impl_panicfmt! {
+///     struct Point {
+///         x: u32,
+///         y: u32,
+///     }
+/// }
+///
+/// impl Display for Point {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         display_via_panicvals(&self.to_panicvals(FmtArg::DEBUG), f)
+///     }
+/// }
+///
+/// assert_eq!(Point { x: 3, y: 5 }.to_string(), "Point { x: 3, y: 5 }");
+///
+/// ```
+pub fn display_via_panicvals(
+    args: &[PanicVal<'_>],
+    f: &mut core::fmt::Formatter<'_>,
+) -> core::fmt::Result {
+    // safety: chunks are only flushed right before the start of a char,
+    // and `write_to_buffer` produces valid utf8.
+    write_chunked(&[args], core::fmt::Error, |bytes| {
+        f.write_str(unsafe { core::str::from_utf8_unchecked(bytes) })
+    })
+}
+
+/// Formats the argument slice, passing the message to `callback` in small chunks.
+///
+/// This is for writing arbitrarily long messages (eg: to a serial port or a socket)
+/// without ever holding the entire message in memory,
+/// only a small fixed-size buffer is used.
+///
+/// Each chunk is non-empty and ends on a `char` boundary,
+/// so it's always valid UTF-8.
+///
+/// This function is not a `const fn`, it can only be called at runtime.
+///
+/// # Example
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{for_each_chunk, FmtArg, PanicVal};
+///
+/// let table = [1000u16; 300];
+///
+/// let args: &[&[PanicVal<'_>]] = &[&[
+///     PanicVal::write_str("table: "),
+///     PanicVal::from_slice_u16(&table, FmtArg::DEBUG),
+/// ]];
+///
+/// let mut message = Vec::<u8>::new();
+/// let mut chunks = 0;
+///
+/// for_each_chunk(args, |bytes: &[u8]| {
+///     message.extend_from_slice(bytes);
+///     chunks += 1;
+/// });
+///
+/// assert!(message.starts_with(b"table: [1000, 1000, "));
+/// assert_eq!(message.len(), "table: []".len() + 300 * "1000".len() + 299 * ", ".len());
+/// assert!(chunks > 1);
+/// ```
+pub fn for_each_chunk<F>(args: &[&[PanicVal<'_>]], mut callback: F)
+where
+    F: FnMut(&[u8]),
+{
+    // the callback never errors, and the message can't realistically
+    // reach the `usize::MAX - 1` byte overflow limit.
+    let _ = write_chunked(args, (), |bytes| {
+        callback(bytes);
+        Ok(())
+    });
+}
+
+// Formats `args` into a small buffer, passing it to `flush` whenever it's full,
+// returning the first error returned by `flush`.
+fn write_chunked<E>(
+    args: &[&[PanicVal<'_>]],
+    overflow_err: E,
+    mut flush: impl FnMut(&[u8]) -> Result<(), E>,
+) -> Result<(), E> {
+    const CAP: usize = 64;
+
+    let mut chunk = [0u8; CAP];
+    let mut chunk_len = 0usize;
+    let mut len = 0usize;
+
+    macro_rules! write_to_chunk {
+        ($value:expr) => {{
+            let b: u8 = $value;
+
+            // leaving space for the rest of the bytes of a char
+            if chunk_len + 4 >= CAP && crate::utils::is_char_boundary(b) {
+                flush(&chunk[..chunk_len])?;
+                chunk_len = 0;
+            }
+
+            chunk[chunk_len] = b;
+            chunk_len += 1;
+            len += 1;
+        }};
+    }
+
+    write_to_buffer! {
+        args
+        (
+            len, usize::MAX - 1, usize::MAX, Err(overflow_err),
+            write_to_chunk, write_to_chunk,
+        )
+    };
+
+    if chunk_len != 0 {
+        flush(&chunk[..chunk_len])?;
+    }
+
+    Ok(())
+}
+
 #[cfg(feature = "test")]
 use crate::test_utils::TestString;
 
@@ -292,7 +973,7 @@ pub fn format_panic_message<const LEN: usize>(
                 len, capacity, max_capacity, Err(NotEnoughSpace),
                 write_buffer, write_buffer_checked,
             )
-        }
+        };
     }
 
     Ok(TestString { buffer, len })
@@ -312,7 +993,7 @@ pub(crate) const fn make_panic_string<const LEN: usize>(
     write_to_buffer! {
         args
         (len, LEN, LEN + 1, Err(NotEnoughSpace), write_buffer, write_buffer_checked,)
-    }
+    };
 
     assert!(len as u32 as usize == len, "the panic message is too large");
 
@@ -322,6 +1003,153 @@ pub(crate) const fn make_panic_string<const LEN: usize>(
     })
 }
 
+/// Formats the argument slice into an [`ArrayString`](crate::ArrayString),
+/// returning the message instead of panicking with it.
+///
+/// This is useful for callers that want to log the message at runtime,
+/// rather than panicking with it.
+///
+/// # Errors
+///
+/// Returns `Err(NotEnoughSpace)` if the formatted arguments are longer than `LEN`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{try_concat_string, ArrayString, FmtArg, NotEnoughSpace, PanicVal};
+///
+/// const ARGS: &[&[PanicVal<'_>]] = &[&[
+///     PanicVal::write_str("the error was "),
+///     PanicVal::from_u8(100, FmtArg::DISPLAY),
+/// ]];
+///
+/// assert_eq!(try_concat_string::<20>(ARGS).unwrap(), "the error was 100");
+///
+/// assert_eq!(try_concat_string::<16>(ARGS).unwrap_err(), NotEnoughSpace);
+///
+/// ```
+#[cfg(feature = "non_basic")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub const fn try_concat_string<const LEN: usize>(
+    args: &[&[PanicVal<'_>]],
+) -> Result<crate::ArrayString<LEN>, NotEnoughSpace> {
+    make_panic_string(args)
+}
+
+/// Panics by concatenating the argument slice,
+/// panicking with a different message if it's longer than `MAX` bytes.
+///
+/// This is the function that the [`concat_panic_max`](macro@crate::concat_panic_max)
+/// macro calls to panic.
+///
+/// Unlike [`concat_panic_bounded`], this never truncates the message,
+/// it panics with the length of the message instead,
+/// which is a compile-time error when this is called at compile-time.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::{FmtArg, PanicVal, concat_panic_max};
+///
+/// const _: () = concat_panic_max::<16>(&[&[
+///     PanicVal::write_str("the value is "),
+///     PanicVal::from_u64(12345678901234, FmtArg::DISPLAY),
+/// ]]);
+/// ```
+/// That fails to compile with this error message:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/concat_panic_.rs:5:15
+///   |
+/// 5 |   const _: () = concat_panic_max::<16>(&[&[
+///   |  _______________^
+/// 6 | |     PanicVal::write_str("the value is "),
+/// 7 | |     PanicVal::from_u64(12345678901234, FmtArg::DISPLAY),
+/// 8 | | ]]);
+///   | |___^ the evaluated program panicked at 'the panic message is 27 bytes long, which is longer than the maximum of 16 bytes', src/concat_panic_.rs:5:15
+/// ```
+///
+#[cfg(feature = "non_basic")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub const fn concat_panic_max<const MAX: usize>(args: &[&[PanicVal<'_>]]) -> ! {
+    match make_panic_string::<MAX>(args) {
+        Ok(msg) => panic!("{}", msg.to_str()),
+        Err(NotEnoughSpace) => {
+            use crate::fmt::FmtArg;
+
+            // 128 bytes fits the text below with two `usize::MAX`s
+            let msg = make_panic_string_unwrapped::<128>(&[&[
+                PanicVal::write_str("the panic message is "),
+                PanicVal::from_usize(compute_length(args), FmtArg::DISPLAY),
+                PanicVal::write_str(" bytes long, which is longer than the maximum of "),
+                PanicVal::from_usize(MAX, FmtArg::DISPLAY),
+                PanicVal::write_str(" bytes"),
+            ]]);
+            panic!("{}", msg.to_str())
+        }
+    }
+}
+
+/// Formats the argument slice into an [`ArrayString`](crate::ArrayString),
+/// truncating the message once it reaches `budget` bytes.
+///
+/// The budget is shared by all of the arguments,
+/// so the arguments are written in order until the budget is used up,
+/// truncating the argument that goes over it, and skipping the ones after it.
+/// `budget`s larger than `LEN` are treated as `LEN`.
+///
+/// Strings are truncated on a `char` boundary,
+/// while integers are either fully written or omitted.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{concat_string_truncated, FmtArg, PanicVal};
+///
+/// const ARGS: &[&[PanicVal<'_>]] = &[&[
+///     PanicVal::write_str("expected "),
+///     PanicVal::from_str("quoted string", FmtArg::DEBUG),
+///     PanicVal::write_str(", found "),
+///     PanicVal::from_u32(1234, FmtArg::DISPLAY),
+/// ]];
+///
+/// assert_eq!(
+///     concat_string_truncated::<64>(64, ARGS),
+///     r#"expected "quoted string", found 1234"#,
+/// );
+/// assert_eq!(concat_string_truncated::<64>(16, ARGS), r#"expected "quoted"#);
+/// assert_eq!(concat_string_truncated::<64>(34, ARGS), r#"expected "quoted string", found "#);
+/// ```
+#[cfg(feature = "non_basic")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub const fn concat_string_truncated<const LEN: usize>(
+    budget: usize,
+    args: &[&[PanicVal<'_>]],
+) -> crate::ArrayString<LEN> {
+    let budget = crate::utils::min_usize(budget, LEN);
+    let mut buffer = [0u8; LEN];
+    let mut len = 0usize;
+
+    make_buffer_writer_macros! {buffer, len}
+
+    // `budget == max_capacity` truncates the message instead of returning an error
+    write_to_buffer! {
+        args
+        (
+            len, budget, budget, crate::ArrayString { buffer, len: len as u32 },
+            write_buffer, write_buffer_checked,
+        )
+    };
+
+    crate::ArrayString {
+        buffer,
+        len: len as u32,
+    }
+}
+
 #[cfg(feature = "non_basic")]
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
 #[doc(hidden)]
@@ -335,10 +1163,51 @@ pub const fn make_panic_string_unwrapped<const LEN: usize>(
     }
 }
 
+/// Computes the length in bytes of the string that the `args` are formatted into.
+///
+/// This is the exact length of the formatted string, without any truncation,
+/// including the padding of the `PanicVal`s,
+/// as well as the quotes and escape sequences of `Debug`-formatted strings and chars.
+///
+/// This is useful for choosing the `LEN` of an
+/// [`ArrayString<LEN>`](crate::ArrayString) that the `args` are formatted into,
+/// or to check whether they fit in the panic message without being truncated.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{display_len, try_concat_string, FmtArg, PanicVal};
+///
+/// const ARGS: &[&[PanicVal<'_>]] = &[
+///     &[PanicVal::write_str("name: ")],
+///     // written as `"foo\n"`, with the quotes and an escaped newline
+///     &[PanicVal::from_str("foo\n", FmtArg::DEBUG)],
+///     // padded with 3 spaces before it
+///     &[PanicVal::write_str("bar").with_leftpad(FmtArg::DISPLAY.set_indentation(3))],
+/// ];
+///
+/// const LEN: usize = display_len(ARGS);
+///
+/// assert_eq!(LEN, 19);
+///
+/// assert_eq!(try_concat_string::<LEN>(ARGS).unwrap(), "name: \"foo\\n\"   bar");
+///
+/// assert!(try_concat_string::<{ LEN - 1 }>(ARGS).is_err());
+///
+/// ```
 #[cfg(feature = "non_basic")]
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub const fn display_len(args: &[&[PanicVal<'_>]]) -> usize {
+    compute_length(args)
+}
+
+#[cfg(feature = "non_basic")]
 #[doc(hidden)]
 pub const fn compute_length(args: &[&[PanicVal<'_>]]) -> usize {
+    compute_joined_length(&[], args)
+}
+
+const fn compute_joined_length(separator: &[PanicVal<'_>], args: &[&[PanicVal<'_>]]) -> usize {
     let mut len = 0usize;
 
     macro_rules! add_to_len {
@@ -350,11 +1219,12 @@ pub const fn compute_length(args: &[&[PanicVal<'_>]]) -> usize {
 
     write_to_buffer! {
         args
+        [separator]
         (
             len, usize::MAX - 1, usize::MAX, usize::MAX,
             add_to_len, add_to_len,
         )
-    }
+    };
 
     len
 }
@@ -14,16 +14,17 @@ impl ForEscaping {
         FOR_ESCAPING.escape_char[(b & 0b1111) as usize]
     }
 
-    // how long this byte inside a utf8 string takes to represent in debug formatting.
-    pub(crate) const fn byte_len(c: u8) -> usize {
+    // how long this byte inside a utf8 string delimited by `quote`
+    // takes to represent in debug formatting.
+    pub(crate) const fn byte_len(c: u8, quote: u8) -> usize {
         if c < 128 {
             let shifted = 1 << c;
 
-            if (FOR_ESCAPING.is_escaped & shifted) != 0 {
+            if (Self::escaped_bits(quote) & shifted) != 0 {
                 if (FOR_ESCAPING.is_backslash_escaped & shifted) != 0 {
                     2
                 } else {
-                    4
+                    unicode_escape_len(c)
                 }
             } else {
                 1
@@ -33,23 +34,58 @@ impl ForEscaping {
         }
     }
 
-    pub(crate) const fn is_escaped(c: u8) -> bool {
-        (c < 128) && ((FOR_ESCAPING.is_escaped & (1 << c)) != 0)
+    // whether this byte is escaped inside a string delimited by `quote`
+    pub(crate) const fn is_escaped(c: u8, quote: u8) -> bool {
+        (c < 128) && ((Self::escaped_bits(quote) & (1 << c)) != 0)
     }
 
     pub(crate) const fn is_backslash_escaped(c: u8) -> bool {
         (c < 128) && ((FOR_ESCAPING.is_backslash_escaped & (1 << c)) != 0)
     }
+
+    // the escaped bytes in a string delimited by `quote`,
+    // which only escapes the quote character that delimits it.
+    const fn escaped_bits(quote: u8) -> u128 {
+        let other_quote = if quote == b'"' { b'\'' } else { b'"' };
+        FOR_ESCAPING.is_escaped & !(1 << other_quote)
+    }
+
+    // whether this is an ascii control character,
+    // which is what `Display` formatting escapes when `escape_control_in_display` is enabled.
+    pub(crate) const fn is_control(c: u8) -> bool {
+        c < 0x20 || c == 0x7F
+    }
+
+    // how long this byte inside a utf8 string takes to represent in `Display` formatting,
+    // when only control characters are escaped.
+    pub(crate) const fn control_byte_len(c: u8) -> usize {
+        if Self::is_control(c) {
+            Self::byte_len(c, b'"')
+        } else {
+            1
+        }
+    }
 }
 
 #[doc(hidden)]
-/// Converts 0..=0xF to its ascii representation of '0'..='9' and 'A'..='F'
+/// Converts 0..=0xF to its ascii representation of '0'..='9' and 'a'..='f'
 #[inline(always)]
 pub(crate) const fn hex_as_ascii(n: u8) -> u8 {
     if n < 10 {
         n + b'0'
     } else {
-        n - 10 + b'A'
+        n - 10 + b'a'
+    }
+}
+
+// The length of the `\u{...}` escape for an ascii byte,
+// using the minimum amount of hex digits, like std does.
+#[inline(always)]
+pub(crate) const fn unicode_escape_len(c: u8) -> usize {
+    if c < 0x10 {
+        5
+    } else {
+        6
     }
 }
 
@@ -57,12 +93,16 @@ pub(crate) const fn hex_as_ascii(n: u8) -> u8 {
 pub(crate) const FOR_ESCAPING: &ForEscaping = {
     let mut is_backslash_escaped = 0;
 
+    // These match the escapes that `<str as Debug>::fmt` uses,
+    // only the quote that delimits the string is escaped (see `ForEscaping::escaped_bits`),
+    // which is `"` by default.
     let escaped = [
+        (b'\0', b'0'),
         (b'\t', b't'),
         (b'\n', b'n'),
         (b'\r', b'r'),
-        (b'\'', b'\''),
         (b'"', b'"'),
+        (b'\'', b'\''),
         (b'\\', b'\\'),
     ];
 
@@ -84,8 +124,9 @@ pub(crate) const FOR_ESCAPING: &ForEscaping = {
         i += 1;
     }
 
-    // Setting all the control characters as being escaped.
-    let is_escaped = is_backslash_escaped | 0xFFFF_FFFF;
+    // Setting all the control characters as being escaped,
+    // the ones that aren't backslash escaped use the `\u{...}` syntax.
+    let is_escaped = is_backslash_escaped | 0xFFFF_FFFF | (1 << 0x7F);
 
     &ForEscaping {
         escape_char,
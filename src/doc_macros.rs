@@ -25,6 +25,21 @@ alternate-`Debug` formats the argument, with binary-formatted numbers.
 `Debug` formats the argument, with hexadecimal-formatted numbers.
 - `alt_hex:` or `{#X}:`:
 alternate-`Debug` formats the argument, with hexadecimal-formatted numbers.
+- `grouped_hex:`: like `alt_hex:`, with
+[`digit_grouping`](crate::FmtArg::set_digit_grouping) enabled,
+separating every 4 hexadecimal digits with `_` (eg: `0xDEAD_BEEF`).
+- `grouped_bin:`: like `alt_bin:`, with
+[`digit_grouping`](crate::FmtArg::set_digit_grouping) enabled,
+separating every 4 binary digits with `_` (eg: `0b1010_0101`).
+Other group sizes can be used with
+[`FmtArg::set_radix_group_len`](crate::FmtArg::set_radix_group_len)
+on the `FmtArg` that the argument is formatted with.
+- `plus:`: formats the argument as it otherwise would,
+prefixing non-negative integers with a `+`.
+- `($fmtarg_expr):`: formats the argument with the `FmtArg` returned by
+the parenthesized expression, keeping the current indentation.
+This allows choosing the formatting of each field from an array of `FmtArg`s,
+eg: `(FIELD_FMTS[0]): self.x, (FIELD_FMTS[1]): self.y`.
 "##,
 $($additional_fmt_overrides,)?
 r##"
@@ -32,9 +47,12 @@ r##"
 
 String expressions are debug-formatted like this:
 - Prepending and appending the double quote character (`"`).
-- Escaping the `'\t'`,`'\n'`,`'\r'`,`'\\'`, `'\''`, and`'\"'` characters.
-- Escaping control characters with `\xYY`, 
-where `YY` is the hexadecimal value of the control character.
+- Escaping the `'\0'`, `'\t'`,`'\n'`,`'\r'`,`'\\'`, and`'\"'` characters.
+- Escaping the other ASCII control characters with `\u{YY}`, 
+where `YY` is the lowercase hexadecimal value of the control character
+(without leading zeroes).
+
+This matches the way that `str`'s `Debug` impl escapes ASCII characters.
 
 "##
 )}}
@@ -2,6 +2,7 @@
 //!
 //! Panic formatting for custom types can be done in these ways
 //! (in increasing order of verbosity):
+//! - Using the [`impl_unit_panicfmt`] macro, for types that are formatted as a fixed name
 //! - Using the [`PanicFmt` derive] macro
 //! (requires the opt-in `"derive"` feature)
 //! - Using the [`impl_panicfmt`] macro
@@ -13,6 +14,7 @@
 //! [`PanicFmt` derive]: derive@crate::PanicFmt
 //! [`PanicFmt`]: trait@crate::fmt::PanicFmt
 //! [`impl_panicfmt`]: crate::impl_panicfmt
+//! [`impl_unit_panicfmt`]: crate::impl_unit_panicfmt
 //! [`flatten_panicvals`]: crate::flatten_panicvals
 
 #[cfg(feature = "non_basic")]
@@ -21,11 +23,25 @@ mod non_basic_fmt;
 #[cfg(feature = "non_basic")]
 mod fmt_compressed;
 
+#[cfg(feature = "non_basic")]
+pub(crate) mod struct_style_fmt;
+
 pub mod char_formatting;
 
+mod ansi_style;
+
+mod digit_buffer;
+
 #[cfg(feature = "non_basic")]
 pub use self::{fmt_compressed::PackedFmtArg, non_basic_fmt::*};
 
+#[cfg(feature = "non_basic")]
+pub use crate::slice_stuff::{ByteStr, SliceWith};
+
+pub use self::{ansi_style::*, digit_buffer::DigitBuffer};
+
+pub use crate::concat_panic_::display_via_panicvals;
+
 use crate::wrapper::StdWrapper;
 
 use core::marker::PhantomData;
@@ -51,6 +67,65 @@ use core::marker::PhantomData;
 /// ```
 /// The returned [`PanicVal`](crate::PanicVal) can also be `PanicVal<'static>`.
 ///
+/// ### Method dispatch
+///
+/// The formatting macros (eg: [`concat_panic`], [`flatten_panicvals`])
+/// call `to_panicvals` by name, with method call syntax,
+/// on the value returned by [`coerce_fmt`]:
+/// - For [`IsCustomType`] types: a `&Self::This`,
+///   so the inherent `to_panicvals` method of the type is called.
+/// - For [`IsStdType`] types: a [`StdWrapper`]`<&Self::This>`,
+///   which has the `to_panicvals` methods for the standard library types.
+///
+/// (how [`Kind`](PanicFmt::Kind) selects between these is described in its docs)
+///
+/// `to_panicvals` is not a method of this trait because
+/// trait methods can't be called in stable const fns,
+/// which is also why generic types need a `to_panicvals` for each
+/// concrete set of formatted type arguments, rather than a single
+/// `impl<T: Trait> Type<T>` block that calls `T`'s trait methods.
+/// Const parameters have no such limitation.
+///
+/// Any `to_panicvals` method that can be called as `this.to_panicvals(fmtarg)`
+/// with a `&Self::This` works,
+/// eg: one that takes `&self`, or `self` for `Copy` types.
+///
+/// This example demonstrates a fixed-point type which is generic over its fractional bits:
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{ArrayString, FmtArg, IsCustomType, PanicFmt, PanicVal};
+///
+/// assert_eq!(
+///     const_panic::concat_!(Fixed::<4>(0x35)),
+///     "3 + 5/16",
+/// );
+///
+/// assert_eq!(
+///     const_panic::concat_!(Fixed::<8>(0x1280)),
+///     "18 + 128/256",
+/// );
+///
+/// struct Fixed<const FRAC_BITS: u32>(u32);
+///
+/// impl<const FRAC_BITS: u32> PanicFmt for Fixed<FRAC_BITS> {
+///     type This = Self;
+///     type Kind = IsCustomType;
+///     const PV_COUNT: usize = 5;
+/// }
+///
+/// impl<const FRAC_BITS: u32> Fixed<FRAC_BITS> {
+///     pub const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; 5] {
+///         const_panic::flatten_panicvals!(f;
+///             u32 => self.0 >> FRAC_BITS,
+///             " + ",
+///             u32 => self.0 & ((1 << FRAC_BITS) - 1),
+///             "/",
+///             u32 => 1 << FRAC_BITS,
+///         )
+///     }
+/// }
+/// ```
+///
 /// # Implementation examples
 ///
 /// This trait can be implemented in these ways (in increasing order of verbosity):
@@ -111,6 +186,9 @@ use core::marker::PhantomData;
 /// [`PanicFmt`]: trait@crate::fmt::PanicFmt
 /// [`impl_panicfmt`]: crate::impl_panicfmt
 /// [`flatten_panicvals`]: crate::flatten_panicvals
+/// [`concat_panic`]: macro@crate::concat_panic
+/// [`StdWrapper`]: crate::StdWrapper
+/// [`coerce_fmt`]: crate::coerce_fmt
 pub trait PanicFmt {
     /// The type after dereferencing all references.
     ///
@@ -119,6 +197,25 @@ pub trait PanicFmt {
     /// Whether this is a user-defined type or standard library type.
     ///
     /// User-defined types should generally set this to [`IsCustomType`].
+    ///
+    /// This type is used for type-level dispatch in the formatting macros,
+    /// which call the `coerce` method of [`PROOF`](Self::PROOF)
+    /// (an [`IsPanicFmt`]`<Self, Self::This, Self::Kind>`)
+    /// with a reference to the formatted value.
+    /// `IsPanicFmt` has one inherent `coerce` method for each `Kind`:
+    /// - [`IsCustomType`]: returns the `&Self::This` reference as-is,
+    ///   so that the `to_panicvals` method of `Self::This` is called.
+    /// - [`IsStdType`]: wraps the `&Self::This` reference in a [`StdWrapper`],
+    ///   so that the `to_panicvals` method of `StdWrapper<&Self::This>` is called.
+    ///
+    /// Because the `to_panicvals` methods of `StdWrapper` can only be defined in this crate,
+    /// third-party types must use `IsCustomType`,
+    /// including wrappers around standard library types,
+    /// which can then format the wrapped value in any way.
+    ///
+    /// References implement `PanicFmt` with the same `This` and `Kind`
+    /// associated types as the referenced type,
+    /// so a `&&T` is formatted the same as a `T`.
     type Kind;
 
     /// The length of the array returned in `Self::to_panicvals`
@@ -138,10 +235,56 @@ impl<'a, T: PanicFmt + ?Sized> PanicFmt for &'a T {
     const PV_COUNT: usize = T::PV_COUNT;
 }
 
+impl<T: PanicFmt + ?Sized> PanicFmt for &mut T {
+    type This = T::This;
+    type Kind = T::Kind;
+    const PV_COUNT: usize = T::PV_COUNT;
+}
+
 /// Marker type used as the [`PanicFmt::Kind`] associated type for std types.
+///
+/// Types with this `Kind` are formatted by the `to_panicvals` methods of
+/// [`StdWrapper`], which are only defined in this crate,
+/// so user-defined types should use [`IsCustomType`] instead.
 pub struct IsStdType;
 
 /// Marker type used as the [`PanicFmt::Kind`] for user-defined types.
+///
+/// Types with this `Kind` are formatted by calling their own `to_panicvals` method,
+/// [as described in the `PanicFmt` docs](PanicFmt#method-dispatch).
+///
+/// # Example
+///
+/// This example demonstrates a wrapper around an integer,
+/// which is entirely formatted by its own `to_panicvals` method,
+/// rather than the way that the wrapped integer is formatted.
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{ArrayString, FmtArg, IsCustomType, PanicFmt, PanicVal};
+///
+/// assert_eq!(const_panic::concat_!(Celsius(-5)), "-5°C");
+///
+/// // references are formatted the same as the referenced value
+/// assert_eq!(const_panic::concat_!(&&Celsius(20)), "20°C");
+///
+/// // the `FmtArg` is passed to `to_panicvals` as-is
+/// assert_eq!(const_panic::concat_!(FmtArg::HEX; Celsius(255)), "FF°C");
+///
+/// struct Celsius(i32);
+///
+/// impl PanicFmt for Celsius {
+///     type This = Self;
+///     type Kind = IsCustomType;
+///     const PV_COUNT: usize = 2;
+/// }
+///
+/// impl Celsius {
+///     pub const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; 2] {
+///         [PanicVal::from_i32(self.0, f), PanicVal::write_str("°C")]
+///     }
+/// }
+/// ```
 pub struct IsCustomType;
 
 /// A marker type that proves that `S` implements
@@ -201,6 +344,34 @@ impl<S: ?Sized, T: ?Sized, K> Clone for IsPanicFmt<S, T, K> {
 
 /// Carries all of the configuration for formatting functions.
 ///
+/// # Construction
+///
+/// Custom `FmtArg`s are built by starting from one of the associated constants
+/// (eg: [`FmtArg::DEBUG`]) and calling the `set_*` methods,
+/// all of which are `const fn`s.
+/// `FmtArg` is `#[non_exhaustive]`, so it can't be constructed with struct literals
+/// outside of this crate, which allows adding fields for new formatting options.
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{ArrayString, FmtArg};
+///
+/// const FMT: FmtArg = FmtArg::DEBUG
+///     .set_alternate(true)
+///     .set_indentation(2)
+///     .set_plus_sign(true);
+///
+/// assert_eq!(
+///     const_panic::concat_!(FMT; [3u8, 5]),
+///     "[\n      +3,\n      +5,\n  ]",
+/// );
+/// ```
+///
+/// The indentation that's added for each level of nesting is
+/// [`fmt::INDENTATION_STEP`](crate::fmt::INDENTATION_STEP) spaces by default,
+/// configurable with the [`set_indentation_step`](FmtArg::set_indentation_step) and
+/// [`set_tab_indentation`](FmtArg::set_tab_indentation) methods.
+///
 /// # Example
 ///
 #[cfg_attr(feature = "non_basic", doc = "```rust")]
@@ -213,7 +384,7 @@ impl<S: ?Sized, T: ?Sized, K> Clone for IsPanicFmt<S, T, K> {
 /// // Debug formatting
 /// assert_eq!(
 ///     const_panic::concat_!(FmtArg::DEBUG; ARRAY),
-///     r#"["3", "foo\nbar", "\x00qux"]"#
+///     r#"["3", "foo\nbar", "\0qux"]"#
 /// );
 ///
 /// // Alternate-Debug formatting
@@ -223,7 +394,7 @@ impl<S: ?Sized, T: ?Sized, K> Clone for IsPanicFmt<S, T, K> {
 ///         "[\n",
 ///         "    \"3\",\n",
 ///         "    \"foo\\nbar\",\n",
-///         "    \"\\x00qux\",\n",
+///         "    \"\\0qux\",\n",
 ///         "]",
 ///     )
 /// );
@@ -248,7 +419,8 @@ impl<S: ?Sized, T: ?Sized, K> Clone for IsPanicFmt<S, T, K> {
 /// );
 ///
 /// ```
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+#[derive(Copy, Clone, PartialEq)]
 pub struct FmtArg {
     /// How much indentation is needed for a field/array element.
     ///
@@ -262,6 +434,77 @@ pub struct FmtArg {
     pub fmt_kind: FmtKind,
     /// What integers are formatted as: decimal, hexadecimal, or binary.
     pub number_fmt: NumberFmt,
+
+    // The options below are private so that they can be stored compactly,
+    // they're set with the `set_*` methods, and read with the methods of the same name.
+    //
+    // `PanicVal`s only store a `PackedFmtArg`, which leaves out the options
+    // that don't affect slice elements, so the non-boolean options are kept as
+    // separate fields here, rather than bitpacked.
+    indentation_step: u8,
+    max_indentation: u8,
+    // How many levels of nesting `indent` didn't add indentation for,
+    // because of `max_indentation`.
+    capped_indents: u8,
+    radix_group_len: u8,
+    struct_style: StructStyle,
+    // the boolean options, as `flags::*` bits
+    flags: u16,
+    // `0` if strings aren't wrapped
+    wrap_width: u16,
+}
+
+// The bits of `FmtArg::flags`.
+//
+// The options that affect how the elements of slices are formatted are in the low bits,
+// because they're the ones stored in a `PackedFmtArg`.
+pub(crate) mod flags {
+    pub(crate) const TAB_INDENTATION: u16 = 1 << 0;
+    pub(crate) const YES_NO: u16 = 1 << 1;
+    pub(crate) const PLUS_SIGN: u16 = 1 << 2;
+    pub(crate) const SIGNED_HEX_BIN: u16 = 1 << 3;
+    pub(crate) const BRACE_SLICES: u16 = 1 << 4;
+    pub(crate) const SHELL_QUOTE: u16 = 1 << 5;
+    pub(crate) const ESCAPE_CONTROL_IN_DISPLAY: u16 = 1 << 6;
+    pub(crate) const SINGLE_QUOTES: u16 = 1 << 7;
+    pub(crate) const DIGIT_GROUPING: u16 = 1 << 8;
+    // the amount of bits of the flags above
+    #[cfg(feature = "non_basic")]
+    pub(crate) const SLICE_BITS: u8 = 9;
+
+    pub(crate) const COMPACT_OPTION: u16 = 1 << 9;
+    pub(crate) const TRANSPARENT_OPTION: u16 = 1 << 10;
+}
+
+impl core::fmt::Debug for FmtArg {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FmtArg")
+            .field("indentation", &self.indentation)
+            .field("is_alternate", &self.is_alternate)
+            .field("fmt_kind", &self.fmt_kind)
+            .field("number_fmt", &self.number_fmt)
+            .field("indentation_step", &self.indentation_step)
+            .field("max_indentation", &self.max_indentation)
+            .field("capped_indents", &self.capped_indents)
+            .field("tab_indentation", &self.tab_indentation())
+            .field("yes_no", &self.yes_no())
+            .field("plus_sign", &self.plus_sign())
+            .field("signed_hex_bin", &self.signed_hex_bin())
+            .field("brace_slices", &self.brace_slices())
+            .field("compact_option", &self.compact_option())
+            .field("transparent_option", &self.transparent_option())
+            .field("shell_quote", &self.shell_quote())
+            .field(
+                "escape_control_in_display",
+                &self.escape_control_in_display(),
+            )
+            .field("single_quotes", &self.single_quotes())
+            .field("digit_grouping", &self.digit_grouping())
+            .field("radix_group_len", &self.radix_group_len)
+            .field("struct_style", &self.struct_style)
+            .field("wrap_width", &self.wrap_width())
+            .finish()
+    }
 }
 
 impl FmtArg {
@@ -271,6 +514,14 @@ impl FmtArg {
         fmt_kind: FmtKind::Display,
         is_alternate: false,
         number_fmt: NumberFmt::Decimal,
+        // same as `fmt::INDENTATION_STEP`, which requires the "non_basic" feature
+        indentation_step: 4,
+        max_indentation: u8::MAX,
+        capped_indents: 0,
+        radix_group_len: 4,
+        struct_style: StructStyle::Full,
+        flags: 0,
+        wrap_width: 0,
     };
 
     /// A `FmtArg` with alternate `Display` formatting, starting with no indentation.
@@ -302,6 +553,490 @@ impl FmtArg {
         self
     }
 
+    /// Sets how many spaces of indentation there are
+    /// before fields and elements in alternate mode.
+    ///
+    /// This is the initial indentation,
+    /// nested values are indented by an additional
+    /// [`indentation_step()`](Self::indentation_step) spaces per level
+    /// ([`fmt::INDENTATION_STEP`](crate::fmt::INDENTATION_STEP) by default).
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::ALT_DEBUG.set_indentation(8);
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; [3u8, 5]),
+    ///     "[\n            3,\n            5,\n        ]",
+    /// );
+    /// ```
+    pub const fn set_indentation(mut self, indentation: u8) -> Self {
+        self.indentation = indentation;
+        self
+    }
+
+    /// Sets whether `bool`s are formatted as `yes`/`no`,
+    /// and `Option<bool>`s as `yes`/`no`/`unset`.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::DEBUG.set_yes_no(true);
+    ///
+    /// assert_eq!(const_panic::concat_!(FMT; Some(true)), "yes");
+    /// assert_eq!(const_panic::concat_!(FMT; Some(false)), "no");
+    /// assert_eq!(const_panic::concat_!(FMT; None::<bool>), "unset");
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; &[Some(true), None, Some(false)]),
+    ///     "[yes, unset, no]",
+    /// );
+    ///
+    /// ```
+    pub const fn set_yes_no(self, yes_no: bool) -> Self {
+        self.with_flag(flags::YES_NO, yes_no)
+    }
+
+    /// Sets whether non-negative integers are prefixed with a `+`.
+    ///
+    /// As with the `{:+}` flag in `core::fmt`,
+    /// hexadecimal and binary formatted integers are always prefixed with a `+`
+    /// when this is enabled, since they're formatted as unsigned
+    /// (unless [`signed_hex_bin`](Self::signed_hex_bin) is enabled).
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::DEBUG.set_plus_sign(true);
+    ///
+    /// assert_eq!(const_panic::concat_!(FMT; 0u8), "+0");
+    /// assert_eq!(const_panic::concat_!(FMT; 13u8), "+13");
+    /// assert_eq!(const_panic::concat_!(FMT; -13i8), "-13");
+    ///
+    /// const HEX: FmtArg = FmtArg::HEX.set_plus_sign(true);
+    /// assert_eq!(const_panic::concat_!(HEX; -1i8), "+FF");
+    ///
+    /// // the `plus` formatting override enables this flag
+    /// assert_eq!(const_panic::concat_!(plus: 3u8, " ", plus: &[-5i8, 8]), "+3 [-5, +8]");
+    ///
+    /// ```
+    pub const fn set_plus_sign(self, plus_sign: bool) -> Self {
+        self.with_flag(flags::PLUS_SIGN, plus_sign)
+    }
+
+    /// Sets whether negative integers are formatted as a `-` followed by their magnitude
+    /// with hexadecimal and binary formatting.
+    ///
+    /// When this is disabled (the default),
+    /// negative integers are formatted as the two's complement bit pattern of their type,
+    /// the same as the `{:X}` and `{:b}` formatting of `core::fmt`.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const NEG_ONE: i32 = -1;
+    /// const NEG_THREE: i8 = -3;
+    ///
+    /// assert_eq!(const_panic::concat_!(FmtArg::HEX; NEG_ONE), "FFFFFFFF");
+    /// assert_eq!(const_panic::concat_!(FmtArg::ALT_BIN; NEG_THREE), "0b11111101");
+    ///
+    /// const HEX: FmtArg = FmtArg::ALT_HEX.set_signed_hex_bin(true);
+    /// assert_eq!(const_panic::concat_!(HEX; NEG_ONE), "-0x1");
+    ///
+    /// const BIN: FmtArg = FmtArg::BIN.set_signed_hex_bin(true);
+    /// assert_eq!(const_panic::concat_!(BIN; NEG_THREE), "-11");
+    /// assert_eq!(const_panic::concat_!(BIN; [-5i16, 5]), "[-101, 101]");
+    ///
+    /// ```
+    pub const fn set_signed_hex_bin(self, signed_hex_bin: bool) -> Self {
+        self.with_flag(flags::SIGNED_HEX_BIN, signed_hex_bin)
+    }
+
+    /// Sets whether slices and arrays are surrounded by `{}` braces,
+    /// instead of `[]` brackets.
+    ///
+    /// This is useful for formatting slices that are used as sets.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::DEBUG.set_brace_slices(true);
+    /// const ALT_FMT: FmtArg = FmtArg::ALT_DEBUG.set_brace_slices(true);
+    ///
+    /// assert_eq!(const_panic::concat_!(FMT; &[1u8, 2, 3]), "{1, 2, 3}");
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(ALT_FMT; &["foo", "bar"]),
+    ///     "{\n    \"foo\",\n    \"bar\",\n}",
+    /// );
+    ///
+    /// ```
+    pub const fn set_brace_slices(self, brace_slices: bool) -> Self {
+        self.with_flag(flags::BRACE_SLICES, brace_slices)
+    }
+
+    /// Sets whether `Some` is written on the same line as the value it wraps
+    /// when using alternate formatting.
+    ///
+    /// By default, alternate formatting writes `Option`s the same way that `{:#?}` does,
+    /// putting the wrapped value on its own indented line.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::ALT_DEBUG.set_compact_option(true);
+    ///
+    /// assert_eq!(const_panic::concat_!(FmtArg::ALT_DEBUG; Some(5u8)), "Some(\n    5,\n)");
+    ///
+    /// assert_eq!(const_panic::concat_!(FMT; Some(5u8)), "Some(5)");
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; Some([3u8, 5])),
+    ///     "Some([\n    3,\n    5,\n])",
+    /// );
+    ///
+    /// ```
+    pub const fn set_compact_option(self, compact_option: bool) -> Self {
+        self.with_flag(flags::COMPACT_OPTION, compact_option)
+    }
+
+    /// Sets whether `Option`s are `Display` formatted transparently,
+    /// writing `Some(x)` as just `x`, and `None` as nothing (an empty string).
+    ///
+    /// This is for optional parts of messages,
+    /// where the `Option` can be passed directly instead of using
+    /// [conditional arguments](crate::flatten_panicvals#conditional-arguments).
+    ///
+    /// `Debug` formatting is unaffected by this,
+    /// and [`yes_no`](Self::yes_no) formatting of `Option<bool>`
+    /// takes precedence over this.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::DISPLAY.set_transparent_option(true);
+    ///
+    /// const USER: Option<&str> = Some("alice");
+    /// const CODE: Option<u16> = None;
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; "user: ", USER, ", code: ", CODE, "."),
+    ///     "user: alice, code: .",
+    /// );
+    ///
+    /// // Debug formatting is unaffected
+    /// const DEBUG_FMT: FmtArg = FMT.set_debug();
+    /// assert_eq!(
+    ///     const_panic::concat_!(DEBUG_FMT; USER, " ", CODE),
+    ///     r#"Some("alice") None"#,
+    /// );
+    ///
+    /// ```
+    pub const fn set_transparent_option(self, transparent_option: bool) -> Self {
+        self.with_flag(flags::TRANSPARENT_OPTION, transparent_option)
+    }
+
+    /// Sets whether `Display`-formatted strings are quoted when they contain whitespace
+    /// or characters that are special in shells, like shell argument quoting.
+    ///
+    /// Strings that need quoting are written the same as with `Debug` formatting,
+    /// other strings (including non-ASCII ones) are written as-is.
+    /// Empty strings are always quoted.
+    ///
+    /// This has no effect on `Debug` formatting, since it always quotes strings.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::DISPLAY.set_shell_quote(true);
+    ///
+    /// const PLAIN: &str = "hello";
+    /// const SPACED: &str = "hello world";
+    /// const DOLLAR: &str = "a$b";
+    /// const EMPTY: &str = "";
+    ///
+    /// assert_eq!(const_panic::concat_!(FMT; PLAIN), "hello");
+    /// assert_eq!(const_panic::concat_!(FMT; SPACED), "\"hello world\"");
+    /// assert_eq!(const_panic::concat_!(FMT; DOLLAR), "\"a$b\"");
+    /// assert_eq!(const_panic::concat_!(FMT; EMPTY), "\"\"");
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; &["cp", "-r", "my files", "/tmp/"]),
+    ///     "[cp, -r, \"my files\", /tmp/]",
+    /// );
+    ///
+    /// // string literals are always written as-is by the formatting macros
+    /// assert_eq!(const_panic::concat_!(FMT; "cp ", SPACED), "cp \"hello world\"");
+    ///
+    /// ```
+    pub const fn set_shell_quote(self, shell_quote: bool) -> Self {
+        self.with_flag(flags::SHELL_QUOTE, shell_quote)
+    }
+
+    /// Sets whether `Display`-formatted strings have their control characters escaped.
+    ///
+    /// The control characters are escaped the same as with `Debug` formatting
+    /// (eg: `\n`, `\t`, `\u{1b}`),
+    /// but unlike `Debug` formatting, the string isn't quoted,
+    /// and quotes and backslashes are written as-is.
+    ///
+    /// This is for writing strings that could contain unexpected control characters,
+    /// without them affecting the terminal output.
+    ///
+    /// This has no effect on `Debug` formatting, since it always escapes control characters,
+    /// nor on strings that are [wrapped](Self::wrap_width).
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::DISPLAY.set_escape_control_in_display(true);
+    ///
+    /// const NAME: &str = "line1\n\t\"line2\"\x1b[0m";
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; "name: ", NAME),
+    ///     r#"name: line1\n\t"line2"\u{1b}[0m"#,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FmtArg::DISPLAY; NAME),
+    ///     "line1\n\t\"line2\"\x1b[0m",
+    /// );
+    ///
+    /// ```
+    pub const fn set_escape_control_in_display(self, escape_control_in_display: bool) -> Self {
+        self.with_flag(flags::ESCAPE_CONTROL_IN_DISPLAY, escape_control_in_display)
+    }
+
+    /// Sets whether `Debug`-formatted strings are delimited with single quotes (`'`),
+    /// instead of double quotes (`"`).
+    ///
+    /// This is useful for embedding strings in text that's already double-quoted.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::DEBUG.set_single_quotes(true);
+    /// const QUOTED: &str = r#"say "it's""#;
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; QUOTED),
+    ///     r#"'say "it\'s"'"#,
+    /// );
+    ///
+    /// assert_eq!(const_panic::concat_!(FMT; &["foo", "bar"]), "['foo', 'bar']");
+    ///
+    /// ```
+    pub const fn set_single_quotes(self, single_quotes: bool) -> Self {
+        self.with_flag(flags::SINGLE_QUOTES, single_quotes)
+    }
+
+    /// Sets whether the digits of integers are grouped with `_` separators.
+    ///
+    /// Decimal integers are grouped in 3 digits,
+    /// hexadecimal and binary integers are grouped in
+    /// [`radix_group_len`](Self::set_radix_group_len) digits (4 by default).
+    /// Integers formatted with [`PanicVal::from_u128_radix`](crate::PanicVal::from_u128_radix)
+    /// are never grouped.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::DEBUG.set_digit_grouping(true);
+    /// const MILLION: u32 = 1_000_000;
+    /// const NEG_THOUSAND: i16 = -1000;
+    /// assert_eq!(const_panic::concat_!(FMT; MILLION), "1_000_000");
+    /// assert_eq!(const_panic::concat_!(FMT; NEG_THOUSAND), "-1_000");
+    ///
+    /// const HEX: FmtArg = FmtArg::ALT_HEX.set_digit_grouping(true);
+    /// const BEEF: u32 = 0xDEADBEEF;
+    /// assert_eq!(const_panic::concat_!(HEX; BEEF), "0xDEAD_BEEF");
+    ///
+    /// const BIN: FmtArg = FmtArg::BIN.set_digit_grouping(true);
+    /// const HUNDRED: u8 = 100;
+    /// assert_eq!(const_panic::concat_!(BIN; HUNDRED), "110_0100");
+    ///
+    /// ```
+    pub const fn set_digit_grouping(self, digit_grouping: bool) -> Self {
+        self.with_flag(flags::DIGIT_GROUPING, digit_grouping)
+    }
+
+    /// Sets the amount of digits between the `_` separators of
+    /// hexadecimal and binary integers when
+    /// [`digit_grouping`](Self::set_digit_grouping) is enabled.
+    ///
+    /// This is `4` by default.
+    /// Decimal integers are always grouped in 3 digits.
+    ///
+    /// The separators are only written between digits,
+    /// never next to the `0x`/`0b` prefix or the sign.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix_group_len` is not in the `4..=63` range,
+    /// the lower bound keeps formatted integers short enough for their buffers.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const BIN: FmtArg = FmtArg::ALT_BIN.set_digit_grouping(true).set_radix_group_len(8);
+    /// const BITS: u16 = 0b1010_0101_1111;
+    /// assert_eq!(const_panic::concat_!(BIN; BITS), "0b1010_01011111");
+    ///
+    /// // decimal integers are grouped in 3 digits
+    /// const DEC: FmtArg = FmtArg::DEBUG.set_digit_grouping(true).set_radix_group_len(8);
+    /// const THOUSAND: u16 = 1000;
+    /// assert_eq!(const_panic::concat_!(DEC; THOUSAND), "1_000");
+    ///
+    /// // the digits aren't grouped unless `digit_grouping` is enabled
+    /// const HEX: FmtArg = FmtArg::ALT_HEX.set_radix_group_len(8);
+    /// const BEEF: u32 = 0xDEADBEEF;
+    /// assert_eq!(const_panic::concat_!(HEX; BEEF), "0xDEADBEEF");
+    ///
+    /// ```
+    #[track_caller]
+    pub const fn set_radix_group_len(mut self, radix_group_len: u8) -> Self {
+        if radix_group_len < MIN_RADIX_GROUP_LEN || radix_group_len > MAX_RADIX_GROUP_LEN {
+            crate::concat_panic(&[&[
+                crate::PanicVal::write_str("`radix_group_len` must be in the "),
+                crate::PanicVal::from_u8(MIN_RADIX_GROUP_LEN, FmtArg::DISPLAY),
+                crate::PanicVal::write_str("..="),
+                crate::PanicVal::from_u8(MAX_RADIX_GROUP_LEN, FmtArg::DISPLAY),
+                crate::PanicVal::write_str(" range, found: "),
+                crate::PanicVal::from_u8(radix_group_len, FmtArg::DISPLAY),
+            ]]);
+        }
+        self.radix_group_len = radix_group_len;
+        self
+    }
+
+    /// Sets how much of structs and enum variants is written with `Display` formatting.
+    ///
+    /// This is supported by the structs and enums that use
+    /// the [`PanicFmt` derive](derive@crate::PanicFmt), [`impl_panicfmt`],
+    /// and the `variant:`/`fields:` syntax of
+    /// [`flatten_panicvals`].
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg, impl_panicfmt};
+    /// use const_panic::fmt::StructStyle;
+    ///
+    /// const POINT: Point = Point { x: 3, y: 5 };
+    ///
+    /// const NO_NAMES: FmtArg = FmtArg::DISPLAY.set_struct_style(StructStyle::NoFieldNames);
+    /// assert_eq!(const_panic::concat_!(NO_NAMES; POINT), "Point { 3, 5 }");
+    ///
+    /// const VALUES: FmtArg = FmtArg::DISPLAY.set_struct_style(StructStyle::ValuesOnly);
+    /// assert_eq!(const_panic::concat_!(VALUES; POINT), "{ 3, 5 }");
+    ///
+    /// // Debug formatting ignores `struct_style`
+    /// const DEBUG: FmtArg = FmtArg::DEBUG.set_struct_style(StructStyle::ValuesOnly);
+    /// assert_eq!(const_panic::concat_!(DEBUG; POINT), "Point { x: 3, y: 5 }");
+    ///
+    /// struct Point {
+    ///     x: u32,
+    ///     y: u32,
+    /// }
+    ///
+    /// impl_panicfmt!{
+    ///     struct Point {
+    ///         x: u32,
+    ///         y: u32,
+    ///     }
+    /// }
+    /// ```
+    pub const fn set_struct_style(mut self, struct_style: StructStyle) -> Self {
+        self.struct_style = struct_style;
+        self
+    }
+
+    /// Sets the column at which `Display`-formatted strings are soft-wrapped,
+    /// `None` disables wrapping.
+    ///
+    /// Lines are broken at the last space that fits in `wrap_width` columns
+    /// (replacing that space with a newline),
+    /// words longer than `wrap_width` are broken wherever the line is full.
+    /// Newlines in the string start a new line as usual.
+    ///
+    /// Columns are counted in `char`s, starting from the start of the string,
+    /// regardless of what was written before it.
+    ///
+    /// Wrapping is not supported for:
+    /// - the elements of slices and arrays: the wrap width isn't stored in their
+    /// [`PackedFmtArg`], so they're written unwrapped.
+    /// - padding: wrapped strings ignore
+    /// [`PanicVal::with_leftpad`](crate::PanicVal::with_leftpad) and
+    /// [`PanicVal::with_rightpad`](crate::PanicVal::with_rightpad).
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const FMT: FmtArg = FmtArg::DISPLAY.set_wrap_width(Some(16));
+    ///
+    /// const DESCRIPTION: &str = "the configuration file is missing";
+    /// const PATH: &str = "/etc/const_panic/config";
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; DESCRIPTION),
+    ///     "the\nconfiguration\nfile is missing",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; PATH),
+    ///     "/etc/const_panic\n/config",
+    /// );
+    /// ```
+    pub const fn set_wrap_width(mut self, wrap_width: Option<u16>) -> Self {
+        self.wrap_width = match wrap_width {
+            Some(x) => x,
+            None => 0,
+        };
+        self
+    }
+
     /// Changes the formatting to `Display`.
     pub const fn set_display(mut self) -> Self {
         self.fmt_kind = FmtKind::Display;
@@ -327,22 +1062,263 @@ impl FmtArg {
         self.number_fmt = NumberFmt::Binary;
         self
     }
+
+    const fn with_flag(mut self, flag: u16, enabled: bool) -> Self {
+        if enabled {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+        self
+    }
+
+    const fn flag(self, flag: u16) -> bool {
+        (self.flags & flag) != 0
+    }
+
+    // The byte that indentation is written with
+    pub(crate) const fn indentation_byte(self) -> u8 {
+        if self.tab_indentation() {
+            b'\t'
+        } else {
+            b' '
+        }
+    }
+}
+
+// The getters of the options that are set with the `set_*` methods
+impl FmtArg {
+    /// How much indentation is added for each level of nesting,
+    /// by [`FmtArg::indent`], and removed by [`FmtArg::unindent`].
+    ///
+    /// This defaults to [`fmt::INDENTATION_STEP`](crate::fmt::INDENTATION_STEP),
+    /// which is `4`, and is at most `15`,
+    /// set with [`set_indentation_step`](Self::set_indentation_step).
+    pub const fn indentation_step(self) -> u8 {
+        self.indentation_step
+    }
+
+    /// The indentation that [`FmtArg::indent`] doesn't increase
+    /// [`indentation`](#structfield.indentation) past,
+    /// which bounds the length of the lines of deeply nested values.
+    ///
+    /// This defaults to `u8::MAX`.
+    pub const fn max_indentation(self) -> u8 {
+        self.max_indentation
+    }
+
+    /// Whether indentation is written with tabs, instead of spaces.
+    ///
+    /// Each unit of [indentation](#structfield.indentation) is written as one tab,
+    /// so this is usually combined with an
+    /// [`indentation_step`](Self::indentation_step) of `1`.
+    pub const fn tab_indentation(self) -> bool {
+        self.flag(flags::TAB_INDENTATION)
+    }
+
+    /// Whether `bool`s are formatted as `yes`/`no`,
+    /// and `Option<bool>`s as `yes`/`no`/`unset`.
+    pub const fn yes_no(self) -> bool {
+        self.flag(flags::YES_NO)
+    }
+
+    /// Whether non-negative integers are prefixed with a `+`,
+    /// like the `{:+}` flag of `core::fmt`.
+    pub const fn plus_sign(self) -> bool {
+        self.flag(flags::PLUS_SIGN)
+    }
+
+    /// Whether negative integers are formatted as a `-` followed by their magnitude
+    /// with hexadecimal and binary formatting,
+    /// instead of as the two's complement bit pattern of their type (the default).
+    pub const fn signed_hex_bin(self) -> bool {
+        self.flag(flags::SIGNED_HEX_BIN)
+    }
+
+    /// Whether slices and arrays are surrounded by `{}` braces, instead of `[]` brackets.
+    pub const fn brace_slices(self) -> bool {
+        self.flag(flags::BRACE_SLICES)
+    }
+
+    /// Whether `Some` is written on the same line as the value it wraps
+    /// when using alternate formatting.
+    pub const fn compact_option(self) -> bool {
+        self.flag(flags::COMPACT_OPTION)
+    }
+
+    /// Whether `Option`s are `Display` formatted as just the value that `Some` wraps,
+    /// and as nothing for `None`.
+    pub const fn transparent_option(self) -> bool {
+        self.flag(flags::TRANSPARENT_OPTION)
+    }
+
+    /// Whether `Display`-formatted strings are quoted when they contain whitespace
+    /// or characters that are special in shells.
+    pub const fn shell_quote(self) -> bool {
+        self.flag(flags::SHELL_QUOTE)
+    }
+
+    /// Whether `Display`-formatted strings have their control characters escaped,
+    /// eg: newlines are written as `\n`.
+    pub const fn escape_control_in_display(self) -> bool {
+        self.flag(flags::ESCAPE_CONTROL_IN_DISPLAY)
+    }
+
+    /// Whether `Debug`-formatted strings are delimited with single quotes (`'`),
+    /// instead of double quotes (`"`).
+    ///
+    /// Only the quote character that delimits the string is escaped inside of it.
+    /// `char`s are always delimited with single quotes.
+    pub const fn single_quotes(self) -> bool {
+        self.flag(flags::SINGLE_QUOTES)
+    }
+
+    /// Whether the digits of integers are grouped with `_` separators,
+    /// in groups of 3 digits for decimal, and of [`radix_group_len`](Self::radix_group_len)
+    /// digits for hexadecimal and binary.
+    pub const fn digit_grouping(self) -> bool {
+        self.flag(flags::DIGIT_GROUPING)
+    }
+
+    /// The amount of digits between the `_` separators of
+    /// hexadecimal and binary integers when [`digit_grouping`](Self::digit_grouping)
+    /// is enabled, `4` by default.
+    pub const fn radix_group_len(self) -> u8 {
+        self.radix_group_len
+    }
+
+    /// How much of structs and enum variants is written with `Display` formatting,
+    /// `Debug` formatting always writes the type and field names.
+    pub const fn struct_style(self) -> StructStyle {
+        self.struct_style
+    }
+
+    /// The column at which `Display`-formatted strings are soft-wrapped,
+    /// `None` if they aren't wrapped.
+    pub const fn wrap_width(self) -> Option<u16> {
+        match self.wrap_width {
+            0 => None,
+            x => Some(x),
+        }
+    }
 }
 
+// The largest `indentation_step` and `radix_group_len`,
+// limited by the bits that `PackedFmtArg` stores them in.
+#[cfg(feature = "non_basic")]
+pub(crate) const MAX_INDENTATION_STEP: u8 = 15;
+pub(crate) const MAX_RADIX_GROUP_LEN: u8 = 63;
+
+// The smallest `radix_group_len`, so that grouped integers fit in `string_cap::LARGE` bytes.
+pub(crate) const MIN_RADIX_GROUP_LEN: u8 = 4;
+
+// The most levels of nesting that `FmtArg::capped_indents` counts.
+#[cfg(feature = "non_basic")]
+pub(crate) const MAX_CAPPED_INDENTS: u8 = 127;
+
 #[cfg(feature = "non_basic")]
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
 impl FmtArg {
-    /// Increments the indentation by [`INDENTATION_STEP`] spaces.
+    /// Increments the indentation by
+    /// [`self.indentation_step()`](Self::indentation_step)
+    /// ([`INDENTATION_STEP`] by default).
+    ///
+    /// The indentation stops growing once another step would go past
+    /// [`self.max_indentation()`](Self::max_indentation),
+    /// [`unindent`](Self::unindent) undoes the levels that didn't add indentation first.
+    /// This counts up to `127` levels.
     pub const fn indent(mut self) -> Self {
-        self.indentation += INDENTATION_STEP;
+        match self.indentation.checked_add(self.indentation_step) {
+            Some(x) if x <= self.max_indentation && self.capped_indents == 0 => {
+                self.indentation = x;
+            }
+            _ if self.capped_indents < MAX_CAPPED_INDENTS => self.capped_indents += 1,
+            _ => {}
+        }
         self
     }
 
-    /// Decrement the indentation by [`INDENTATION_STEP`] spaces.
+    /// Decrement the indentation by
+    /// [`self.indentation_step()`](Self::indentation_step)
+    /// ([`INDENTATION_STEP`] by default).
+    ///
+    /// This undoes the levels of nesting that [`indent`](Self::indent)
+    /// didn't add indentation for first, without changing the indentation.
     pub const fn unindent(mut self) -> Self {
-        self.indentation = self.indentation.saturating_sub(INDENTATION_STEP);
+        if self.capped_indents != 0 {
+            self.capped_indents -= 1;
+        } else {
+            self.indentation = self.indentation.saturating_sub(self.indentation_step);
+        }
+        self
+    }
+
+    /// Sets how much indentation is added for each level of nesting.
+    ///
+    /// Steps above `15` are treated as `15`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const NESTED: &[&[u8]] = &[&[3], &[5]];
+    /// const FMT: FmtArg = FmtArg::ALT_DEBUG.set_indentation_step(2);
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; NESTED),
+    ///     "[\n  [\n    3,\n  ],\n  [\n    5,\n  ],\n]",
+    /// );
+    /// ```
+    pub const fn set_indentation_step(mut self, indentation_step: u8) -> Self {
+        self.indentation_step = crate::utils::min_u8(indentation_step, MAX_INDENTATION_STEP);
         self
     }
+
+    /// Sets the indentation that [`indent`](Self::indent) doesn't increase
+    /// the indentation past.
+    ///
+    /// This bounds the amount of indentation written for deeply nested values,
+    /// so that they don't fill the panic message with whitespace.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const NESTED: &[&[u8]] = &[&[3], &[5]];
+    /// const FMT: FmtArg = FmtArg::ALT_DEBUG.set_max_indentation(4);
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; NESTED),
+    ///     "[\n    [\n    3,\n    ],\n    [\n    5,\n    ],\n]",
+    /// );
+    /// ```
+    pub const fn set_max_indentation(mut self, max_indentation: u8) -> Self {
+        self.max_indentation = max_indentation;
+        self
+    }
+
+    /// Sets whether indentation is written with tabs, instead of spaces.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{ArrayString, FmtArg};
+    ///
+    /// const NESTED: &[&[u8]] = &[&[3], &[5]];
+    /// const FMT: FmtArg = FmtArg::ALT_DEBUG
+    ///     .set_indentation_step(1)
+    ///     .set_tab_indentation(true);
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(FMT; NESTED),
+    ///     "[\n\t[\n\t\t3,\n\t],\n\t[\n\t\t5,\n\t],\n]",
+    /// );
+    /// ```
+    pub const fn set_tab_indentation(self, tab_indentation: bool) -> Self {
+        self.with_flag(flags::TAB_INDENTATION, tab_indentation)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -359,6 +1335,53 @@ pub enum FmtKind {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// How much of structs and enum variants is written with `Display` formatting.
+///
+/// Variants without fields are always written as their name.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StructStyle {
+    /// Written like `Debug` does, eg: `Foo { x: 3, y: 5 }`, `Bar(8)`.
+    Full = 0,
+    /// Without field names, eg: `Foo { 3, 5 }`, `Bar(8)`.
+    NoFieldNames = 1,
+    /// Without the type and field names, eg: `{ 3, 5 }`, `(8)`.
+    ValuesOnly = 2,
+}
+
+#[cfg(feature = "non_basic")]
+impl StructStyle {
+    /// The `StructStyle` used by `fmtarg`,
+    /// which is always `Full` for `Debug` formatting.
+    pub(crate) const fn of(fmtarg: FmtArg) -> Self {
+        match fmtarg.fmt_kind {
+            FmtKind::Display => fmtarg.struct_style,
+            FmtKind::Debug => Self::Full,
+        }
+    }
+}
+
+// The `FmtArg` for a field with a `#[pfmt(fmtarg = ...)]` override in the `PanicFmt` derive.
+//
+// The layout state (indentation, indentation step, capped indents, tab indentation)
+// comes from `outer`, alternate formatting is used if either enables it,
+// and the other options are replaced by those of `field`,
+// so that `field` can disable options that `outer` enables.
+#[doc(hidden)]
+#[cfg(feature = "non_basic")]
+pub const fn merge_field_fmtarg(outer: FmtArg, field: FmtArg) -> FmtArg {
+    FmtArg {
+        is_alternate: outer.is_alternate || field.is_alternate,
+        fmt_kind: field.fmt_kind,
+        number_fmt: field.number_fmt,
+        radix_group_len: field.radix_group_len,
+        struct_style: field.struct_style,
+        flags: (outer.flags & flags::TAB_INDENTATION) | (field.flags & !flags::TAB_INDENTATION),
+        wrap_width: field.wrap_width,
+        ..outer
+    }
+}
+
 /// What integers are formatted as.
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -370,3 +1393,41 @@ pub enum NumberFmt {
     /// Formatted as hexadecimal, eg: `FAD`, `0xDE`.
     Hexadecimal = 2,
 }
+
+/// Constructs a `PanicVal` with the name of the `T` type,
+/// as returned by [`core::any::type_name`].
+///
+/// This is for panics in generic code that report which type caused the failure.
+///
+/// # MSRV
+///
+/// This function is not a `const fn`, because
+/// [`core::any::type_name`] can't be called in const contexts on stable Rust,
+/// so it can only be used for panics (or formatting) that happen at runtime.
+/// It can become a `const fn` once `type_name` is const-stable,
+/// which will require a cargo feature for the Rust version that stabilizes it.
+///
+/// The returned name is only meant for diagnostics,
+/// [its exact contents are unspecified](core::any::type_name#note).
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{fmt, format_into, PanicVal};
+///
+/// fn describe<T: ?Sized>(buffer: &mut [u8]) -> &str {
+///     let len = format_into(buffer, &[&[
+///         PanicVal::write_str("expected a nonzero "),
+///         fmt::type_name::<T>(),
+///     ]]).unwrap();
+///
+///     std::str::from_utf8(&buffer[..len]).unwrap()
+/// }
+///
+/// assert_eq!(describe::<u16>(&mut [0; 64]), "expected a nonzero u16");
+/// assert_eq!(describe::<[bool]>(&mut [0; 64]), "expected a nonzero [bool]");
+///
+/// ```
+pub fn type_name<T: ?Sized>() -> crate::PanicVal<'static> {
+    crate::PanicVal::write_str(core::any::type_name::<T>())
+}
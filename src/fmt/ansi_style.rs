@@ -0,0 +1,55 @@
+//! ANSI escape sequences for styling panic messages.
+
+use crate::PanicVal;
+
+macro_rules! declare_styles {
+    ($( $(#[$attr:meta])* $name:ident = $code:literal; )*) => {
+        $(
+            $(#[$attr])*
+            ///
+            /// This is empty unless the `"style"` feature is enabled,
+            /// when enabled, the escape sequence counts towards the length of the message
+            /// (eg: towards [`MAX_PANIC_MSG_LEN`](crate::MAX_PANIC_MSG_LEN) for panics).
+            pub const $name: PanicVal<'static> = PanicVal::write_str(
+                if cfg!(feature = "style") { concat!("\x1b[", $code, "m") } else { "" }
+            );
+        )*
+    };
+}
+
+declare_styles! {
+    /// Resets all styling, written as `"\x1b[0m"`.
+    RESET = "0";
+    /// Bold text, written as `"\x1b[1m"`.
+    BOLD = "1";
+    /// Dim text, written as `"\x1b[2m"`.
+    DIM = "2";
+    /// Underlined text, written as `"\x1b[4m"`.
+    UNDERLINE = "4";
+    /// Red text, written as `"\x1b[31m"`.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(all(feature = "style", feature = "non_basic"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "style", feature = "non_basic")), doc = "```ignore")]
+    /// use const_panic::{ArrayString, fmt};
+    ///
+    /// const COUNT: u32 = 3;
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(fmt::BOLD, fmt::RED, "error", fmt::RESET, ": ", COUNT),
+    ///     "\x1b[1m\x1b[31merror\x1b[0m: 3",
+    /// );
+    /// ```
+    RED = "31";
+    /// Green text, written as `"\x1b[32m"`.
+    GREEN = "32";
+    /// Yellow text, written as `"\x1b[33m"`.
+    YELLOW = "33";
+    /// Blue text, written as `"\x1b[34m"`.
+    BLUE = "34";
+    /// Magenta text, written as `"\x1b[35m"`.
+    MAGENTA = "35";
+    /// Cyan text, written as `"\x1b[36m"`.
+    CYAN = "36";
+}
@@ -1,6 +1,7 @@
 //! `char`-formatted related items
 
 use crate::{
+    debug_str_fmt::hex_as_ascii,
     fmt::{FmtArg, FmtKind},
     fmt_impls::basic_fmt_impls::primitive_static_panicfmt,
     panic_val::{PanicVal, PanicVariant},
@@ -32,6 +33,91 @@ impl PanicVal<'_> {
             var: PanicVariant::PreFmt(prefmt),
         }
     }
+
+    /// Constructs a `PanicVal` from a byte, formatted like a byte literal.
+    ///
+    /// With `Debug` formatting, this is written as a byte literal, eg: `b'A'`,
+    /// escaping the non-printable and non-ASCII bytes, eg: `b'\n'`, `b'\x7f'`, `b'\xff'`.
+    ///
+    /// With `Display` formatting, ASCII bytes are written as the `char` they represent,
+    /// while non-ASCII bytes are written escaped, eg: `\xff`.
+    ///
+    /// To write both the number and the character,
+    /// use [`from_u8_with_ascii`](Self::from_u8_with_ascii).
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg, PanicVal};
+    ///
+    /// const DELIM: u8 = b';';
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<99>::from_panicvals(&[
+    ///         PanicVal::write_str("delimiter: "),
+    ///         PanicVal::from_u8(DELIM, FmtArg::DISPLAY),
+    ///         PanicVal::write_str(" "),
+    ///         PanicVal::from_ascii_byte(DELIM, FmtArg::DEBUG),
+    ///         PanicVal::write_str(" "),
+    ///         PanicVal::from_ascii_byte(DELIM, FmtArg::DISPLAY),
+    ///     ]).unwrap(),
+    ///     "delimiter: 59 b';' ;",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<99>::from_panicvals(&[
+    ///         PanicVal::from_ascii_byte(b'\t', FmtArg::DEBUG),
+    ///         PanicVal::from_ascii_byte(0xFF, FmtArg::DEBUG),
+    ///         PanicVal::from_ascii_byte(0xFF, FmtArg::DISPLAY),
+    ///     ]).unwrap(),
+    ///     r"b'\t'b'\xff'\xff",
+    /// );
+    ///
+    /// ```
+    pub const fn from_ascii_byte(b: u8, fmtarg: FmtArg) -> Self {
+        let is_debug = matches!(fmtarg.fmt_kind, FmtKind::Debug);
+        let (escaped, escaped_len) = escape_byte(b, is_debug, b'\'');
+
+        let mut arr = [0u8; 7];
+        let mut len = 0;
+        if is_debug {
+            arr[0] = b'b';
+            arr[1] = b'\'';
+            len = 2;
+        }
+        let mut i = 0;
+        while i < escaped_len {
+            arr[len] = escaped[i];
+            len += 1;
+            i += 1;
+        }
+        if is_debug {
+            arr[len] = b'\'';
+            len += 1;
+        }
+
+        Self::from_ascii_bytes(&arr, len)
+    }
+
+    // Constructs a `PanicVal` from a byte of a byte string,
+    // escaped like in `b"..."` literals when using `Debug` formatting.
+    #[cfg(feature = "non_basic")]
+    pub(crate) const fn from_byte_str_byte(b: u8, fmtarg: FmtArg) -> Self {
+        let is_debug = matches!(fmtarg.fmt_kind, FmtKind::Debug);
+        let (escaped, len) = escape_byte(b, is_debug, b'"');
+        Self::from_ascii_bytes(&escaped, len)
+    }
+
+    const fn from_ascii_bytes(arr: &[u8], len: usize) -> Self {
+        let StartAndBytes { start, bytes } =
+            crate::utils::tail_byte_array::<{ string_cap::PREFMT }>(len, arr);
+        // SAFETY: escape_byte only writes ASCII bytes, and so do the callers of this function.
+        let prefmt = unsafe { PreFmtString::new(start, bytes) };
+        PanicVal {
+            var: PanicVariant::PreFmt(prefmt),
+        }
+    }
 }
 
 primitive_static_panicfmt! {
@@ -40,21 +126,32 @@ primitive_static_panicfmt! {
     }
 }
 
-/// Converts 0..=0xF to its ascii representation of '0'..='9' and 'A'..='F'
-#[inline]
-const fn hex_as_ascii(n: u8) -> u8 {
-    if n < 10 {
-        n + b'0'
-    } else {
-        n - 10 + b'A'
+// Escapes a byte the same as in byte literals delimited by `quote` if `is_debug`,
+// otherwise only non-ASCII bytes are escaped.
+//
+// Returns the written ASCII bytes and how many of them are used.
+const fn escape_byte(b: u8, is_debug: bool, quote: u8) -> ([u8; 4], usize) {
+    match b {
+        0..=0x7F if !is_debug => ([b, 0, 0, 0], 1),
+        b' '..=b'~' if b != b'\\' && b != quote => ([b, 0, 0, 0], 1),
+        b'\0' => ([b'\\', b'0', 0, 0], 2),
+        b'\t' => ([b'\\', b't', 0, 0], 2),
+        b'\r' => ([b'\\', b'r', 0, 0], 2),
+        b'\n' => ([b'\\', b'n', 0, 0], 2),
+        b'\\' | b'\'' | b'"' => ([b'\\', b, 0, 0], 2),
+        _ => (
+            [b'\\', b'x', hex_as_ascii(b >> 4), hex_as_ascii(b & 0b1111)],
+            4,
+        ),
     }
 }
 
 #[cfg(any(test, feature = "fmt"))]
 pub(crate) const fn char_debug_len(c: char) -> usize {
     let inner = match c {
-        '\t' | '\r' | '\n' | '\\' | '\'' => 2,
-        '\x00'..='\x1F' => 4,
+        '\0' | '\t' | '\r' | '\n' | '\\' | '\'' => 2,
+        '\x01'..='\x0F' => 5,
+        '\x10'..='\x1F' | '\x7F' => 6,
         _ => c.len_utf8(),
     };
     inner + 2
@@ -95,25 +192,48 @@ pub const fn char_to_display(char: char) -> FmtChar {
 }
 
 /// Debug formats a `char`
+///
+/// ASCII characters are escaped the same way as `<char as Debug>::fmt` escapes them,
+/// non-ASCII characters are always written as-is.
 pub const fn char_to_debug(c: char) -> FmtChar {
-    let ([b0, b1, b2, b3], len) = match c {
-        '\t' => (*br#"\t  "#, 2),
-        '\r' => (*br#"\r  "#, 2),
-        '\n' => (*br#"\n  "#, 2),
-        '\\' => (*br#"\\  "#, 2),
-        '\'' => (*br#"\'  "#, 2),
-        '\"' => (*br#""   "#, 1),
-        '\x00'..='\x1F' => {
+    let mut encoded = [b'\'', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+    let len = match c {
+        '\0' | '\t' | '\r' | '\n' | '\\' | '\'' => {
+            encoded[1] = b'\\';
+            encoded[2] = match c {
+                '\0' => b'0',
+                '\t' => b't',
+                '\r' => b'r',
+                '\n' => b'n',
+                _ => c as u8,
+            };
+            2
+        }
+        '\x01'..='\x1F' | '\x7F' => {
             let n = c as u8;
-            (
-                [b'\\', b'x', hex_as_ascii(n >> 4), hex_as_ascii(n & 0b1111)],
-                4,
-            )
+            encoded[1] = b'\\';
+            encoded[2] = b'u';
+            encoded[3] = b'{';
+            let mut i = 4;
+            if n >= 0x10 {
+                encoded[i] = hex_as_ascii(n >> 4);
+                i += 1;
+            }
+            encoded[i] = hex_as_ascii(n & 0b1111);
+            encoded[i + 1] = b'}';
+            i + 1
+        }
+        _ => {
+            let ([b0, b1, b2, b3], len) = char_to_utf8(c);
+            encoded[1] = b0;
+            encoded[2] = b1;
+            encoded[3] = b2;
+            encoded[4] = b3;
+            len
         }
-        _ => char_to_utf8(c),
     };
 
-    let mut encoded = [b'\'', b0, b1, b2, b3, 0, 0, 0, 0, 0, 0, 0];
     encoded[len + 1] = b'\'';
 
     FmtChar {
@@ -29,38 +29,38 @@ fn char_to_utf8_display_test() {
 #[test]
 fn char_to_utf8_debug_test() {
     let first_escapes = [
-        ('\x00', r#"'\x00'"#),
-        ('\x01', r#"'\x01'"#),
-        ('\x02', r#"'\x02'"#),
-        ('\x03', r#"'\x03'"#),
-        ('\x04', r#"'\x04'"#),
-        ('\x05', r#"'\x05'"#),
-        ('\x06', r#"'\x06'"#),
-        ('\x07', r#"'\x07'"#),
-        ('\x08', r#"'\x08'"#),
+        ('\x00', r#"'\0'"#),
+        ('\x01', r#"'\u{1}'"#),
+        ('\x02', r#"'\u{2}'"#),
+        ('\x03', r#"'\u{3}'"#),
+        ('\x04', r#"'\u{4}'"#),
+        ('\x05', r#"'\u{5}'"#),
+        ('\x06', r#"'\u{6}'"#),
+        ('\x07', r#"'\u{7}'"#),
+        ('\x08', r#"'\u{8}'"#),
         ('\t', r#"'\t'"#),
         ('\n', r#"'\n'"#),
-        ('\x0B', r#"'\x0B'"#),
-        ('\x0C', r#"'\x0C'"#),
+        ('\x0B', r#"'\u{b}'"#),
+        ('\x0C', r#"'\u{c}'"#),
         ('\r', r#"'\r'"#),
-        ('\x0E', r#"'\x0E'"#),
-        ('\x0F', r#"'\x0F'"#),
-        ('\x10', r#"'\x10'"#),
-        ('\x11', r#"'\x11'"#),
-        ('\x12', r#"'\x12'"#),
-        ('\x13', r#"'\x13'"#),
-        ('\x14', r#"'\x14'"#),
-        ('\x15', r#"'\x15'"#),
-        ('\x16', r#"'\x16'"#),
-        ('\x17', r#"'\x17'"#),
-        ('\x18', r#"'\x18'"#),
-        ('\x19', r#"'\x19'"#),
-        ('\x1A', r#"'\x1A'"#),
-        ('\x1B', r#"'\x1B'"#),
-        ('\x1C', r#"'\x1C'"#),
-        ('\x1D', r#"'\x1D'"#),
-        ('\x1E', r#"'\x1E'"#),
-        ('\x1F', r#"'\x1F'"#),
+        ('\x0E', r#"'\u{e}'"#),
+        ('\x0F', r#"'\u{f}'"#),
+        ('\x10', r#"'\u{10}'"#),
+        ('\x11', r#"'\u{11}'"#),
+        ('\x12', r#"'\u{12}'"#),
+        ('\x13', r#"'\u{13}'"#),
+        ('\x14', r#"'\u{14}'"#),
+        ('\x15', r#"'\u{15}'"#),
+        ('\x16', r#"'\u{16}'"#),
+        ('\x17', r#"'\u{17}'"#),
+        ('\x18', r#"'\u{18}'"#),
+        ('\x19', r#"'\u{19}'"#),
+        ('\x1A', r#"'\u{1a}'"#),
+        ('\x1B', r#"'\u{1b}'"#),
+        ('\x1C', r#"'\u{1c}'"#),
+        ('\x1D', r#"'\u{1d}'"#),
+        ('\x1E', r#"'\u{1e}'"#),
+        ('\x1F', r#"'\u{1f}'"#),
     ];
 
     for (c, expected) in first_escapes.iter().copied() {
@@ -69,7 +69,12 @@ fn char_to_utf8_debug_test() {
         assert_eq!(expected.len(), char_debug_len(c), "{:?}", c);
     }
 
-    let other_escapes = [('\'', r#"'\''"#), ('\"', r#"'"'"#), ('\\', r#"'\\'"#)];
+    let other_escapes = [
+        ('\'', r#"'\''"#),
+        ('\"', r#"'"'"#),
+        ('\\', r#"'\\'"#),
+        ('\x7F', r#"'\u{7f}'"#),
+    ];
 
     let mut buffer = arrayvec::ArrayString::<12>::new();
     for c in '\x20'..=core::char::MAX {
@@ -0,0 +1,184 @@
+use crate::{
+    panic_val::{PanicVal, PanicVariant},
+    utils::{string_cap, PreFmtString},
+    FmtArg, NotEnoughSpace, PanicFmt,
+};
+
+use core::fmt::{self, Debug};
+
+/// A small buffer of ASCII bytes, for formatting numeric types
+/// that can't be converted to the integers supported by [`PanicVal`].
+///
+/// This is converted into a `PanicVal<'static>` which stores a copy of the bytes,
+/// so it can be returned from `to_panicvals` methods.
+///
+/// # Example
+///
+/// Formatting a fixed-point number.
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{fmt::DigitBuffer, ArrayString, FmtArg, IsCustomType, PanicFmt, PanicVal};
+///
+/// const HALF: Fixed = Fixed(50);
+/// const PRICE: Fixed = Fixed(1205);
+///
+/// assert_eq!(const_panic::concat_!(HALF), "0.50");
+/// assert_eq!(const_panic::concat_!("costs ", PRICE), "costs 12.05");
+///
+/// // a number with two decimal digits
+/// struct Fixed(u32);
+///
+/// impl PanicFmt for Fixed {
+///     type This = Self;
+///     type Kind = IsCustomType;
+///     const PV_COUNT: usize = 1;
+/// }
+///
+/// impl Fixed {
+///     const fn to_panicvals(&self, _: FmtArg) -> [PanicVal<'static>; 1] {
+///         // the digits are written in reverse, starting from the last one
+///         let mut digits = [0u8; 10];
+///         let mut count = 0;
+///         let mut n = self.0;
+///         while n != 0 || count < 3 {
+///             digits[count] = b'0' + (n % 10) as u8;
+///             n /= 10;
+///             count += 1;
+///         }
+///
+///         let mut buffer = DigitBuffer::new();
+///         while count != 0 {
+///             count -= 1;
+///             buffer = buffer.push(digits[count]);
+///             if count == 2 {
+///                 buffer = buffer.push(b'.');
+///             }
+///         }
+///         [buffer.to_panicval()]
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone)]
+pub struct DigitBuffer {
+    len: u8,
+    buffer: [u8; DigitBuffer::CAPACITY],
+}
+
+impl DigitBuffer {
+    /// The maximum amount of bytes that this can hold.
+    pub const CAPACITY: usize = string_cap::PREFMT;
+
+    /// Constructs an empty `DigitBuffer`.
+    pub const fn new() -> Self {
+        Self {
+            len: 0,
+            buffer: [0; Self::CAPACITY],
+        }
+    }
+
+    /// Appends an ASCII `byte` to this buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte` isn't ASCII, or if this buffer is full.
+    #[track_caller]
+    pub const fn push(self, byte: u8) -> Self {
+        match self.try_push(byte) {
+            Ok(x) => x,
+            Err(_) => crate::concat_panic(&[&[
+                PanicVal::write_str("DigitBuffer is full, its capacity is "),
+                PanicVal::from_usize(Self::CAPACITY, FmtArg::DISPLAY),
+            ]]),
+        }
+    }
+
+    /// Appends an ASCII `byte` to this buffer,
+    /// returning an error if this buffer is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte` isn't ASCII.
+    #[track_caller]
+    pub const fn try_push(mut self, byte: u8) -> Result<Self, NotEnoughSpace> {
+        if !byte.is_ascii() {
+            crate::concat_panic(&[&[
+                PanicVal::write_str("expected an ASCII byte, found: "),
+                PanicVal::from_u8(byte, FmtArg::DEBUG),
+            ]]);
+        }
+
+        if self.len() == Self::CAPACITY {
+            return Err(NotEnoughSpace);
+        }
+
+        self.buffer[self.len as usize] = byte;
+        self.len += 1;
+        Ok(self)
+    }
+
+    /// The amount of bytes in this buffer.
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Whether this buffer is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the bytes in this buffer.
+    pub const fn as_bytes(&self) -> &[u8] {
+        let mut bytes: &[u8] = &self.buffer;
+        let mut to_remove = Self::CAPACITY - self.len();
+        while let ([rem @ .., _], 1..) = (bytes, to_remove) {
+            bytes = rem;
+            to_remove -= 1;
+        }
+        bytes
+    }
+
+    /// Creates a `PanicVal` that writes the bytes in this buffer.
+    pub const fn to_panicval(&self) -> PanicVal<'static> {
+        let len = self.len();
+        let start = Self::CAPACITY - len;
+
+        let mut tail = [0u8; Self::CAPACITY];
+        let mut i = 0;
+        while i < len {
+            tail[start + i] = self.buffer[i];
+            i += 1;
+        }
+
+        // safety: only ASCII bytes are ever written to the buffer
+        let prefmt = unsafe { PreFmtString::new(start as u8, tail) };
+        PanicVal {
+            var: PanicVariant::PreFmt(prefmt),
+        }
+    }
+
+    /// Creates a single element `PanicVal` array that writes the bytes in this buffer.
+    pub const fn to_panicvals(&self, _: FmtArg) -> [PanicVal<'static>; 1] {
+        [self.to_panicval()]
+    }
+}
+
+impl Debug for DigitBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // safety: only ASCII bytes are ever written to the buffer
+        let string = unsafe { core::str::from_utf8_unchecked(self.as_bytes()) };
+        Debug::fmt(string, f)
+    }
+}
+
+impl Default for DigitBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PanicFmt for DigitBuffer {
+    type This = Self;
+    type Kind = crate::fmt::IsCustomType;
+    const PV_COUNT: usize = 1;
+}
@@ -1,15 +1,45 @@
-use crate::fmt::{FmtArg, FmtKind, NumberFmt};
+use crate::fmt::{flags, FmtArg, FmtKind, NumberFmt, StructStyle};
+use crate::fmt::{MAX_INDENTATION_STEP, MAX_RADIX_GROUP_LEN};
 
 /// A version of FmtArg which occupies less space, but needs to be unpacked to be used.
+///
+/// This only stores the options that affect how the elements of slices are formatted,
+/// because it's stored in the `PanicVal`s of slices.
+/// [`unpack`](Self::unpack) returns the default value for the other options, which are:
+/// [`compact_option`](FmtArg::compact_option),
+/// [`transparent_option`](FmtArg::transparent_option),
+/// [`struct_style`](FmtArg::struct_style),
+/// and [`wrap_width`](FmtArg::wrap_width).
+///
+/// The levels of nesting that [`FmtArg::indent`] didn't add indentation for
+/// (because of [`max_indentation`](FmtArg::max_indentation))
+/// are stored as at most one level.
 #[derive(Copy, Clone)]
 #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+// packed so that `bitfields` doesn't increase the size of `PanicVal`s containing this
+#[repr(packed)]
 pub struct PackedFmtArg {
     indentation: u8,
-    bitfields: u8,
+    bitfields: u32,
 }
 
 const FMT_KIND_OFFSET: u8 = 1;
 const NUMBER_FMT_OFFSET: u8 = FMT_KIND_OFFSET + FmtKind::BITS;
+const FLAGS_OFFSET: u8 = NUMBER_FMT_OFFSET + NumberFmt::BITS;
+const IS_CAPPED_OFFSET: u8 = FLAGS_OFFSET + flags::SLICE_BITS;
+const INDENTATION_STEP_OFFSET: u8 = IS_CAPPED_OFFSET + 1;
+const MAX_INDENTATION_OFFSET: u8 = INDENTATION_STEP_OFFSET + bit_len(MAX_INDENTATION_STEP);
+const RADIX_GROUP_LEN_OFFSET: u8 = MAX_INDENTATION_OFFSET + 8;
+
+const FLAGS_MASK: u16 = (1 << flags::SLICE_BITS) - 1;
+
+// all the options must fit in `PackedFmtArg::bitfields`
+const _: () = assert!(RADIX_GROUP_LEN_OFFSET + bit_len(MAX_RADIX_GROUP_LEN) <= 32);
+
+// the amount of bits needed to store `max`, which must be one less than a power of two
+const fn bit_len(max: u8) -> u8 {
+    (max as u32 + 1).trailing_zeros() as u8
+}
 
 impl FmtArg {
     /// Converts this `FmtArg` into a `PackedFmtArg`,
@@ -21,13 +51,25 @@ impl FmtArg {
             is_alternate,
             fmt_kind,
             number_fmt,
+            indentation_step,
+            max_indentation,
+            capped_indents,
+            radix_group_len,
+            struct_style: _,
+            flags,
+            wrap_width: _,
         } = self;
 
         PackedFmtArg {
             indentation,
-            bitfields: is_alternate as u8
-                | ((fmt_kind as u8) << FMT_KIND_OFFSET)
-                | ((number_fmt as u8) << NUMBER_FMT_OFFSET),
+            bitfields: is_alternate as u32
+                | ((fmt_kind as u32) << FMT_KIND_OFFSET)
+                | ((number_fmt as u32) << NUMBER_FMT_OFFSET)
+                | (((flags & FLAGS_MASK) as u32) << FLAGS_OFFSET)
+                | (((capped_indents != 0) as u32) << IS_CAPPED_OFFSET)
+                | ((indentation_step as u32) << INDENTATION_STEP_OFFSET)
+                | ((max_indentation as u32) << MAX_INDENTATION_OFFSET)
+                | ((radix_group_len as u32) << RADIX_GROUP_LEN_OFFSET),
         }
     }
 }
@@ -35,16 +77,23 @@ impl FmtArg {
 impl PackedFmtArg {
     /// Converts this `PackedFmtArg` back into a `FmtArg`.
     pub const fn unpack(self) -> FmtArg {
-        let indentation = self.indentation;
-        let is_alternate = (self.bitfields & 1) != 0;
-        let fmt_kind = FmtKind::from_prim(self.bitfields >> FMT_KIND_OFFSET);
-        let number_fmt = NumberFmt::from_prim(self.bitfields >> NUMBER_FMT_OFFSET);
+        let bitfields = self.bitfields;
+        const fn field(bitfields: u32, offset: u8, max: u8) -> u8 {
+            ((bitfields >> offset) as u8) & max
+        }
 
         FmtArg {
-            indentation,
-            is_alternate,
-            fmt_kind,
-            number_fmt,
+            indentation: self.indentation,
+            is_alternate: (bitfields & 1) != 0,
+            fmt_kind: FmtKind::from_prim((bitfields >> FMT_KIND_OFFSET) as u8),
+            number_fmt: NumberFmt::from_prim((bitfields >> NUMBER_FMT_OFFSET) as u8),
+            indentation_step: field(bitfields, INDENTATION_STEP_OFFSET, MAX_INDENTATION_STEP),
+            max_indentation: field(bitfields, MAX_INDENTATION_OFFSET, u8::MAX),
+            capped_indents: field(bitfields, IS_CAPPED_OFFSET, 1),
+            radix_group_len: field(bitfields, RADIX_GROUP_LEN_OFFSET, MAX_RADIX_GROUP_LEN),
+            struct_style: StructStyle::Full,
+            flags: (bitfields >> FLAGS_OFFSET) as u16 & FLAGS_MASK,
+            wrap_width: 0,
         }
     }
 }
@@ -82,7 +131,7 @@ macro_rules! enum_prim {
 use enum_prim;
 
 enum_prim! {
-    FmtKind, 2;
+    FmtKind, 1;
     default Debug,
     Display,
 }
@@ -130,7 +130,9 @@ impl PanicFmt for Delimiter {
 ////////////////////////////////////////////////////////////////////////////////
 
 /// How much indentation (in spaces) is added with [`FmtArg::indent`],
-/// and removed with [`FmtArg::unindent`].
+/// and removed with [`FmtArg::unindent`], by default.
+///
+/// This can be changed with [`FmtArg::set_indentation_step`].
 ///
 /// [The FmtArg.indentation field](crate::FmtArg#structfield.indentation)
 /// is used by [`fmt::Delimiter`](crate::fmt::Delimiter)
@@ -164,7 +166,7 @@ pub const INDENTATION_STEP: u8 = 4;
 ///
 /// assert_eq!(
 ///     ArrayString::<20>::from_panicvals(&[pv_debug]).unwrap(),
-///     "\"foo\\n\\x00bar\"",
+///     "\"foo\\n\\0bar\"",
 /// );
 ///
 /// ```
@@ -179,6 +181,85 @@ impl<'a> PanicVal<'a> {
         use crate::panic_val::{PanicVariant, StrFmt};
         PanicVal::__new(PanicVariant::ShortString(StrFmt::new(f), this.to_compact()))
     }
+
+    /// Constructs a `PanicVal` from a [`StrOrShort`],
+    /// formatting either kind of string the same as a `&str`.
+    pub const fn from_str_or_short(this: StrOrShort<'a>, f: FmtArg) -> PanicVal<'a> {
+        match this {
+            StrOrShort::Str(x) => PanicVal::from_str(x, f),
+            StrOrShort::Short(x) => PanicVal::from_short_str(x, f),
+        }
+    }
+}
+
+/// Either a `&str` or a [`ShortString`],
+/// for strings that are sometimes borrowed and sometimes computed.
+///
+/// Both variants are formatted the same as a `&str`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{
+///     fmt::{ShortString, StrOrShort},
+///     ArrayString, FmtArg, PanicVal,
+/// };
+///
+/// const fn unit_name(index: u8) -> StrOrShort<'static> {
+///     match index {
+///         0 => StrOrShort::Str("main"),
+///         _ => match ShortString::concat_panicvals(&[&[
+///             PanicVal::write_str("worker"),
+///             PanicVal::from_u8(index, FmtArg::DISPLAY),
+///         ]]) {
+///             Some(x) => StrOrShort::Short(x),
+///             None => StrOrShort::Str("worker"),
+///         },
+///     }
+/// }
+///
+/// assert_eq!(const_panic::concat_!({}: unit_name(0)), "main");
+/// assert_eq!(const_panic::concat_!({}: unit_name(3)), "worker3");
+///
+/// let pv = PanicVal::from_str_or_short(unit_name(10), FmtArg::DEBUG);
+/// assert_eq!(ArrayString::<20>::from_panicvals(&[pv]).unwrap(), "\"worker10\"");
+///
+/// ```
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub enum StrOrShort<'a> {
+    /// A borrowed string
+    Str(&'a str),
+    /// A string stored inline
+    Short(ShortString),
+}
+
+impl<'a> StrOrShort<'a> {
+    /// Gets the string as a `&str`.
+    pub const fn to_str(&self) -> &str {
+        match self {
+            Self::Str(x) => x,
+            Self::Short(x) => x.to_str(),
+        }
+    }
+
+    /// Converts this `StrOrShort` into an array of one `PanicVal`,
+    /// formatted the same as a `&str`.
+    pub const fn to_panicvals(self, f: FmtArg) -> [PanicVal<'a>; 1] {
+        [PanicVal::from_str_or_short(self, f)]
+    }
+
+    /// Converts this `StrOrShort` into a `PanicVal`,
+    /// formatted the same as a `&str`.
+    pub const fn to_panicval(self, f: FmtArg) -> PanicVal<'a> {
+        PanicVal::from_str_or_short(self, f)
+    }
+}
+
+impl PanicFmt for StrOrShort<'_> {
+    type This = Self;
+    type Kind = IsCustomType;
+    const PV_COUNT: usize = 1;
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -445,6 +526,49 @@ pub enum IsLast {
     No,
 }
 
+impl IsLast {
+    /// Constructs an `IsLast` from whether the separator comes after the last field.
+    ///
+    /// This is useful for choosing the separator of each element in a loop,
+    /// when the amount of elements isn't known in advance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{
+    ///     fmt::{FmtArg, IsLast},
+    ///     ArrayString, PanicVal,
+    /// };
+    ///
+    /// const fn separated(elems: &[u8], f: FmtArg) -> [PanicVal<'static>; 8] {
+    ///     let mut out = [PanicVal::EMPTY; 8];
+    ///     let mut i = 0;
+    ///     while i < elems.len() {
+    ///         let is_last = IsLast::from_bool(i + 1 == elems.len());
+    ///         out[2 * i] = PanicVal::from_u8(elems[i], f);
+    ///         out[2 * i + 1] = PanicVal::from_element_separator(";", is_last, f);
+    ///         i += 1;
+    ///     }
+    ///     out
+    /// }
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<32>::from_panicvals(&separated(&[3, 5, 8], FmtArg::DEBUG)).unwrap(),
+    ///     "3; 5; 8",
+    /// );
+    ///
+    /// assert!(IsLast::from_bool(true) == IsLast::Yes);
+    /// assert!(IsLast::from_bool(false) == IsLast::No);
+    /// ```
+    pub const fn from_bool(is_last: bool) -> Self {
+        if is_last {
+            IsLast::Yes
+        } else {
+            IsLast::No
+        }
+    }
+}
+
 /// For [alternate flag]-aware separation of fields, collection elements, etc.
 ///
 /// # Example
@@ -549,3 +673,98 @@ impl PanicFmt for Separator<'_> {
     type Kind = IsCustomType;
     const PV_COUNT: usize = 1;
 }
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Formats the names of the flags in `value` that are set, separated by `" | "`,
+/// for formatting bitflags-style types.
+///
+/// A flag is set when all the bits of its mask are set in `value`,
+/// flags with a mask of `0` are never set.
+/// The names of the set flags are written in the order that they're declared in `flags`,
+/// and `(empty)` is written if no flag is set.
+/// Bits of `value` that aren't in any flag's mask are not written.
+///
+/// The names and separators are always written with `Display` formatting.
+///
+/// The returned array has a name (or `(empty)`) in every other element,
+/// with the separators in between, and the trailing elements are
+/// filled with [`PanicVal::EMPTY`].
+///
+/// # Panics
+///
+/// Panics if `LEN` is smaller than the amount of `PanicVal`s required
+/// for the flags set in `value`.
+/// `LEN` is always large enough if it's at least `2 * flags.len()`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{fmt, ArrayString, FmtArg, PanicFmt, PanicVal};
+///
+/// #[derive(Copy, Clone)]
+/// struct Perms(u8);
+///
+/// impl Perms {
+///     const READ: Self = Self(0b001);
+///     const WRITE: Self = Self(0b010);
+///     const EXEC: Self = Self(0b100);
+///
+///     const FLAGS: &'static [(u128, &'static str)] = &[
+///         (Self::READ.0 as u128, "READ"),
+///         (Self::WRITE.0 as u128, "WRITE"),
+///         (Self::EXEC.0 as u128, "EXEC"),
+///     ];
+///
+///     const fn to_panicvals(self, f: FmtArg) -> [PanicVal<'static>; 6] {
+///         fmt::flags(Self::FLAGS, self.0 as u128, f)
+///     }
+/// }
+///
+/// impl PanicFmt for Perms {
+///     type This = Self;
+///     type Kind = const_panic::IsCustomType;
+///     const PV_COUNT: usize = 6;
+/// }
+///
+/// assert_eq!(const_panic::concat_!(Perms(0b011)), "READ | WRITE");
+/// assert_eq!(const_panic::concat_!(Perms(0b110)), "WRITE | EXEC");
+/// assert_eq!(const_panic::concat_!(Perms::EXEC), "EXEC");
+/// assert_eq!(const_panic::concat_!(Perms(0)), "(empty)");
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub const fn flags<'a, const LEN: usize>(
+    flags: &[(u128, &'a str)],
+    value: u128,
+    f: FmtArg,
+) -> [PanicVal<'a>; LEN] {
+    let f = f.set_display();
+    let mut out = [PanicVal::EMPTY; LEN];
+    let mut len = 0;
+
+    let mut rem = flags;
+    while let [(mask, name), ref rem_ @ ..] = *rem {
+        rem = rem_;
+
+        if mask == 0 || value & mask != mask {
+            continue;
+        }
+
+        if len != 0 {
+            assert!(len < LEN, "LEN is too small for the set flags");
+            out[len] = PanicVal::from_str(" | ", f);
+            len += 1;
+        }
+
+        assert!(len < LEN, "LEN is too small for the set flags");
+        out[len] = PanicVal::from_str(name, f);
+        len += 1;
+    }
+
+    if len == 0 {
+        assert!(LEN != 0, "LEN is too small for the set flags");
+        out[0] = PanicVal::from_str("(empty)", f);
+    }
+
+    out
+}
@@ -0,0 +1,82 @@
+// The parts of structs and enum variants whose output depends on `FmtArg::struct_style`,
+// used by the `PanicFmt` derive, `impl_panicfmt`, and `flatten_panicvals`.
+
+use crate::{
+    fmt::{Delimiter, FmtArg, IsCustomType, StructStyle},
+    PanicFmt, PanicVal,
+};
+
+/// The name of a struct, or of an enum variant with fields.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct StructName<'a>(pub &'a str);
+
+/// The name of a field, including the `": "` after it.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct FieldName<'a>(pub &'a str);
+
+/// The delimiter that follows the name of a struct or enum variant.
+///
+/// This must be formatted with the `FmtArg` that's used for the fields,
+/// not with `open:` (which sets `Display` formatting).
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct StructOpen(pub Delimiter);
+
+macro_rules! impl_struct_part {
+    ($ty:ty) => {
+        impl PanicFmt for $ty {
+            type This = Self;
+            type Kind = IsCustomType;
+            const PV_COUNT: usize = 1;
+        }
+    };
+}
+
+impl_struct_part! {StructName<'_>}
+impl_struct_part! {FieldName<'_>}
+impl_struct_part! {StructOpen}
+
+impl<'a> StructName<'a> {
+    pub const fn to_panicval(&self, f: FmtArg) -> PanicVal<'a> {
+        match StructStyle::of(f) {
+            StructStyle::ValuesOnly => PanicVal::EMPTY,
+            _ => PanicVal::write_str(self.0),
+        }
+    }
+
+    pub const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'a>; 1] {
+        [self.to_panicval(f)]
+    }
+}
+
+impl<'a> FieldName<'a> {
+    pub const fn to_panicval(&self, f: FmtArg) -> PanicVal<'a> {
+        match StructStyle::of(f) {
+            StructStyle::Full => PanicVal::write_str(self.0),
+            _ => PanicVal::EMPTY,
+        }
+    }
+
+    pub const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'a>; 1] {
+        [self.to_panicval(f)]
+    }
+}
+
+impl StructOpen {
+    pub const fn to_panicval(&self, f: FmtArg) -> PanicVal<'static> {
+        // without a name before it, the space before the brace is removed
+        match (StructStyle::of(f), self.0, f.is_alternate) {
+            (StructStyle::ValuesOnly, Delimiter::OpenBrace, false) => PanicVal::write_str("{ "),
+            (StructStyle::ValuesOnly, Delimiter::OpenBrace, true) => {
+                PanicVal::write_str("{\n").with_rightpad(f)
+            }
+            (_, delim, _) => delim.to_panicval(f),
+        }
+    }
+
+    pub const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; 1] {
+        [self.to_panicval(f)]
+    }
+}
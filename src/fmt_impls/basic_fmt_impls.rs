@@ -1,21 +1,26 @@
 use crate::{
+    fmt::{FmtKind, NumberFmt},
     panic_val::{IntVal, PanicVal, PanicVariant, StrFmt},
     utils::Packed,
+    wrapped_str::WrappedStr,
     FmtArg, PanicFmt, StdWrapper,
 };
 
 macro_rules! primitive_static_panicfmt {
     (
+        $(#[$attr:meta])*
         fn[$($impl:tt)*](&$self:ident: $ty:ty, $f:ident) {
             $($content:tt)*
         }
     ) => {
+        $(#[$attr])*
         impl<$($impl)*> crate::PanicFmt for $ty {
             type This = Self;
             type Kind = crate::fmt::IsStdType;
             const PV_COUNT: usize = 1;
         }
 
+        $(#[$attr])*
         impl<$($impl)*> crate::StdWrapper<&$ty> {
             #[doc = concat!(
                 "Converts this `", stringify!($ty), "` to a single-element `PanicVal` array."
@@ -99,14 +104,68 @@ macro_rules! impl_panicfmt_panicarg {
     }
 }
 
+// documents how negative integers are written in hexadecimal and binary
+macro_rules! int_ctor_docs {
+    (from_u128, $ty:ty) => {
+        ""
+    };
+    (from_i128, $ty:ty) => {
+        concat!(
+            "\n",
+            "With hexadecimal and binary formatting, negative integers are written as ",
+            "their two's complement bit pattern, masked to the width of `",
+            stringify!($ty),
+            "` (like `core::fmt` does),\n",
+            "unless [`FmtArg::signed_hex_bin`](crate::FmtArg::signed_hex_bin) ",
+            "is enabled.",
+        )
+    };
+}
+
 macro_rules! impl_panicfmt_int {
-    ($panic_arg_ctor:ident, $intarg_contructor:ident, $ty:ty) => {
+    ($panic_arg_ctor:ident, $dual_radix_ctor:ident, $intarg_contructor:ident, $ty:ty) => {
         impl PanicVal<'_> {
             /// Constructs this `PanicVal` from an integer.
+            #[doc = int_ctor_docs!($intarg_contructor, $ty)]
             pub const fn $panic_arg_ctor(this: $ty, f: FmtArg) -> PanicVal<'static> {
                 const BITS: u8 = core::mem::size_of::<$ty>() as u8 * 8;
                 IntVal::$intarg_contructor(this as _, BITS, f)
             }
+
+            /// Constructs `PanicVal`s which format an integer as specified by `f`,
+            /// followed by the integer formatted in the `secondary` number format
+            /// (with its prefix) in parentheses.
+            ///
+            /// # Example
+            ///
+            #[cfg_attr(feature = "non_basic", doc = "```rust")]
+            #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+            /// use const_panic::{fmt::NumberFmt, ArrayString, FmtArg, PanicVal};
+            ///
+            /// let pvs = PanicVal::from_u8_dual_radix(100, NumberFmt::Hexadecimal, FmtArg::DEBUG);
+            /// assert_eq!(ArrayString::<32>::from_panicvals(&pvs).unwrap(), "100 (0x64)");
+            ///
+            /// let pvs = PanicVal::from_i32_dual_radix(-2, NumberFmt::Hexadecimal, FmtArg::DEBUG);
+            /// assert_eq!(ArrayString::<32>::from_panicvals(&pvs).unwrap(), "-2 (0xFFFFFFFE)");
+            ///
+            /// let pvs = PanicVal::from_u16_dual_radix(5, NumberFmt::Binary, FmtArg::DEBUG);
+            /// assert_eq!(ArrayString::<32>::from_panicvals(&pvs).unwrap(), "5 (0b101)");
+            /// ```
+            pub const fn $dual_radix_ctor(
+                this: $ty,
+                secondary: NumberFmt,
+                f: FmtArg,
+            ) -> [PanicVal<'static>; 4] {
+                let mut secondary_fmt = f.set_alternate(true);
+                secondary_fmt.number_fmt = secondary;
+
+                [
+                    PanicVal::$panic_arg_ctor(this, f),
+                    PanicVal::write_str(" ("),
+                    PanicVal::$panic_arg_ctor(this, secondary_fmt),
+                    PanicVal::write_str(")"),
+                ]
+            }
         }
 
         primitive_static_panicfmt! {
@@ -117,29 +176,395 @@ macro_rules! impl_panicfmt_int {
     };
 }
 
-impl_panicfmt_int! {from_u8, from_u128, u8}
-impl_panicfmt_int! {from_u16, from_u128, u16}
-impl_panicfmt_int! {from_u32, from_u128, u32}
-impl_panicfmt_int! {from_u64, from_u128, u64}
-impl_panicfmt_int! {from_u128, from_u128, u128}
-impl_panicfmt_int! {from_usize, from_u128, usize}
+macro_rules! impl_hex_padded {
+    ($(($ctor:ident, $ty:ty, $example:expr, $grouped:expr, $ungrouped:expr))*) => {
+        impl PanicVal<'_> {
+            $(
+                #[doc = concat!(
+                    "Constructs a `PanicVal` which formats `this` as `0x`-prefixed ",
+                    "hexadecimal, zero-padded to the width of a `",
+                    stringify!($ty),
+                    "`,\n",
+                    "with a `_` between every 4 hex digits when `group` is `true`.",
+                )]
+                ///
+                /// This is for formatting registers and other fixed-width values,
+                /// the hex digits are written regardless of the [`FmtArg`] used elsewhere.
+                /// Like with [`FmtArg::HEX`], the digits above `9` are uppercase.
+                ///
+                /// # Example
+                ///
+                #[cfg_attr(feature = "non_basic", doc = "```rust")]
+                #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+                /// use const_panic::{ArrayString, PanicVal};
+                ///
+                #[doc = concat!(
+                    "let pv = PanicVal::", stringify!($ctor), "(", stringify!($example), ", true);"
+                )]
+                #[doc = concat!(
+                    "assert_eq!(ArrayString::<32>::from_panicvals(&[pv]).unwrap(), \"",
+                    $grouped,
+                    "\");",
+                )]
+                ///
+                #[doc = concat!(
+                    "let pv = PanicVal::", stringify!($ctor), "(", stringify!($example), ", false);"
+                )]
+                #[doc = concat!(
+                    "assert_eq!(ArrayString::<32>::from_panicvals(&[pv]).unwrap(), \"",
+                    $ungrouped,
+                    "\");",
+                )]
+                /// ```
+                pub const fn $ctor(this: $ty, group: bool) -> PanicVal<'static> {
+                    const DIGITS: u8 = core::mem::size_of::<$ty>() as u8 * 2;
+
+                    PanicVal::__new(PanicVariant::PreFmt(
+                        crate::int_formatting::fmt_hex_padded(this as u128, DIGITS, group, true),
+                    ))
+                }
+            )*
+        }
+    };
+}
+
+impl_hex_padded! {
+    (from_u8_hex_padded, u8, 0xA, "0x0A", "0x0A")
+    (from_u16_hex_padded, u16, 0xFF, "0x00FF", "0x00FF")
+    (from_u32_hex_padded, u32, 0xFF, "0x0000_00FF", "0x000000FF")
+    (from_u64_hex_padded, u64, 0xDEAD_BEEF, "0x0000_0000_DEAD_BEEF", "0x00000000DEADBEEF")
+}
+
+macro_rules! impl_signed_hex_padded {
+    ($(($ctor:ident, $ty:ty, $example:expr, $bits:expr, $grouped:expr, $ungrouped:expr))*) => {
+        impl PanicVal<'_> {
+            $(
+                #[doc = concat!(
+                    "Constructs a `PanicVal` which formats `this` as `0x`-prefixed ",
+                    "hexadecimal, zero-padded to `bits` bits (rounded up to whole hex digits),\n",
+                    "with a `_` between every 4 hex digits when `group` is `true`.",
+                )]
+                ///
+                /// Negative numbers are written as their `bits`-bit two's complement,
+                /// eg: `-1` with a `bits` of `12` is written as `0xFFF`.
+                ///
+                /// This is for formatting signed registers and other fixed-width values,
+                /// the hex digits are written regardless of the [`FmtArg`] used elsewhere.
+                /// Like with [`FmtArg::HEX`], the digits above `9` are uppercase.
+                ///
+                /// # Panics
+                ///
+                #[doc = concat!(
+                    "Panics if `bits` is not in the `1..=",
+                    stringify!($ty),
+                    "::BITS` range, or if `this` doesn't fit in a `bits`-bit signed integer.",
+                )]
+                ///
+                /// # Example
+                ///
+                #[cfg_attr(feature = "non_basic", doc = "```rust")]
+                #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+                /// use const_panic::{ArrayString, PanicVal};
+                ///
+                #[doc = concat!(
+                    "let pv = PanicVal::", stringify!($ctor), "(",
+                    stringify!($example), ", ", stringify!($bits), ", true);"
+                )]
+                #[doc = concat!(
+                    "assert_eq!(ArrayString::<32>::from_panicvals(&[pv]).unwrap(), \"",
+                    $grouped,
+                    "\");",
+                )]
+                ///
+                #[doc = concat!(
+                    "let pv = PanicVal::", stringify!($ctor), "(",
+                    stringify!($example), ", ", stringify!($bits), ", false);"
+                )]
+                #[doc = concat!(
+                    "assert_eq!(ArrayString::<32>::from_panicvals(&[pv]).unwrap(), \"",
+                    $ungrouped,
+                    "\");",
+                )]
+                /// ```
+                #[track_caller]
+                pub const fn $ctor(this: $ty, bits: u32, group: bool) -> PanicVal<'static> {
+                    signed_hex_padded(this as i128, bits, <$ty>::BITS, group)
+                }
+            )*
+        }
+    };
+}
+
+impl_signed_hex_padded! {
+    (from_i8_hex_padded, i8, -1, 6, "0x3F", "0x3F")
+    (from_i16_hex_padded, i16, -2, 12, "0xFFE", "0xFFE")
+    (from_i32_hex_padded, i32, -0x100, 32, "0xFFFF_FF00", "0xFFFFFF00")
+    (from_i64_hex_padded, i64, 0xFF, 40, "0x00_0000_00FF", "0x00000000FF")
+}
+
+#[track_caller]
+const fn signed_hex_padded(n: i128, bits: u32, max_bits: u32, group: bool) -> PanicVal<'static> {
+    if bits == 0 || bits > max_bits {
+        crate::concat_panic(&[&[
+            PanicVal::write_str("`bits` must be in the 1..="),
+            PanicVal::from_u32(max_bits, FmtArg::DISPLAY),
+            PanicVal::write_str(" range, found: "),
+            PanicVal::from_u32(bits, FmtArg::DISPLAY),
+        ]]);
+    }
+
+    let min = -1i128 << (bits - 1);
+    if n < min || n > !min {
+        crate::concat_panic(&[&[
+            PanicVal::from_i128(n, FmtArg::DISPLAY),
+            PanicVal::write_str(" doesn't fit in a "),
+            PanicVal::from_u32(bits, FmtArg::DISPLAY),
+            PanicVal::write_str("-bit signed integer"),
+        ]]);
+    }
+
+    let mask = (1u128 << bits) - 1;
+    let digits = ((bits + 3) / 4) as u8;
+
+    PanicVal::__new(PanicVariant::PreFmt(crate::int_formatting::fmt_hex_padded(
+        n as u128 & mask,
+        digits,
+        group,
+        true,
+    )))
+}
+
+impl_panicfmt_int! {from_u8, from_u8_dual_radix, from_u128, u8}
+impl_panicfmt_int! {from_u16, from_u16_dual_radix, from_u128, u16}
+impl_panicfmt_int! {from_u32, from_u32_dual_radix, from_u128, u32}
+impl_panicfmt_int! {from_u64, from_u64_dual_radix, from_u128, u64}
+impl_panicfmt_int! {from_u128, from_u128_dual_radix, from_u128, u128}
+impl_panicfmt_int! {from_usize, from_usize_dual_radix, from_u128, usize}
 
-impl_panicfmt_int! {from_i8, from_i128, i8}
-impl_panicfmt_int! {from_i16, from_i128, i16}
-impl_panicfmt_int! {from_i32, from_i128, i32}
-impl_panicfmt_int! {from_i64, from_i128, i64}
-impl_panicfmt_int! {from_i128, from_i128, i128}
-impl_panicfmt_int! {from_isize, from_i128, isize}
+impl_panicfmt_int! {from_i8, from_i8_dual_radix, from_i128, i8}
+impl_panicfmt_int! {from_i16, from_i16_dual_radix, from_i128, i16}
+impl_panicfmt_int! {from_i32, from_i32_dual_radix, from_i128, i32}
+impl_panicfmt_int! {from_i64, from_i64_dual_radix, from_i128, i64}
+impl_panicfmt_int! {from_i128, from_i128_dual_radix, from_i128, i128}
+impl_panicfmt_int! {from_isize, from_isize_dual_radix, from_i128, isize}
+
+impl PanicVal<'_> {
+    /// Constructs `PanicVal`s which format a byte as specified by `f`,
+    /// followed by the `Debug`-formatted ASCII character for that byte in parentheses,
+    /// if the byte is ASCII.
+    ///
+    /// Non-printable ASCII characters are escaped (eg: `'\n'`, `'\u{1}'`),
+    /// and non-ASCII bytes are written without the character.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg, PanicVal};
+    ///
+    /// let pvs = PanicVal::from_u8_with_ascii(b'A', FmtArg::DEBUG);
+    /// assert_eq!(ArrayString::<32>::from_panicvals(&pvs).unwrap(), "65 ('A')");
+    ///
+    /// let pvs = PanicVal::from_u8_with_ascii(b'\n', FmtArg::ALT_HEX);
+    /// assert_eq!(ArrayString::<32>::from_panicvals(&pvs).unwrap(), r"0xA ('\n')");
+    ///
+    /// let pvs = PanicVal::from_u8_with_ascii(0xFF, FmtArg::DEBUG);
+    /// assert_eq!(ArrayString::<32>::from_panicvals(&pvs).unwrap(), "255");
+    /// ```
+    pub const fn from_u8_with_ascii(byte: u8, f: FmtArg) -> [PanicVal<'static>; 4] {
+        if byte.is_ascii() {
+            [
+                PanicVal::from_u8(byte, f),
+                PanicVal::write_str(" ("),
+                PanicVal::from_char(byte as char, f.set_debug()),
+                PanicVal::write_str(")"),
+            ]
+        } else {
+            [
+                PanicVal::from_u8(byte, f),
+                PanicVal::EMPTY,
+                PanicVal::EMPTY,
+                PanicVal::EMPTY,
+            ]
+        }
+    }
+}
+
+impl PanicVal<'_> {
+    /// Constructs a `PanicVal` which formats `n` in base `radix`,
+    /// using `0-9` and then `A-Z` as the digits.
+    ///
+    /// Like with [`FmtArg::HEX`], the digits above `9` are uppercase.
+    ///
+    /// This ignores the [`number_fmt`] and [`is_alternate`] fields of `fmtarg`,
+    /// no prefix is written for any radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the `2..=36` range.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg, PanicVal};
+    ///
+    /// const fn to_string(n: u128, radix: u32) -> ArrayString<32> {
+    ///     ArrayString::from_panicvals(&[PanicVal::from_u128_radix(n, radix, FmtArg::DEBUG)])
+    ///         .unwrap()
+    /// }
+    ///
+    /// assert_eq!(to_string(35, 36), "Z");
+    /// assert_eq!(to_string(36, 36), "10");
+    /// assert_eq!(to_string(8, 8), "10");
+    /// assert_eq!(to_string(255, 16), "FF");
+    /// assert_eq!(to_string(5, 2), "101");
+    /// ```
+    ///
+    /// [`number_fmt`]: crate::FmtArg#structfield.number_fmt
+    /// [`is_alternate`]: crate::FmtArg#structfield.is_alternate
+    #[track_caller]
+    pub const fn from_u128_radix(n: u128, radix: u32, fmtarg: FmtArg) -> PanicVal<'static> {
+        if radix < 2 || radix > 36 {
+            crate::concat_panic(&[&[
+                PanicVal::write_str("radix must be in the 2..=36 range, found: "),
+                PanicVal::from_u32(radix, FmtArg::DISPLAY),
+            ]]);
+        }
+
+        IntVal::from_u128_radix(n, radix as u8, fmtarg)
+    }
+
+    /// Constructs a `PanicVal` which formats `address` as `0x`-prefixed lowercase hexadecimal,
+    /// zero-padded to the width of a pointer (eg: 16 hex digits on 64-bit platforms).
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, PanicVal};
+    ///
+    /// const ADDR: usize = 0x1F00;
+    ///
+    /// let string = ArrayString::<32>::from_panicvals(&[PanicVal::from_address(ADDR)]).unwrap();
+    ///
+    /// # #[cfg(target_pointer_width = "64")]
+    /// assert_eq!(string, "0x0000000000001f00");
+    /// ```
+    pub const fn from_address(address: usize) -> PanicVal<'static> {
+        PanicVal::__new(PanicVariant::PreFmt(crate::int_formatting::fmt_address(
+            address,
+        )))
+    }
+
+    /// Constructs a `PanicVal` which formats the address of `ptr`,
+    /// formatted the same as [`from_address`](Self::from_address).
+    ///
+    /// # Const caveat
+    ///
+    /// This function is not a `const fn`,
+    /// because pointer addresses aren't known at compile-time.
+    /// For that reason, the [`PanicFmt`](trait@crate::PanicFmt) impls for raw pointers
+    /// (which must be usable in const) don't print the address.
+    ///
+    /// If you compute addresses as integers in const code
+    /// (eg: when calculating layouts), use [`from_address`](Self::from_address).
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, PanicVal};
+    ///
+    /// let ptr = 0x2A0 as *const u8;
+    ///
+    /// let string = ArrayString::<32>::from_panicvals(&[PanicVal::from_ptr(ptr)]).unwrap();
+    ///
+    /// # #[cfg(target_pointer_width = "64")]
+    /// assert_eq!(string, "0x00000000000002a0");
+    /// ```
+    ///
+    /// ### FFI pointers
+    ///
+    /// `*mut T` pointers coerce to `*const T`,
+    /// so type-erased pointers received over FFI can be passed directly.
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, PanicVal};
+    ///
+    /// use core::ffi::c_void;
+    ///
+    /// let ptr = 0xBEEF0 as *mut c_void;
+    ///
+    /// let string = ArrayString::<64>::concat_panicvals(&[&[
+    ///     PanicVal::write_str("callback data: "),
+    ///     PanicVal::from_ptr(ptr),
+    /// ]]).unwrap();
+    ///
+    /// # #[cfg(target_pointer_width = "64")]
+    /// assert_eq!(string, "callback data: 0x00000000000beef0");
+    /// ```
+    pub fn from_ptr<T: ?Sized>(ptr: *const T) -> PanicVal<'static> {
+        PanicVal::from_address(ptr as *const () as usize)
+    }
+
+    /// Constructs a `PanicVal` which formats the address of `ptr`,
+    /// formatted the same as [`from_address`](Self::from_address).
+    ///
+    /// This has the same const caveat as [`from_ptr`](Self::from_ptr),
+    /// the [`PanicFmt`](trait@crate::PanicFmt) impl for `NonNull` doesn't print the address.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, PanicVal};
+    ///
+    /// use core::ptr::NonNull;
+    ///
+    /// let ptr = NonNull::new(0x2A0 as *mut u8).unwrap();
+    ///
+    /// let string = ArrayString::<32>::from_panicvals(&[PanicVal::from_non_null(ptr)]).unwrap();
+    ///
+    /// # #[cfg(target_pointer_width = "64")]
+    /// assert_eq!(string, "0x00000000000002a0");
+    /// ```
+    pub fn from_non_null<T: ?Sized>(ptr: core::ptr::NonNull<T>) -> PanicVal<'static> {
+        PanicVal::from_ptr(ptr.as_ptr())
+    }
+}
 
 impl_panicfmt_panicarg! {
-    fn from_bool[](this: bool, _f) -> PanicVal<'static> {
-        PanicVal::write_str(if this { "true" } else { "false" })
+    fn from_bool[](this: bool, f) -> PanicVal<'static> {
+        PanicVal::write_str(match (this, f.yes_no()) {
+            (true, false) => "true",
+            (false, false) => "false",
+            (true, true) => "yes",
+            (false, true) => "no",
+        })
     }
 }
 
 impl<'a> PanicVal<'a> {
     /// Constructs a `PanicVal` from a `&str`
-    pub const fn from_str(this: &'a str, f: FmtArg) -> PanicVal<'a> {
+    ///
+    /// If [`f.shell_quote()`](crate::FmtArg::shell_quote) is enabled,
+    /// `Display` formatting quotes `this` when it's not a plain shell argument.
+    ///
+    /// If [`f.wrap_width()`](crate::FmtArg::wrap_width) is set,
+    /// `Display` formatting soft-wraps `this` at that many columns.
+    pub const fn from_str(this: &'a str, mut f: FmtArg) -> PanicVal<'a> {
+        if let (FmtKind::Display, true) = (f.fmt_kind, f.shell_quote()) {
+            if crate::utils::needs_shell_quoting(this) {
+                f = f.set_debug();
+            }
+        }
+
+        if let (FmtKind::Display, Some(width @ 1..=u16::MAX)) = (f.fmt_kind, f.wrap_width()) {
+            return PanicVal::__new(PanicVariant::WrappedStr(WrappedStr::new(this, width)));
+        }
+
         PanicVal::__new(PanicVariant::Str(StrFmt::new(f), Packed(this)))
     }
 }
@@ -2,18 +2,38 @@ use crate::{FmtArg, PanicFmt, PanicVal, StdWrapper};
 
 use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
+macro_rules! impl_range_generic_panicfmt {
+    ($($ty:ident => $pv_count:expr,)*) => {
+        $(
+            /// Note: there is only `to_panicvals` methods for ranges of
+            /// primitive integers and `char` for now.
+            ///
+            impl<T> PanicFmt for $ty<T>
+            where
+                T: PanicFmt,
+            {
+                type This = Self;
+                type Kind = crate::fmt::IsStdType;
+                const PV_COUNT: usize = $pv_count;
+            }
+        )*
+    };
+}
+
+impl_range_generic_panicfmt! {
+    Range => 2 * T::PV_COUNT + 1,
+    RangeFrom => T::PV_COUNT + 1,
+    RangeTo => T::PV_COUNT + 1,
+    RangeToInclusive => T::PV_COUNT + 1,
+    RangeInclusive => 2 * T::PV_COUNT + 1,
+}
+
 macro_rules! impl_range_panicfmt_one {
     (
         fn(&$self:ident: $ty:ty, $f:ident) -> $pv_count:literal {
             $($content:tt)*
         }
     ) => {
-        impl PanicFmt for $ty {
-            type This = Self;
-            type Kind = crate::fmt::IsStdType;
-            const PV_COUNT: usize = $pv_count;
-        }
-
         impl crate::StdWrapper<&$ty> {
             #[doc = concat!(
                 "Converts this `", stringify!($ty), "` to a single-element `PanicVal` array."
@@ -76,10 +96,30 @@ macro_rules! impl_range_panicfmt {
     };
 }
 
+impl_range_panicfmt! {u8}
+impl_range_panicfmt! {u16}
+impl_range_panicfmt! {u32}
+impl_range_panicfmt! {u64}
+impl_range_panicfmt! {u128}
 impl_range_panicfmt! {usize}
 
+impl_range_panicfmt! {i8}
+impl_range_panicfmt! {i16}
+impl_range_panicfmt! {i32}
+impl_range_panicfmt! {i64}
+impl_range_panicfmt! {i128}
+impl_range_panicfmt! {isize}
+
+impl_range_panicfmt! {char}
+
 ////////////////////////////////////////////////////////////////////////////////
 
+impl PanicFmt for RangeFull {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 1;
+}
+
 impl_range_panicfmt_one! {
     fn(&self: RangeFull, _f) -> 1 {
         [PanicVal::write_str("..")]
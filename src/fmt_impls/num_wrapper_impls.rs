@@ -0,0 +1,41 @@
+use crate::PanicVal;
+
+#[cfg(feature = "rust_1_74")]
+use core::num::Saturating;
+
+use core::num::Wrapping;
+
+macro_rules! num_wrapper_impls {
+    ($(($int_ctor:ident, $int:ty))*) => (
+        $(
+            primitive_static_panicfmt!{
+                fn[](&self: Wrapping<$int>, fmtarg) {
+                    PanicVal::$int_ctor(self.0 .0, fmtarg)
+                }
+            }
+
+            primitive_static_panicfmt!{
+                #[cfg(feature = "rust_1_74")]
+                #[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_74")))]
+                fn[](&self: Saturating<$int>, fmtarg) {
+                    PanicVal::$int_ctor(self.0 .0, fmtarg)
+                }
+            }
+        )*
+    )
+}
+
+num_wrapper_impls! {
+    (from_u8, u8)
+    (from_i8, i8)
+    (from_u16, u16)
+    (from_i16, i16)
+    (from_u32, u32)
+    (from_i32, i32)
+    (from_u64, u64)
+    (from_i64, i64)
+    (from_u128, u128)
+    (from_i128, i128)
+    (from_usize, usize)
+    (from_isize, isize)
+}
@@ -12,6 +12,25 @@ where
     const PV_COUNT: usize = 4 + T::PV_COUNT;
 }
 
+// Returns the `FmtArg`s for the delimiters and the wrapped value of a `Some`.
+//
+// With `fmtarg.compact_option` enabled,
+// the delimiters are formatted as non-alternate to keep the value on the same line.
+pub(crate) const fn some_fmtargs(fmtarg: FmtArg) -> (FmtArg, FmtArg) {
+    if fmtarg.compact_option() {
+        (fmtarg.set_alternate(false), fmtarg)
+    } else {
+        let fmtarg = fmtarg.indent();
+        (fmtarg, fmtarg)
+    }
+}
+
+// Whether `Option`s are formatted as only the value wrapped by `Some`,
+// and as nothing for `None`.
+pub(crate) const fn is_transparent(fmtarg: FmtArg) -> bool {
+    fmtarg.transparent_option() && matches!(fmtarg.fmt_kind, crate::fmt::FmtKind::Display)
+}
+
 macro_rules! impl_for_option {
     (
         $((for[$($generics:tt)*],$lt:lifetime, $ty:ty, $unref:ty))*
@@ -25,18 +44,28 @@ macro_rules! impl_for_option {
                 )]
                 pub const fn to_panicvals(
                     self: Self,
-                    mut fmtarg: FmtArg,
+                    fmtarg: FmtArg,
                 ) -> [PanicVal<$lt>; 5] {
                     use crate::{PanicVal, StdWrapper, __::EPV, fmt};
 
                     match self.0 {
-                        Some(x) => [
-                            PanicVal::write_str("Some"),
-                            {fmtarg = fmtarg.indent(); fmt::OpenParen.to_panicval(fmtarg)},
-                            StdWrapper::<&$unref>(x).to_panicval(fmtarg),
-                            fmt::COMMA_TERM.to_panicval(fmtarg),
-                            {fmtarg = fmtarg.unindent(); fmt::CloseParen.to_panicval(fmtarg)},
-                        ],
+                        Some(x) if crate::fmt_impls::option_fmt_impls::is_transparent(fmtarg) => {
+                            [StdWrapper::<&$unref>(x).to_panicval(fmtarg), EPV, EPV, EPV, EPV]
+                        }
+                        None if crate::fmt_impls::option_fmt_impls::is_transparent(fmtarg) => {
+                            [EPV; 5]
+                        }
+                        Some(x) => {
+                            let (delim_fmt, inner_fmt) =
+                                crate::fmt_impls::option_fmt_impls::some_fmtargs(fmtarg);
+                            [
+                                PanicVal::write_str("Some"),
+                                fmt::OpenParen.to_panicval(delim_fmt),
+                                StdWrapper::<&$unref>(x).to_panicval(inner_fmt),
+                                fmt::COMMA_TERM.to_panicval(delim_fmt),
+                                fmt::CloseParen.to_panicval(delim_fmt.unindent()),
+                            ]
+                        }
                         None => [PanicVal::write_str("None"), EPV, EPV, EPV, EPV],
                     }
                 }
@@ -60,8 +89,64 @@ macro_rules! impl_for_option_outer {
     )
 }
 
+impl_for_option! {
+    (for[const N: usize], 's, [bool; N], [bool; N])
+    (for[const N: usize], 's, &'s [bool; N], [bool; N])
+    (for[], 's, &'s [bool], [bool])
+}
+
+impl crate::StdWrapper<&Option<bool>> {
+    /// Converts this `Option<bool>` to a `PanicVal` array.
+    ///
+    /// If [`fmtarg.yes_no()`](crate::FmtArg::yes_no) is enabled,
+    /// this is formatted as `yes`, `no`, or `unset`.
+    pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'static>; 5] {
+        use crate::{fmt, StdWrapper, __::EPV};
+
+        match (*self.0, fmtarg.yes_no()) {
+            (_, true) => [self.to_panicval(fmtarg), EPV, EPV, EPV, EPV],
+            (_, false) if is_transparent(fmtarg) => [self.to_panicval(fmtarg), EPV, EPV, EPV, EPV],
+            (Some(x), false) => {
+                let (delim_fmt, inner_fmt) = some_fmtargs(fmtarg);
+                [
+                    PanicVal::write_str("Some"),
+                    fmt::OpenParen.to_panicval(delim_fmt),
+                    StdWrapper(&x).to_panicval(inner_fmt),
+                    fmt::COMMA_TERM.to_panicval(delim_fmt),
+                    fmt::CloseParen.to_panicval(delim_fmt.unindent()),
+                ]
+            }
+            (None, false) => [PanicVal::write_str("None"), EPV, EPV, EPV, EPV],
+        }
+    }
+
+    /// Converts this `Option<bool>` to a single `PanicVal`.
+    ///
+    /// Unlike [`to_panicvals`](#method.to_panicvals),
+    /// this never spans multiple lines, even with alternate formatting.
+    ///
+    /// If [`fmtarg.yes_no()`](crate::FmtArg::yes_no) is enabled,
+    /// this is formatted as `yes`, `no`, or `unset`.
+    pub const fn to_panicval(self, fmtarg: FmtArg) -> PanicVal<'static> {
+        if !fmtarg.yes_no() && is_transparent(fmtarg) {
+            return match *self.0 {
+                Some(x) => PanicVal::from_bool(x, fmtarg),
+                None => PanicVal::EMPTY,
+            };
+        }
+
+        PanicVal::write_str(match (*self.0, fmtarg.yes_no()) {
+            (Some(true), false) => "Some(true)",
+            (Some(false), false) => "Some(false)",
+            (None, false) => "None",
+            (Some(true), true) => "yes",
+            (Some(false), true) => "no",
+            (None, true) => "unset",
+        })
+    }
+}
+
 impl_for_option_outer! {
-    ('static, bool, bool)
     ('static, u8, u8)
     ('static, u16, u16)
     ('static, u32, u32)
@@ -39,6 +39,26 @@ primitive_static_panicfmt! {
     }
 }
 
+impl PanicFmt for core::convert::Infallible {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 1;
+}
+
+// Since `Infallible` can't be constructed, these methods can't be called,
+// they exist so that types containing `Infallible` can derive `PanicFmt`.
+impl crate::StdWrapper<&core::convert::Infallible> {
+    /// Converts this `Infallible` to a single-element `PanicVal` array.
+    pub const fn to_panicvals(self, _: FmtArg) -> [PanicVal<'static>; 1] {
+        match *self.0 {}
+    }
+
+    /// Converts this `Infallible` to a `PanicVal`.
+    pub const fn to_panicval(self, _: FmtArg) -> PanicVal<'static> {
+        match *self.0 {}
+    }
+}
+
 primitive_static_panicfmt! {
     fn[](&self: (), _f) {
         PanicVal::write_str("()")
@@ -73,3 +93,331 @@ primitive_static_panicfmt! {
         PanicVal::write_str(v)
     }
 }
+
+impl_for_option! {
+    (for[], 'static, core::num::FpCategory, core::num::FpCategory)
+}
+primitive_static_panicfmt! {
+    fn[](&self: std::num::FpCategory, _f) {
+        use std::num::FpCategory;
+        let v = match self.0 {
+            FpCategory::Nan => "Nan",
+            FpCategory::Infinite => "Infinite",
+            FpCategory::Zero => "Zero",
+            FpCategory::Subnormal => "Subnormal",
+            FpCategory::Normal => "Normal",
+        };
+        PanicVal::write_str(v)
+    }
+}
+
+macro_rules! impl_unit_error_panicfmt {
+    ($(($ty:ty, $name:literal, $message:literal))*) => (
+        $(
+            impl PanicFmt for $ty {
+                type This = Self;
+                type Kind = crate::fmt::IsStdType;
+                const PV_COUNT: usize = 5;
+            }
+
+            impl crate::StdWrapper<&$ty> {
+                #[doc = concat!(
+                    "Converts this `",
+                    $name,
+                    "` to a `PanicVal` array.",
+                )]
+                ///
+                /// This is formatted the same as its `Debug` and `Display` impls.
+                pub const fn to_panicvals(self, fmtarg: FmtArg) -> [PanicVal<'static>; 5] {
+                    use crate::{fmt::{self, FmtKind}, __::EPV};
+
+                    if let FmtKind::Display = fmtarg.fmt_kind {
+                        return [PanicVal::write_str($message), EPV, EPV, EPV, EPV];
+                    }
+
+                    let inner_fmt = fmtarg.indent();
+                    [
+                        PanicVal::write_str($name),
+                        fmt::OpenParen.to_panicval(inner_fmt),
+                        PanicVal::write_str("()"),
+                        fmt::COMMA_TERM.to_panicval(inner_fmt),
+                        fmt::CloseParen.to_panicval(fmtarg),
+                    ]
+                }
+            }
+        )*
+    )
+}
+
+impl_unit_error_panicfmt! {
+    (
+        core::num::TryFromIntError,
+        "TryFromIntError",
+        "out of range integral type conversion attempted"
+    )
+    (
+        core::array::TryFromSliceError,
+        "TryFromSliceError",
+        "could not convert slice to array"
+    )
+    (
+        core::char::CharTryFromError,
+        "CharTryFromError",
+        "converted integer out of range for `char`"
+    )
+}
+
+macro_rules! impl_reverse_panicfmt {
+    ($((for[$($generics:tt)*], $lt:lifetime, $ctor:ident, $ty:ty))*) => (
+        $(
+            /// Note: there is only `PanicFmt` impls for `Reverse`s of primitive types and `&str`
+            /// for now.
+            ///
+            impl<$($generics)*> PanicFmt for std::cmp::Reverse<$ty> {
+                type This = Self;
+                type Kind = crate::fmt::IsStdType;
+                const PV_COUNT: usize = 5;
+            }
+
+            impl<'s, $($generics)*> crate::StdWrapper<&'s std::cmp::Reverse<$ty>> {
+                #[doc = concat!(
+                    "Converts this `Reverse<",
+                    stringify!($ty),
+                    ">` to a `PanicVal` array.",
+                )]
+                ///
+                /// With Display formatting, this only writes the wrapped value.
+                pub const fn to_panicvals(self: Self, fmtarg: FmtArg) -> [PanicVal<$lt>; 5] {
+                    use crate::{fmt::{self, FmtKind}, __::EPV};
+
+                    if let FmtKind::Display = fmtarg.fmt_kind {
+                        return [PanicVal::$ctor(self.0 .0, fmtarg), EPV, EPV, EPV, EPV];
+                    }
+
+                    let inner_fmt = fmtarg.indent();
+                    [
+                        PanicVal::write_str("Reverse"),
+                        fmt::OpenParen.to_panicval(inner_fmt),
+                        PanicVal::$ctor(self.0 .0, inner_fmt),
+                        fmt::COMMA_TERM.to_panicval(inner_fmt),
+                        fmt::CloseParen.to_panicval(fmtarg),
+                    ]
+                }
+            }
+        )*
+    )
+}
+
+impl_reverse_panicfmt! {
+    (for[], 'static, from_u8, u8)
+    (for[], 'static, from_i8, i8)
+    (for[], 'static, from_u16, u16)
+    (for[], 'static, from_i16, i16)
+    (for[], 'static, from_u32, u32)
+    (for[], 'static, from_i32, i32)
+    (for[], 'static, from_u64, u64)
+    (for[], 'static, from_i64, i64)
+    (for[], 'static, from_u128, u128)
+    (for[], 'static, from_i128, i128)
+    (for[], 'static, from_usize, usize)
+    (for[], 'static, from_isize, isize)
+    (for[], 'static, from_bool, bool)
+    (for[], 'static, from_char, char)
+    (for['a], 'a, from_str, &'a str)
+}
+
+/// Note: there is only `to_panicvals` methods for `Poll`s of standard library types
+/// for now.
+///
+impl<T> PanicFmt for core::task::Poll<T>
+where
+    T: PanicFmt,
+{
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 4 + T::PV_COUNT;
+}
+
+macro_rules! impl_poll_panicfmt {
+    ($((for[$($generics:tt)*], $lt:lifetime, $ctor:ident, $ty:ty))*) => (
+        $(
+            impl<'s, $($generics)*> crate::StdWrapper<&'s core::task::Poll<$ty>> {
+                #[doc = concat!(
+                    "Converts this `Poll<",
+                    stringify!($ty),
+                    ">` to a `PanicVal` array.",
+                )]
+                pub const fn to_panicvals(self: Self, fmtarg: FmtArg) -> [PanicVal<$lt>; 5] {
+                    use crate::{fmt, __::EPV};
+                    use core::task::Poll;
+
+                    match *self.0 {
+                        Poll::Ready(x) => {
+                            let inner_fmt = fmtarg.indent();
+                            [
+                                PanicVal::write_str("Ready"),
+                                fmt::OpenParen.to_panicval(inner_fmt),
+                                PanicVal::$ctor(x, inner_fmt),
+                                fmt::COMMA_TERM.to_panicval(inner_fmt),
+                                fmt::CloseParen.to_panicval(fmtarg),
+                            ]
+                        }
+                        Poll::Pending => [PanicVal::write_str("Pending"), EPV, EPV, EPV, EPV],
+                    }
+                }
+            }
+        )*
+    )
+}
+
+impl_poll_panicfmt! {
+    (for[], 'static, from_u8, u8)
+    (for[], 'static, from_i8, i8)
+    (for[], 'static, from_u16, u16)
+    (for[], 'static, from_i16, i16)
+    (for[], 'static, from_u32, u32)
+    (for[], 'static, from_i32, i32)
+    (for[], 'static, from_u64, u64)
+    (for[], 'static, from_i64, i64)
+    (for[], 'static, from_u128, u128)
+    (for[], 'static, from_i128, i128)
+    (for[], 'static, from_usize, usize)
+    (for[], 'static, from_isize, isize)
+    (for[], 'static, from_bool, bool)
+    (for[], 'static, from_char, char)
+    (for['a], 'a, from_str, &'a str)
+}
+
+/// Note: there is only `to_panicvals` methods for `ControlFlow`s of
+/// standard library types for now,
+/// where the other type parameter is `()`.
+///
+impl<B, C> PanicFmt for core::ops::ControlFlow<B, C>
+where
+    B: PanicFmt,
+    C: PanicFmt,
+{
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 4 + crate::utils::max_usize(B::PV_COUNT, C::PV_COUNT);
+}
+
+const fn unit_panicval(_: (), f: FmtArg) -> PanicVal<'static> {
+    crate::StdWrapper(&()).to_panicval(f)
+}
+
+macro_rules! impl_control_flow_panicfmt {
+    ($((for[$($generics:tt)*], $lt:lifetime, $ctor:path, $ty:ty))*) => (
+        $(
+            impl_control_flow_panicfmt! {
+                @single
+                (for[$($generics)*], $lt, $ctor, unit_panicval, $ty, ())
+            }
+            impl_control_flow_panicfmt! {
+                @single
+                (for[$($generics)*], $lt, unit_panicval, $ctor, (), $ty)
+            }
+        )*
+    );
+    (@single (for[$($generics:tt)*], $lt:lifetime, $bctor:path, $cctor:path, $b:ty, $c:ty)) => (
+        impl<'s, $($generics)*> crate::StdWrapper<&'s core::ops::ControlFlow<$b, $c>> {
+            #[doc = concat!(
+                "Converts this `ControlFlow<",
+                stringify!($b),
+                ", ",
+                stringify!($c),
+                ">` to a `PanicVal` array.",
+            )]
+            pub const fn to_panicvals(self: Self, fmtarg: FmtArg) -> [PanicVal<$lt>; 5] {
+                use crate::fmt;
+                use core::ops::ControlFlow;
+
+                let inner_fmt = fmtarg.indent();
+                let (variant, pv) = match *self.0 {
+                    ControlFlow::Continue(x) => ("Continue", $cctor(x, inner_fmt)),
+                    ControlFlow::Break(x) => ("Break", $bctor(x, inner_fmt)),
+                };
+
+                [
+                    PanicVal::write_str(variant),
+                    fmt::OpenParen.to_panicval(inner_fmt),
+                    pv,
+                    fmt::COMMA_TERM.to_panicval(inner_fmt),
+                    fmt::CloseParen.to_panicval(fmtarg),
+                ]
+            }
+        }
+    );
+}
+
+impl_control_flow_panicfmt! {
+    (for[], 'static, PanicVal::from_u8, u8)
+    (for[], 'static, PanicVal::from_i8, i8)
+    (for[], 'static, PanicVal::from_u16, u16)
+    (for[], 'static, PanicVal::from_i16, i16)
+    (for[], 'static, PanicVal::from_u32, u32)
+    (for[], 'static, PanicVal::from_i32, i32)
+    (for[], 'static, PanicVal::from_u64, u64)
+    (for[], 'static, PanicVal::from_i64, i64)
+    (for[], 'static, PanicVal::from_u128, u128)
+    (for[], 'static, PanicVal::from_i128, i128)
+    (for[], 'static, PanicVal::from_usize, usize)
+    (for[], 'static, PanicVal::from_isize, isize)
+    (for[], 'static, PanicVal::from_bool, bool)
+    (for[], 'static, PanicVal::from_char, char)
+    (for['a], 'a, PanicVal::from_str, &'a str)
+}
+
+impl_control_flow_panicfmt! {
+    @single
+    (for[], 'static, unit_panicval, unit_panicval, (), ())
+}
+
+#[cfg(feature = "rust_1_74")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_74")))]
+impl PanicFmt for core::ffi::CStr {
+    type This = Self;
+    type Kind = crate::fmt::IsStdType;
+    const PV_COUNT: usize = 3;
+}
+
+#[cfg(feature = "rust_1_74")]
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "rust_1_74")))]
+impl<'a> crate::StdWrapper<&'a core::ffi::CStr> {
+    /// Formats the bytes of this `&CStr`, without the trailing nul, into a `PanicVal` array.
+    ///
+    /// If the bytes are valid UTF-8, they're formatted like a `&str`,
+    /// otherwise they're formatted like a [`ByteStr`](crate::fmt::ByteStr),
+    /// as a `b"..."` byte string when `Debug`-formatted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{concat_, FmtArg};
+    ///
+    /// use core::ffi::CStr;
+    ///
+    /// const UTF8: &CStr = cstr(b"hello\0");
+    /// assert_eq!(concat_!(UTF8), "\"hello\"");
+    /// assert_eq!(concat_!(FmtArg::DISPLAY; UTF8), "hello");
+    ///
+    /// const NOT_UTF8: &CStr = cstr(b"a\xFF\0");
+    /// assert_eq!(concat_!(NOT_UTF8), r#"b"a\xff""#);
+    /// assert_eq!(concat_!(FmtArg::DISPLAY; NOT_UTF8), r#"a\xff"#);
+    ///
+    /// const fn cstr(bytes: &[u8]) -> &CStr {
+    ///     match CStr::from_bytes_with_nul(bytes) {
+    ///         Ok(x) => x,
+    ///         Err(_) => panic!("not a nul-terminated string"),
+    ///     }
+    /// }
+    ///
+    /// ```
+    pub const fn to_panicvals(self, f: FmtArg) -> [PanicVal<'a>; 3] {
+        let bytes = self.0.to_bytes();
+        match core::str::from_utf8(bytes) {
+            Ok(str) => [PanicVal::from_str(str, f), PanicVal::EMPTY, PanicVal::EMPTY],
+            Err(_) => crate::fmt::ByteStr::new(bytes).to_panicvals(f),
+        }
+    }
+}
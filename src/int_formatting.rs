@@ -3,11 +3,24 @@ use crate::{
     utils::{Sign, TailShortString},
 };
 
-pub(crate) const fn fmt_decimal<const N: usize>(sign: Sign, mut n: u128) -> TailShortString<N> {
+pub(crate) const fn fmt_decimal<const N: usize>(
+    sign: Sign,
+    mut n: u128,
+    plus_sign: bool,
+    group_len: u8,
+) -> TailShortString<N> {
     let mut start = N;
     let mut buffer = [0u8; N];
+    let mut digits = 0u8;
 
     loop {
+        if is_separator_position(digits, group_len) {
+            debug_assert!(start != 0, "integer buffer is too small for its separators");
+            start -= 1;
+            buffer[start] = b'_';
+        }
+        digits += 1;
+        debug_assert!(start != 0, "integer buffer is too small for its digits");
         start -= 1;
         let digit = (n % 10) as u8;
         buffer[start] = b'0' + digit;
@@ -17,9 +30,10 @@ pub(crate) const fn fmt_decimal<const N: usize>(sign: Sign, mut n: u128) -> Tail
         }
     }
 
-    if let Sign::Negative = sign {
+    if let Some(sign) = sign_byte(sign, plus_sign) {
+        debug_assert!(start != 0, "integer buffer is too small for the sign");
         start -= 1;
-        buffer[start] = b'-';
+        buffer[start] = sign;
     }
 
     // safety: buffer is only ever written ascii, so its automatically valid utf8.
@@ -27,13 +41,24 @@ pub(crate) const fn fmt_decimal<const N: usize>(sign: Sign, mut n: u128) -> Tail
 }
 
 pub(crate) const fn fmt_binary<const N: usize>(
+    sign: Sign,
     mut n: u128,
     is_alternate: bool,
+    plus_sign: bool,
+    group_len: u8,
 ) -> TailShortString<N> {
     let mut start = N;
     let mut buffer = [0u8; N];
+    let mut digits = 0u8;
 
     loop {
+        if is_separator_position(digits, group_len) {
+            debug_assert!(start != 0, "integer buffer is too small for its separators");
+            start -= 1;
+            buffer[start] = b'_';
+        }
+        digits += 1;
+        debug_assert!(start != 0, "integer buffer is too small for its digits");
         start -= 1;
         let digit = (n & 1) as u8;
         buffer[start] = b'0' + digit;
@@ -44,24 +69,42 @@ pub(crate) const fn fmt_binary<const N: usize>(
     }
 
     if is_alternate {
+        debug_assert!(start >= 2, "integer buffer is too small for the prefix");
         start -= 1;
         buffer[start] = b'b';
         start -= 1;
         buffer[start] = b'0';
     }
 
+    if let Some(sign) = sign_byte(sign, plus_sign) {
+        debug_assert!(start != 0, "integer buffer is too small for the sign");
+        start -= 1;
+        buffer[start] = sign;
+    }
+
     // safety: buffer is only ever written ascii, so its automatically valid utf8.
     unsafe { TailShortString::new(start as u8, buffer) }
 }
 
 pub(crate) const fn fmt_hexadecimal<const N: usize>(
+    sign: Sign,
     mut n: u128,
     is_alternate: bool,
+    plus_sign: bool,
+    group_len: u8,
 ) -> TailShortString<N> {
     let mut start = N;
     let mut buffer = [0u8; N];
+    let mut digits = 0u8;
 
     loop {
+        if is_separator_position(digits, group_len) {
+            debug_assert!(start != 0, "integer buffer is too small for its separators");
+            start -= 1;
+            buffer[start] = b'_';
+        }
+        digits += 1;
+        debug_assert!(start != 0, "integer buffer is too small for its digits");
         start -= 1;
         let digit = (n & 0xF) as u8;
         buffer[start] = match digit {
@@ -75,43 +118,206 @@ pub(crate) const fn fmt_hexadecimal<const N: usize>(
     }
 
     if is_alternate {
+        debug_assert!(start >= 2, "integer buffer is too small for the prefix");
         start -= 1;
         buffer[start] = b'x';
         start -= 1;
         buffer[start] = b'0';
     }
 
+    if let Some(sign) = sign_byte(sign, plus_sign) {
+        debug_assert!(start != 0, "integer buffer is too small for the sign");
+        start -= 1;
+        buffer[start] = sign;
+    }
+
+    // safety: buffer is only ever written ascii, so its automatically valid utf8.
+    unsafe { TailShortString::new(start as u8, buffer) }
+}
+
+// the `-` of negative integers, or the `+` of the rest when `plus_sign` is enabled.
+const fn sign_byte(sign: Sign, plus_sign: bool) -> Option<u8> {
+    match sign {
+        Sign::Negative => Some(b'-'),
+        Sign::Positive if plus_sign => Some(b'+'),
+        Sign::Positive => None,
+    }
+}
+
+// whether a `_` is written before the digit that comes after `digits` digits,
+// which is every `group_len` digits (after the first group).
+// A `group_len` of `0` disables the separators.
+//
+// Since this is only checked before writing a digit,
+// separators are never written next to the sign or the `0x`/`0b` prefix.
+const fn is_separator_position(digits: u8, group_len: u8) -> bool {
+    group_len != 0 && digits != 0 && digits % group_len == 0
+}
+
+// formats `n` in base `radix`, using `0-9A-Z` as the digits.
+pub(crate) const fn fmt_radix<const N: usize>(
+    mut n: u128,
+    radix: u8,
+    plus_sign: bool,
+) -> TailShortString<N> {
+    let mut start = N;
+    let mut buffer = [0u8; N];
+
+    let radix = radix as u128;
+
+    loop {
+        debug_assert!(start != 0, "integer buffer is too small for its digits");
+        start -= 1;
+        let digit = (n % radix) as u8;
+        buffer[start] = match digit {
+            0..=9 => b'0' + digit,
+            _ => b'A' - 10 + digit,
+        };
+        n /= radix;
+        if n == 0 {
+            break;
+        }
+    }
+
+    if plus_sign {
+        debug_assert!(start != 0, "integer buffer is too small for the sign");
+        start -= 1;
+        buffer[start] = b'+';
+    }
+
+    // safety: buffer is only ever written ascii, so its automatically valid utf8.
+    unsafe { TailShortString::new(start as u8, buffer) }
+}
+
+// formats `n` as `0x`-prefixed lowercase hexadecimal,
+// zero-padded to the number of hex digits in a `usize`.
+pub(crate) const fn fmt_address<const N: usize>(n: usize) -> TailShortString<N> {
+    const DIGITS: u8 = core::mem::size_of::<usize>() as u8 * 2;
+
+    fmt_hex_padded(n as u128, DIGITS, false, false)
+}
+
+// formats `n` as `0x`-prefixed hexadecimal, zero-padded to `digits` hex digits,
+// with a `_` between every 4 digits when `digit_grouping` is enabled.
+//
+// The digits above `9` are uppercase if `uppercase` is true, lowercase otherwise.
+pub(crate) const fn fmt_hex_padded<const N: usize>(
+    mut n: u128,
+    digits: u8,
+    digit_grouping: bool,
+    uppercase: bool,
+) -> TailShortString<N> {
+    let mut start = N;
+    let mut buffer = [0u8; N];
+    let mut written = 0u8;
+    let group_len = if digit_grouping { 4 } else { 0 };
+    let letter_a = if uppercase { b'A' } else { b'a' };
+
+    debug_assert!(
+        N >= digits as usize + separator_count(digits, group_len) as usize + 2,
+        "integer buffer is too small for the padded integer"
+    );
+
+    while written != digits {
+        if is_separator_position(written, group_len) {
+            start -= 1;
+            buffer[start] = b'_';
+        }
+        written += 1;
+        start -= 1;
+        let digit = (n & 0xF) as u8;
+        buffer[start] = match digit {
+            0..=9 => b'0' + digit,
+            _ => letter_a - 10 + digit,
+        };
+        n >>= 4;
+    }
+
+    start -= 1;
+    buffer[start] = b'x';
+    start -= 1;
+    buffer[start] = b'0';
+
     // safety: buffer is only ever written ascii, so its automatically valid utf8.
     unsafe { TailShortString::new(start as u8, buffer) }
 }
 
 pub(crate) const fn compute_len(sign: Sign, int: u128, bits: u8, fmt: FmtArg) -> u8 {
-    match fmt.number_fmt {
-        NumberFmt::Decimal => compute_decimal_len(sign, int),
+    // whether this is formatted as the two's complement bit pattern of a negative integer
+    let is_bit_pattern = match (fmt.number_fmt, sign) {
+        (NumberFmt::Decimal, _) | (_, Sign::Positive) => false,
+        (_, Sign::Negative) => !fmt.signed_hex_bin(),
+    };
+
+    let with_sign = match (fmt.number_fmt, sign) {
+        (NumberFmt::Decimal, Sign::Negative) => 0,
+        (_, Sign::Negative) if fmt.signed_hex_bin() => 1,
+        _ => fmt.plus_sign() as u8,
+    };
+
+    let len = match fmt.number_fmt {
+        NumberFmt::Decimal => {
+            let len = compute_decimal_len(sign, int);
+            let digits = len - matches!(sign, Sign::Negative) as u8;
+            len + separator_count(digits, group_len(NumberFmt::Decimal, fmt))
+        }
         NumberFmt::Hexadecimal => {
             let with_0x = (fmt.is_alternate as u8) * 2;
-            let i = match sign {
-                Sign::Negative => bits,
-                Sign::Positive => (128 - int.leading_zeros()) as u8,
+            let i = if is_bit_pattern {
+                bits
+            } else {
+                (128 - int.leading_zeros()) as u8
             };
             let tmp = if i == 0 {
                 1
             } else {
                 i / 4 + (i % 4 != 0) as u8
             };
-            tmp + with_0x
+            tmp + separator_count(tmp, group_len(fmt.number_fmt, fmt)) + with_0x
         }
         NumberFmt::Binary => {
             let with_0b = (fmt.is_alternate as u8) * 2;
-            let i = match sign {
-                Sign::Negative => bits,
-                Sign::Positive => (128 - int.leading_zeros()) as u8,
+            let i = if is_bit_pattern {
+                bits
+            } else {
+                (128 - int.leading_zeros()) as u8
             };
-            (if i == 0 { 1 } else { i }) + with_0b
+            let tmp = if i == 0 { 1 } else { i };
+            tmp + separator_count(tmp, group_len(fmt.number_fmt, fmt)) + with_0b
         }
+    };
+
+    len + with_sign
+}
+
+// the amount of `_` separators in an integer with `digits` digits
+const fn separator_count(digits: u8, group_len: u8) -> u8 {
+    match (digits - 1).checked_div(group_len) {
+        Some(count) => count,
+        None => 0,
     }
 }
 
+// the amount of digits between `_` separators for integers formatted with `number_fmt`,
+// `0` if the digits aren't grouped.
+pub(crate) const fn group_len(number_fmt: NumberFmt, fmt: FmtArg) -> u8 {
+    match number_fmt {
+        _ if !fmt.digit_grouping() => 0,
+        NumberFmt::Decimal => 3,
+        _ => fmt.radix_group_len(),
+    }
+}
+
+pub(crate) const fn compute_radix_len(mut n: u128, radix: u8, plus_sign: bool) -> u8 {
+    let radix = radix as u128;
+    let mut len = 1 + plus_sign as u8;
+    while n >= radix {
+        n /= radix;
+        len += 1;
+    }
+    len
+}
+
 const fn compute_decimal_len(sign: Sign, mut n: u128) -> u8 {
     let mut len = matches!(sign, Sign::Negative) as u8 + 1;
     if n >= 1_0000_0000_0000_0000 {
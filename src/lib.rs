@@ -160,7 +160,57 @@
 //! ### Panic message length
 //!
 //! The panic message can only be up to [`MAX_PANIC_MSG_LEN`] long,
-//! after which it is truncated.
+//! after which it is truncated, ending with [`TRUNCATION_MARKER`].
+//! <br>[`concat_panic_bounded`] can be used to panic with a different maximum length.
+//! <br>[`concat_panic_max`] (requires the `"non_basic"` feature)
+//! panics with a different message instead of truncating messages longer than its maximum.
+//! <br>[`display_len`] (requires the `"non_basic"` feature)
+//! computes the length of the formatted message, without truncating it.
+//!
+//! # Testing
+//!
+//! The output of [`PanicFmt`] impls can be tested by formatting it into an
+//! [`ArrayString`] instead of panicking with it
+//! (these require the `"non_basic"` feature):
+//! - [`concat_!`]: formats constant arguments, the same as [`concat_panic`].
+//! - [`try_concat_string`]: formats `PanicVal`s (eg: from a `to_panicvals` method),
+//! returning an error if they don't fit in the `ArrayString`.
+//! - [`ArrayString::from_panicvals`]: like `try_concat_string`,
+//! but takes a single slice of `PanicVal`s.
+//!
+//! The `"test"` cargo feature is only for testing `const_panic` itself,
+//! its items aren't part of the public API.
+//!
+//! This example tests the output of a type that implements [`PanicFmt`]
+//! with the [`impl_panicfmt`] macro:
+//!
+#![cfg_attr(feature = "non_basic", doc = "```rust")]
+#![cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+//! use const_panic::{impl_panicfmt, try_concat_string, FmtArg};
+//!
+//! struct Point {
+//!     x: u32,
+//!     y: u32,
+//! }
+//!
+//! impl_panicfmt! {
+//!     struct Point {
+//!         x: u32,
+//!         y: u32,
+//!     }
+//! }
+//!
+//! let point = Point { x: 3, y: 5 };
+//!
+//! assert_eq!(
+//!     try_concat_string::<32>(&[&point.to_panicvals(FmtArg::DEBUG)]).unwrap(),
+//!     "Point { x: 3, y: 5 }",
+//! );
+//! assert_eq!(
+//!     try_concat_string::<32>(&[&point.to_panicvals(FmtArg::ALT_DEBUG)]).unwrap(),
+//!     "Point {\n    x: 3,\n    y: 5,\n}",
+//! );
+//! ```
 //!
 //! # Cargo features
 //!
@@ -173,8 +223,24 @@
 //! - `"rust_1_64"`(disabled by default):
 //! Enables formatting of additional items that require Rust 1.64.0 to do so.
 //!
+//! - `"rust_1_74"`(disabled by default):
+//! Enables formatting of additional items that require Rust 1.74.0 to do so
+//! (eg: `core::num::Saturating` and `core::ffi::CStr`),
+//! also enables the `"rust_1_64"` feature.
+//!
 //! - `"derive"`(disabled by default):
-//! Enables the [`PanicFmt` derive] macro.
+//! Enables the [`PanicFmt` derive] macro,
+//! and the [`fmt_panic`] macro (both are implemented with proc macros).
+//!
+//! - `"style"`(disabled by default):
+//! Makes the [ANSI styling constants](crate::fmt::RED) write their escape sequences,
+//! without this feature they're empty.
+//!
+//! - `"small_stack"`(disabled by default):
+//! Lowers [`MAX_PANIC_MSG_LEN`] from `32768` to `512`,
+//! and makes [`concat_panic_bounded`] skip its intermediate `6144` byte buffer,
+//! for targets with small stacks that call the panicking functions at runtime.
+//! <br>The tradeoff is that longer panic messages get truncated.
 //!
 //! # Plans
 //!
@@ -194,6 +260,16 @@
 //! [`impl_panicfmt`]: crate::impl_panicfmt
 //! [`flatten_panicvals`]: crate::flatten_panicvals
 //! [`MAX_PANIC_MSG_LEN`]: crate::MAX_PANIC_MSG_LEN
+//! [`TRUNCATION_MARKER`]: crate::TRUNCATION_MARKER
+//! [`concat_panic_bounded`]: crate::concat_panic_bounded
+//! [`concat_panic_max`]: macro@crate::concat_panic_max
+//! [`display_len`]: crate::display_len
+//! [`concat_!`]: crate::concat_
+//! [`concat_panic`]: macro@crate::concat_panic
+//! [`try_concat_string`]: crate::try_concat_string
+//! [`ArrayString`]: crate::ArrayString
+//! [`ArrayString::from_panicvals`]: crate::ArrayString::from_panicvals
+//! [`fmt_panic`]: crate::fmt_panic
 #![no_std]
 #![cfg_attr(feature = "docsrs", feature(doc_cfg))]
 #![warn(missing_docs)]
@@ -238,12 +314,19 @@ compile_error! {r##"please use cargo test --features "test""##}
 #[cfg(feature = "non_basic")]
 mod slice_stuff;
 
+mod wrapped_str;
+
 #[cfg(feature = "non_basic")]
 mod array_string;
 
 #[cfg(feature = "non_basic")]
 pub use crate::array_string::ArrayString;
 
+#[cfg(feature = "non_basic")]
+pub use crate::concat_panic_::{
+    concat_panic_max, concat_string_truncated, display_len, try_concat_string,
+};
+
 mod wrapper;
 
 mod fmt_impls {
@@ -260,6 +343,9 @@ mod fmt_impls {
     #[cfg(feature = "non_basic")]
     mod nonzero_impls;
 
+    #[cfg(feature = "non_basic")]
+    mod num_wrapper_impls;
+
     #[cfg(feature = "non_basic")]
     mod other_impls;
 
@@ -268,7 +354,11 @@ mod fmt_impls {
 }
 
 pub use crate::{
-    concat_panic_::{concat_panic, MAX_PANIC_MSG_LEN},
+    concat_panic_::{
+        concat_panic, concat_panic_bounded, concat_panic_bounded_with_marker, concat_panic_joined,
+        concat_panic_slice, concat_panic_with_crash_buffer, for_each_chunk, format_into,
+        format_into_truncated, FormatOutcome, NotEnoughSpace, MAX_PANIC_MSG_LEN, TRUNCATION_MARKER,
+    },
     panic_val::PanicVal,
     wrapper::StdWrapper,
 };
@@ -292,8 +382,15 @@ pub mod __ {
 
     pub use crate::*;
 
+    pub use crate::utils::{
+        assert_str_eq, no_error_panicvals, option_panicvals, EMPTY_PVS, NEWLINE_PVS,
+    };
+
     #[cfg(feature = "non_basic")]
     pub use crate::reexported_non_basic::*;
+
+    #[cfg(feature = "derive")]
+    pub use const_panic_proc_macros::__fmt_panic;
 }
 
 #[cfg(feature = "non_basic")]
@@ -301,7 +398,7 @@ pub mod __ {
 mod reexported_non_basic {
     pub use core::{
         option::Option::{self, None, Some},
-        primitive::str,
+        primitive::{bool, str},
     };
 
     pub use typewit::MakeTypeWitness;
@@ -309,8 +406,16 @@ mod reexported_non_basic {
     pub use crate::{
         concat_panic_::{compute_length, make_panic_string_unwrapped},
         const_default::ConstDefault,
+        fmt::{
+            merge_field_fmtarg,
+            struct_style_fmt::{FieldName, StructName, StructOpen},
+        },
         macros::concat_macro::ConcatCmd,
-        utils::{assert_flatten_panicvals_length, flatten_panicvals, panicvals_id},
+        slice_stuff::flatten_array_panicvals,
+        utils::{
+            assert_flatten_panicvals_length, flatten_panicvals, pad_panicvals, panicvals_id,
+            PvfPadding,
+        },
     };
 
     pub const EPV: crate::PanicVal<'_> = crate::PanicVal::EMPTY;
@@ -319,6 +424,9 @@ mod reexported_non_basic {
 #[cfg(feature = "derive")]
 include! {"./proc_macro_reexports/panicfmt_derive.rs"}
 
+#[cfg(feature = "derive")]
+include! {"./proc_macro_reexports/fmt_panic_macro.rs"}
+
 #[doc(hidden)]
 #[cfg(feature = "test")]
 pub mod test_utils;
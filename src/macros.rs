@@ -20,6 +20,9 @@ mod impl_panicfmt;
 #[macro_use]
 mod unwrapping;
 
+#[macro_use]
+mod impl_unit_panicfmt;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __write_array {
@@ -364,6 +367,200 @@ macro_rules! concat_panic {
     )
 }
 
+/// Panics with the concatenation of the arguments, preceded by a header.
+///
+/// This takes a `$header` expression, followed by a `;`,
+/// then the same arguments as [`concat_panic`].
+///
+/// `$header` is written before the rest of the arguments with `Display` formatting,
+/// it's usually a `&str` literal, eg: `"[const assert] "`.
+/// <br>The header is part of the panic message like any other argument,
+/// counting towards its [maximum length](crate#panic-message-length).
+///
+/// This is useful for defining a macro which prefixes all the panic messages of a crate
+/// with the same text, without repeating it at every call site.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::concat_panic_with_header;
+///
+/// macro_rules! my_panic {
+///     ($($args:tt)*) => {
+///         concat_panic_with_header!("[const assert] "; $($args)*)
+///     };
+/// }
+///
+/// const _: () = check_len(&[3, 5, 8]);
+///
+/// const fn check_len(slice: &[u8]) {
+///     if slice.len() != 4 {
+///         my_panic!("expected 4 elements, found: ", slice.len())
+///     }
+/// }
+/// ```
+/// The above code fails to compile with this error:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///   --> src/macros.rs:9:15
+///    |
+/// 9  | const _: () = check_len(&[3, 5, 8]);
+///    |               ^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at '[const assert] expected 4 elements, found: 3', src/macros.rs:13:9
+/// ```
+///
+/// [`concat_panic`]: macro@crate::concat_panic
+#[macro_export]
+macro_rules! concat_panic_with_header {
+    ($header:expr; $($args:tt)*) => (
+        $crate::__concat_func_setup!{
+            (|args| $crate::concat_panic(args))
+            [($crate::FmtArg::DISPLAY, $header)]
+            [$($args)*,]
+        }
+    )
+}
+
+/// Panics with the concatenation of the arguments, framed by leading and trailing newlines.
+///
+/// This takes the same arguments as [`concat_panic`].
+///
+/// The newlines make the message stand out from the surrounding text
+/// (eg: the location of the panic, or the output of a test harness),
+/// without writing `"\n"` literals around the arguments.
+/// <br>The newlines are part of the panic message like any other argument,
+/// counting towards its [maximum length](crate#panic-message-length),
+/// so the trailing newline is replaced by the
+/// [truncation marker](crate::TRUNCATION_MARKER) in truncated messages.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::concat_panic_framed;
+///
+/// const _: () = check_len(&[3, 5, 8]);
+///
+/// const fn check_len(slice: &[u8]) {
+///     if slice.len() != 4 {
+///         concat_panic_framed!("expected 4 elements, found: ", slice.len())
+///     }
+/// }
+/// ```
+/// The above code fails to compile with this error:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/macros.rs:3:15
+///   |
+/// 3 | const _: () = check_len(&[3, 5, 8]);
+///   |               ^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at '
+/// expected 4 elements, found: 3
+/// ', src/macros.rs:7:9
+/// ```
+///
+/// [`concat_panic`]: macro@crate::concat_panic
+#[macro_export]
+macro_rules! concat_panic_framed {
+    ($($args:tt)*) => (
+        $crate::__concat_func_setup!{
+            (
+                |args| $crate::concat_panic(args);
+                suffix [($crate::FmtArg::DISPLAY, "\n")]
+            )
+            [($crate::FmtArg::DISPLAY, "\n")]
+            [$($args)*,]
+        }
+    )
+}
+
+/// Panics with all of the `Some` diagnostics passed to it, separated by newlines.
+///
+/// This takes a comma-separated list of `Option<[PanicVal<'_>; N]>` expressions
+/// (the `N` of each one can be different),
+/// and delegates to the [`concat_panic`](fn@crate::concat_panic) function
+/// if at least one of the arguments is `Some`,
+/// otherwise this evaluates to `()`.
+///
+/// This is useful for reporting every problem found when validating something,
+/// instead of only the first one.
+///
+/// The arrays of [`PanicVal`](crate::PanicVal)s can be constructed with the
+/// [`flatten_panicvals`](crate::flatten_panicvals) macro.
+///
+/// # Example
+///
+#[cfg_attr(feature = "non_basic", doc = "```compile_fail")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{concat_panic_all, flatten_panicvals, FmtArg};
+///
+/// const _: () = check_ports(&[80, 0, 443, 70000]);
+///
+/// const fn check_ports(ports: &[u32]) {
+///     concat_panic_all!(
+///         check_port(ports, 0),
+///         check_port(ports, 1),
+///         check_port(ports, 2),
+///         check_port(ports, 3),
+///     )
+/// }
+///
+/// const fn check_port(ports: &[u32], index: usize) -> Option<[const_panic::PanicVal<'_>; 4]> {
+///     let port = ports[index];
+///     if 0 < port && port <= 65535 {
+///         None
+///     } else {
+///         Some(flatten_panicvals!(FmtArg::DEBUG;
+///             "invalid port at index ", index, ": ", port
+///         ))
+///     }
+/// }
+/// ```
+/// The above code produces this compile-time error:
+/// ```text
+/// error[E0080]: evaluation panicked: invalid port at index 1: 0
+///               invalid port at index 3: 70000
+///   --> src/macros.rs:3:15
+///  3 | const _: () = check_ports(&[80, 0, 443, 70000]);
+///    |               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ evaluation of `_` failed inside this call
+/// note: inside `check_ports`
+///   --> src/macros.rs:6:5
+///  6 | /     concat_panic_all!(
+///  7 | |         check_port(ports, 0),
+///  8 | |         check_port(ports, 1),
+///  9 | |         check_port(ports, 2),
+/// 10 | |         check_port(ports, 3),
+/// 11 | |     )
+///    | |_____^ the failure occurred here
+///
+/// ```
+///
+#[macro_export]
+macro_rules! concat_panic_all {
+    ($($group:expr),+ $(,)?) => ({
+        match [$($crate::__::option_panicvals(&$group)),*] {
+            groups => {
+                let mut args = [
+                    $crate::__::EMPTY_PVS;
+                    2 * [$($crate::__::stringify!($group)),*].len()
+                ];
+                let mut any_some = false;
+                let mut i = 0;
+                while i < groups.len() {
+                    if let $crate::__::Some(group) = groups[i] {
+                        if any_some {
+                            args[2 * i] = $crate::__::NEWLINE_PVS;
+                        }
+                        args[2 * i + 1] = group;
+                        any_some = true;
+                    }
+                    i += 1;
+                }
+                if any_some {
+                    $crate::concat_panic(&args)
+                }
+            }
+        }
+    })
+}
+
 // This macro takes the optional `$fmt:expr;` argument before everything else.
 // But I had to parse the argument manually,
 // because `$fmt:expr;` fails compilation instead of trying the following branches
@@ -399,7 +596,7 @@ macro_rules! __concat_func {
         $crate::__concat_func!{
             $fmt
             $args
-            [$($prev)* ($crate::__set_fmt_from_kw!(display, $fmt), $expr)]
+            [$($prev)* ($crate::__set_fmt_from_kw!(__literal, $fmt), $expr)]
             [$($rem)*]
         }
     };
@@ -411,6 +608,19 @@ macro_rules! __concat_func {
             [$($rem)*]
         }
     };
+    (
+        $fmt:ident
+        (|$args:ident| $function_call:expr; suffix [$($suffix:tt)*])
+        [$($prev:tt)*]
+        [$(,)*]
+    ) => {
+        $crate::__concat_func!{
+            $fmt
+            (|$args| $function_call)
+            [$($prev)* $($suffix)*]
+            []
+        }
+    };
     ($fmt:ident (|$args:ident| $function_call:expr) [$(($fmt_arg:expr, $reff:expr))*] [$(,)*]) => {
         match &[
             $(
@@ -436,6 +646,21 @@ macro_rules! __set_fmt_from_kw {
         $fmtarg = $fmtarg.unindent();
         $fmtarg.set_display()
     }};
+    // used for the delimiter after the name of a struct or variant,
+    // keeping the `FmtKind` so that `FmtArg::struct_style` can be checked
+    (__struct_open, $fmtarg:ident) => {{
+        $fmtarg = $fmtarg.indent();
+        $fmtarg
+    }};
+    // used for literal arguments, which are written as-is
+    (__literal, $fmtarg:ident) => {
+        $fmtarg
+            .set_display()
+            .set_alternate(false)
+            .set_shell_quote(false)
+            .set_escape_control_in_display(false)
+            .set_wrap_width(None)
+    };
     (display, $fmtarg:ident) => {
         $fmtarg.set_display().set_alternate(false)
     };
@@ -484,9 +709,23 @@ macro_rules! __set_fmt_from_kw {
     ({#b}, $fmtarg:ident) => {
         $fmtarg.set_bin().set_alternate(true)
     };
+    (grouped_hex, $fmtarg:ident) => {
+        $fmtarg.set_hex().set_alternate(true).set_digit_grouping(true)
+    };
+    (grouped_bin, $fmtarg:ident) => {
+        $fmtarg.set_bin().set_alternate(true).set_digit_grouping(true)
+    };
+    (plus, $fmtarg:ident) => {
+        $fmtarg.set_plus_sign(true)
+    };
     (_, $fmtarg:ident) => {
         $fmtarg
     };
+    (($($fmt:tt)*), $fmtarg:ident) => {{
+        let mut fmt: $crate::FmtArg = $($fmt)*;
+        fmt.indentation = $fmtarg.indentation;
+        fmt
+    }};
     ($kw:tt, $fmtarg:ident) => {
         compile_error!(concat!(
             "unrecognized formatting specifier: ",
@@ -501,6 +740,10 @@ macro_rules! __set_fmt_from_kw {
             "- alt_hex/{#X}\n",
             "- bin/{b}\n",
             "- alt_bin/{#b}\n",
+            "- grouped_hex\n",
+            "- grouped_bin\n",
+            "- plus\n",
+            "- a parenthesized `FmtArg` expression\n",
         ))
     };
 }
@@ -122,3 +122,225 @@ macro_rules! concat_assert {
         }
     }};
 }
+
+/// Asserts that two `&str`s are equal,
+/// printing the first byte index where they differ and the text around it.
+///
+/// This is useful for testing string-generating const code.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::const_assert_str_eq;
+///
+/// const _: () = const_assert_str_eq!(greeting("world"), "Hello, World!");
+///
+/// const fn greeting(name: &str) -> &'static str {
+///     "Hello, world!"
+/// }
+/// ```
+/// the above code errors with this message:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/macros/concat_assert.rs:133:15
+///   |
+/// 5 | const _: () = const_assert_str_eq!(greeting("world"), "Hello, World!");
+///   |               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'assertion failed: `left == right`
+/// first difference at byte 7
+///   left: "Hello, world!"
+///  right: "Hello, World!"', src/macros/concat_assert.rs:5:15
+///   |
+///   = note: this error originates in the macro `const_assert_str_eq` (in Nightly builds, run with -Z macro-backtrace for more info)
+/// ```
+///
+/// When the difference is far from the start or end of the strings,
+/// only the text around it is printed:
+///
+/// ```text
+/// assertion failed: `left == right`
+/// first difference at byte 14
+///   left: ..."cdefghijXlmnopqr"...
+///  right: ..."cdefghijYlmnopqr"...
+/// ```
+#[macro_export]
+macro_rules! const_assert_str_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__::assert_str_eq($left, $right)
+    };
+}
+
+/// Asserts that a comparison between two values is true,
+/// printing both values and the comparison operator otherwise.
+///
+/// The operands are `Debug` formatted, and are evaluated once.
+///
+/// # Syntax
+///
+/// ```text
+/// const_assert_cmp!($left:expr, $operator:tt, $right:expr)
+/// ```
+///
+/// `$operator` must be one of `<`, `<=`, `>`, `>=`, `==`, or `!=`.
+///
+/// The operands must implement [`PanicFmt`](trait@crate::PanicFmt),
+/// and the comparison operator must be usable in const contexts for their type
+/// (eg: integers, `bool`s, and `char`s).
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::const_assert_cmp;
+///
+/// const _: () = check_len(3, 2);
+///
+/// const fn check_len(len: usize, max: usize) {
+///     const_assert_cmp!(len, <, max);
+/// }
+/// ```
+/// the above code errors with this message:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/macros/concat_assert.rs:185:15
+///   |
+/// 3 | const _: () = check_len(3, 2);
+///   |               ^^^^^^^^^^^^^^^ the evaluated program panicked at 'assertion failed: 3 < 2', src/macros/concat_assert.rs:6:5
+/// ```
+#[macro_export]
+macro_rules! const_assert_cmp {
+    ($left:expr, $op:tt, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::__const_assert_cmp_op!(*left, $op, *right) {
+                    $crate::concat_panic!(
+                        "assertion failed: ",
+                        debug: *left,
+                        display: $crate::__::concat!(" ", $crate::__::stringify!($op), " "),
+                        debug: *right,
+                    )
+                }
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __const_assert_cmp_op {
+    ($left:expr, <, $right:expr) => {
+        $left < $right
+    };
+    ($left:expr, <=, $right:expr) => {
+        $left <= $right
+    };
+    ($left:expr, >, $right:expr) => {
+        $left > $right
+    };
+    ($left:expr, >=, $right:expr) => {
+        $left >= $right
+    };
+    ($left:expr, ==, $right:expr) => {
+        $left == $right
+    };
+    ($left:expr, !=, $right:expr) => {
+        $left != $right
+    };
+    ($left:expr, $op:tt, $right:expr) => {
+        $crate::__::compile_error!($crate::__::concat!(
+            "unsupported comparison operator: `",
+            $crate::__::stringify!($op),
+            "`, expected one of `<`, `<=`, `>`, `>=`, `==`, or `!=`",
+        ))
+    };
+}
+
+/// Asserts that the `to_panicvals` method of a type returns an array of
+/// [`PanicFmt::PV_COUNT`](crate::PanicFmt::PV_COUNT) `PanicVal`s.
+///
+/// This is for checking manual [`PanicFmt`](crate::PanicFmt) impls,
+/// whose `PV_COUNT` is hand-written and can drift from the length of
+/// the array that `to_panicvals` returns
+/// (eg: after a field is added to the type).
+///
+/// This macro takes the type and a value of that type to call `to_panicvals` on,
+/// and can be used both in `const _: () = ...;` items and at runtime (eg: in tests).
+///
+/// # Syntax
+///
+/// ```text
+/// assert_pv_count!($Type:ty => $value:expr)
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{assert_pv_count, FmtArg, PanicFmt, PanicVal};
+///
+/// const _: () = assert_pv_count!(Port => Port(8080));
+///
+/// struct Port(u16);
+///
+/// impl PanicFmt for Port {
+///     type This = Self;
+///     type Kind = const_panic::IsCustomType;
+///     const PV_COUNT: usize = 2;
+/// }
+///
+/// impl Port {
+///     const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; 2] {
+///         [PanicVal::write_str("port "), PanicVal::from_u16(self.0, f)]
+///     }
+/// }
+/// ```
+///
+/// ### Mismatched count
+///
+/// ```compile_fail
+/// use const_panic::{assert_pv_count, FmtArg, PanicFmt, PanicVal};
+///
+/// const _: () = assert_pv_count!(Port => Port(8080));
+///
+/// struct Port(u16);
+///
+/// impl PanicFmt for Port {
+///     type This = Self;
+///     type Kind = const_panic::IsCustomType;
+///     const PV_COUNT: usize = 1;
+/// }
+///
+/// impl Port {
+///     const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; 2] {
+///         [PanicVal::write_str("port "), PanicVal::from_u16(self.0, f)]
+///     }
+/// }
+/// ```
+/// the above code errors with this message:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///   |
+///   | const _: () = assert_pv_count!(Port => Port(8080));
+///   |               ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at '
+/// the `to_panicvals` method of `Port` returned 2 `PanicVal`s, but its `PV_COUNT` is 1
+/// '
+/// ```
+#[macro_export]
+macro_rules! assert_pv_count {
+    ($ty:ty => $value:expr $(,)?) => {{
+        let value: $ty = $value;
+        let returned: $crate::__::usize = $crate::coerce_fmt!(value)
+            .to_panicvals($crate::FmtArg::DEBUG)
+            .len();
+        let pv_count: $crate::__::usize = <$ty as $crate::PanicFmt>::PV_COUNT;
+
+        if returned != pv_count {
+            $crate::concat_panic!(
+                "\nthe `to_panicvals` method of `",
+                display: $crate::__::stringify!($ty),
+                "` returned ",
+                returned,
+                " `PanicVal`s, but its `PV_COUNT` is ",
+                pv_count,
+                "\n",
+            )
+        }
+    }};
+}
@@ -67,6 +67,64 @@ macro_rules! concat_ {
     })
 }
 
+/// Panics with the concatenation of the arguments,
+/// panicking with a different message if it's longer than `MAX` bytes.
+///
+/// This takes the `MAX` maximum length, followed by a `;`,
+/// then the same arguments as [`concat_panic`].
+///
+/// Unlike [`concat_panic`], the panic message is never truncated,
+/// a message longer than `MAX` bytes makes this panic with its length instead
+/// (which is a compile-time error when this is evaluated at compile-time).
+///
+/// This macro delegates to the [`concat_panic_max`](fn@crate::concat_panic_max) function.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::concat_panic_max;
+///
+/// const _: () = check_id(b"ABC-0123456789");
+///
+/// const fn check_id(id: &[u8]) {
+///     if id.len() > 8 {
+///         concat_panic_max!(32; "id is too long: ", id.len())
+///     }
+/// }
+/// ```
+/// The above code fails to compile with this error:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/macros/concat_macro.rs:3:15
+///   |
+/// 3 | const _: () = check_id(b"ABC-0123456789");
+///   |               ^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'id is too long: 14', src/macros/concat_macro.rs:7:9
+/// ```
+///
+/// If the arguments don't fit in the `MAX` bytes,
+/// like `concat_panic_max!(16; "id is too long: ", id.len())`,
+/// this is the error instead:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/macros/concat_macro.rs:3:15
+///   |
+/// 3 | const _: () = check_id(b"ABC-0123456789");
+///   |               ^^^^^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'the panic message is 18 bytes long, which is longer than the maximum of 16 bytes', src/macros/concat_macro.rs:7:9
+/// ```
+///
+/// [`concat_panic`]: macro@crate::concat_panic
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+#[macro_export]
+macro_rules! concat_panic_max {
+    ($max:expr; $($args:tt)*) => (
+        $crate::__concat_func_setup!{
+            (|args| $crate::concat_panic_max::<{ $max }>(args))
+            []
+            [$($args)*,]
+        }
+    )
+}
+
 #[doc(hidden)]
 pub enum ConcatCmd<Ret, const CAP: usize> {
     ComputeLength(TypeEq<Ret, usize>),
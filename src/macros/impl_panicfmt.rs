@@ -1045,14 +1045,14 @@ macro_rules!  __ipm_fmt {
 
         $crate::__::flatten_panicvals::<{$count}>(&[
             &[
-                $crate::PanicVal::write_str($crate::__::stringify!($typename)),
+                $crate::__::StructName($crate::__::stringify!($typename)).to_panicval($fmt),
                 {
                     $fmt = $fmt.indent();
-                    open.to_panicval($fmt)
+                    $crate::__::StructOpen(open).to_panicval($fmt)
                 }
             ],
             $(
-                $crate::__ipm_pv_fmt_field_name!($delimiter $fname),
+                $crate::__ipm_pv_fmt_field_name!($delimiter $fname $fmt),
                 &$crate::PanicFmt::PROOF
                     .infer($fname)
                     .coerce($fname)
@@ -1081,14 +1081,15 @@ macro_rules!  __ipm_fmt {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __ipm_pv_fmt_field_name {
-    (Tupled $field_name:ident) => {
+    (Tupled $field_name:ident $fmt:ident) => {
         &[]
     };
-    (Braced $field_name:ident) => {
-        &[$crate::PanicVal::write_str($crate::__::concat!(
+    (Braced $field_name:ident $fmt:ident) => {
+        &[$crate::__::FieldName($crate::__::concat!(
             $crate::__::stringify!($field_name),
             ": "
-        ))]
+        ))
+        .to_panicval($fmt)]
     };
 }
 
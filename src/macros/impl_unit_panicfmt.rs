@@ -0,0 +1,63 @@
+/// Implements [`PanicFmt`](crate::fmt::PanicFmt) for a unit type,
+/// which is formatted as a fixed name.
+///
+/// This is the recommended way to make unit structs (and other marker types)
+/// formattable, since it generates less code than
+/// the [`PanicFmt`](derive@crate::PanicFmt) derive or
+/// [`impl_panicfmt`](crate::impl_panicfmt).
+///
+/// The generated `to_panicvals` method outputs the name regardless of the [`FmtArg`]
+/// it's passed.
+///
+/// # Syntax
+///
+/// ```text
+/// impl_unit_panicfmt!(Type)
+/// impl_unit_panicfmt!(Type, "name")
+/// ```
+///
+/// The name defaults to the stringified type.
+///
+/// # Example
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{ArrayString, FmtArg, impl_unit_panicfmt};
+///
+/// struct Marker;
+///
+/// struct Sealed;
+///
+/// struct Byte<const N: u8>;
+///
+/// impl_unit_panicfmt!(Marker);
+/// impl_unit_panicfmt!(Sealed, "<sealed>");
+/// impl_unit_panicfmt!(Byte<3>, "Byte");
+///
+/// assert_eq!(const_panic::concat_!(Marker), "Marker");
+/// assert_eq!(const_panic::concat_!(FmtArg::ALT_DEBUG; Sealed), "<sealed>");
+/// assert_eq!(const_panic::concat_!(Byte::<3>), "Byte");
+///
+/// ```
+///
+/// [`FmtArg`]: crate::FmtArg
+#[macro_export]
+macro_rules! impl_unit_panicfmt {
+    ($ty:ty $(,)?) => {
+        $crate::impl_unit_panicfmt! {$ty, $crate::__::stringify!($ty)}
+    };
+    ($ty:ty, $name:expr $(,)?) => {
+        impl $crate::PanicFmt for $ty {
+            type This = Self;
+            type Kind = $crate::IsCustomType;
+            const PV_COUNT: $crate::__::usize = 1;
+        }
+
+        impl $ty {
+            /// Converts this unit type to a single-element `PanicVal` array.
+            pub const fn to_panicvals(&self, _: $crate::FmtArg) -> [$crate::PanicVal<'static>; 1] {
+                [$crate::PanicVal::write_str($name)]
+            }
+        }
+    };
+}
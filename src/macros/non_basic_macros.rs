@@ -11,11 +11,15 @@
 /// flatten_panicvals!(
 ///     $fmtarg:expr $(, $pv_count:expr)?;
 ///     $(
-///         $($Type:ty => )? $($format_override:tt :)? $arg_to_fmt:expr
+///         $($Type:ty => )?
+///         $($format_override:tt)? $($flag:ident = $flag_value:tt)* $(:)?
+///         $arg_to_fmt:expr
 ///     ),*
 ///     $()?
 /// )
 /// ```
+/// (each argument can also be a [conditional group](#conditional-arguments)
+/// of arguments, or an [array of custom types](#array-arguments))
 ///
 /// `$fmtarg` is a [`FmtArg`](crate::FmtArg) argument
 /// which determines how non-literal `$arg_to_fmt` arguments are formatted.
@@ -29,6 +33,9 @@
 /// [`$format_override`](#formatting-overrides) overrides the `$fmtarg` argument,
 /// changing how that `$arg_to_fmt` argument is formatted.
 ///
+/// [`$flag = $flag_value`](#padding-flags) pads the formatted `$arg_to_fmt` argument,
+/// the `:` is required if there's a `$format_override` or any flag.
+///
 /// `$arg_to_fmt` are the formatted arguments,
 /// which must implement the [`PanicFmt`](crate::fmt::PanicFmt) trait.
 ///
@@ -63,6 +70,249 @@
 /// # }
 /// ```
 ///
+/// ### Conditional arguments
+///
+/// Arguments can be grouped with the `if $condition => ( $($args)* )` syntax,
+/// where `$condition` is a `bool` expression,
+/// and `$args` uses the same syntax as the other arguments.
+///
+/// When `$condition` is true, the grouped arguments are formatted as usual,
+/// when it's false, nothing is written for them.
+///
+/// So that the length of the returned array can still be computed at compile-time,
+/// the grouped arguments take up the same amount of elements in the array
+/// regardless of `$condition`,
+/// and those elements are filled with `PanicVal::EMPTY` when `$condition` is false.
+///
+/// Changes to the indentation of `$fmtarg` (by the `open` and `close` overrides)
+/// don't carry over from inside the group to the arguments after it.
+///
+/// ```rust
+/// use const_panic::{ArrayString, FmtArg, PanicVal, flatten_panicvals};
+///
+/// const fn message(index: usize, len: Option<usize>) -> [PanicVal<'static>; 4] {
+///     let (has_len, len) = match len {
+///         Some(x) => (true, x),
+///         None => (false, 0),
+///     };
+///
+///     flatten_panicvals!(FmtArg::DEBUG;
+///         "index: ", index,
+///         if has_len => (", len: ", len),
+///     )
+/// }
+///
+/// assert_eq!(
+///     ArrayString::<99>::from_panicvals(&message(3, Some(2))).unwrap(),
+///     "index: 3, len: 2",
+/// );
+///
+/// assert_eq!(
+///     ArrayString::<99>::from_panicvals(&message(3, None)).unwrap(),
+///     "index: 3",
+/// );
+/// ```
+///
+/// ### Variant arguments
+///
+/// The `variant: $Name $fields` syntax formats an enum variant (or a struct)
+/// the same way that `Debug` does,
+/// where `$fields` is either:
+/// - nothing: for unit variants, which are written as `$Name`.
+/// - `( $($field:expr),* )`: for tuple variants.
+/// - `{ $($field_name:ident $(: $field:expr)?),* }`: for braced variants,
+/// where the `$field_name` shorthand formats the `$field_name` variable.
+///
+/// The fields are formatted with `$fmtarg`,
+/// with the delimiters and separators of [`fmt`](crate::fmt) between them
+/// (eg: [`fmt::OpenParen`](crate::fmt::OpenParen) and [`fmt::COMMA_SEP`](crate::fmt::COMMA_SEP)).
+///
+/// ```rust
+/// use const_panic::{ArrayString, FmtArg, PanicVal, flatten_panicvals};
+///
+/// enum Shape {
+///     Point,
+///     Circle(u32),
+///     Rect { width: u32, height: u32 },
+/// }
+///
+/// const fn shape_panicvals(shape: &Shape, fmtarg: FmtArg) -> [PanicVal<'static>; 9] {
+///     match *shape {
+///         Shape::Point => flatten_panicvals!(fmtarg, 9; variant: Point),
+///         Shape::Circle(radius) => flatten_panicvals!(fmtarg, 9; variant: Circle(radius)),
+///         Shape::Rect { width, height } => flatten_panicvals!(fmtarg, 9;
+///             variant: Rect { width, height }
+///         ),
+///     }
+/// }
+///
+/// let fmt = |shape, fmtarg| ArrayString::<99>::from_panicvals(&shape_panicvals(&shape, fmtarg));
+///
+/// assert_eq!(fmt(Shape::Point, FmtArg::DEBUG).unwrap(), "Point");
+/// assert_eq!(fmt(Shape::Circle(3), FmtArg::DEBUG).unwrap(), "Circle(3)");
+/// assert_eq!(
+///     fmt(Shape::Rect { width: 5, height: 8 }, FmtArg::DEBUG).unwrap(),
+///     "Rect { width: 5, height: 8 }",
+/// );
+/// assert_eq!(
+///     fmt(Shape::Circle(3), FmtArg::ALT_DEBUG).unwrap(),
+///     "Circle(\n    3,\n)",
+/// );
+/// ```
+///
+/// ### Field arguments
+///
+/// The `fields: { $($field_name:ident $(: $args)?),* }` syntax formats
+/// the fields of a braced struct, without the delimiters around them,
+/// for when the type name or the braces need to be written separately.
+///
+/// Each field is written as its name, followed by `": "`,
+/// followed by `$args` (which uses the same syntax as the other arguments,
+/// including the `$type => $arg_to_fmt` syntax and the Display formatting of literals),
+/// with [`fmt::COMMA_SEP`](crate::fmt::COMMA_SEP) after every field but the last,
+/// which is followed by [`fmt::COMMA_TERM`](crate::fmt::COMMA_TERM).
+/// The `$field_name` shorthand formats the `$field_name` variable.
+///
+/// Because commas separate the fields,
+/// types with commas in `$type => $arg_to_fmt` must be wrapped in parentheses.
+///
+/// ```rust
+/// use const_panic::{ArrayString, FmtArg, PanicVal, flatten_panicvals, fmt};
+///
+/// struct Point {
+///     x: u32,
+///     y: u32,
+///     tags: &'static [&'static str],
+/// }
+///
+/// impl Point {
+///     const fn to_panicvals(&self, fmtarg: FmtArg) -> [PanicVal<'_>; 12] {
+///         let tags = self.tags;
+///         flatten_panicvals!(fmtarg, 12;
+///             "Point",
+///             open: fmt::OpenBrace,
+///             fields: { x: self.x, y: self.y, tags },
+///             close: fmt::CloseBrace,
+///         )
+///     }
+/// }
+///
+/// const POINT: Point = Point { x: 3, y: 5, tags: &["origin"] };
+///
+/// let fmt = |fmtarg| ArrayString::<99>::from_panicvals(&POINT.to_panicvals(fmtarg));
+///
+/// assert_eq!(
+///     fmt(FmtArg::DEBUG).unwrap(),
+///     "Point { x: 3, y: 5, tags: [\"origin\"] }",
+/// );
+/// assert_eq!(
+///     fmt(FmtArg::ALT_DEBUG).unwrap(),
+///     "Point {\n    x: 3,\n    y: 5,\n    tags: [\n        \"origin\",\n    ],\n}",
+/// );
+/// ```
+///
+/// ### Array arguments
+///
+/// The `array($Type; $len) => $array` syntax formats an array
+/// (or a reference to one) of `$len` elements of the `$Type` type,
+/// for element types that don't implement [`PanicFmt`](crate::fmt::PanicFmt) for arrays of them
+/// (eg: custom types).
+///
+/// Each element is formatted by calling its `to_panicvals` method,
+/// with the indentation of the `$fmtarg` [incremented](crate::FmtArg::indent),
+/// so that the elements are one level deeper than the `[` `]` brackets
+/// with alternate formatting, the same as `core::fmt` does.
+///
+/// This produces `$len * (<$Type as PanicFmt>::PV_COUNT + 1) + 2` `PanicVal`s.
+///
+/// ```rust
+/// use const_panic::{ArrayString, FmtArg, PanicVal, flatten_panicvals, fmt};
+///
+/// #[derive(Debug)]
+/// struct Point {
+///     x: u8,
+///     y: u8,
+/// }
+///
+/// impl Point {
+///     const fn to_panicvals(&self, fmtarg: FmtArg) -> [PanicVal<'_>; 9] {
+///         flatten_panicvals!(fmtarg;
+///             "Point",
+///             open: fmt::OpenBrace,
+///             fields: { x: self.x, y: self.y },
+///             close: fmt::CloseBrace,
+///         )
+///     }
+/// }
+///
+/// impl const_panic::PanicFmt for Point {
+///     type This = Self;
+///     type Kind = const_panic::IsCustomType;
+///     const PV_COUNT: usize = 9;
+/// }
+///
+/// const POINTS: [Point; 2] = [Point { x: 3, y: 5 }, Point { x: 8, y: 13 }];
+///
+/// let fmt = |fmtarg| {
+///     ArrayString::<99>::from_panicvals(&flatten_panicvals!(fmtarg;
+///         array(Point; 2) => POINTS
+///     ))
+///     .unwrap()
+/// };
+///
+/// assert_eq!(fmt(FmtArg::DEBUG), "[Point { x: 3, y: 5 }, Point { x: 8, y: 13 }]");
+/// assert_eq!(fmt(FmtArg::DEBUG), *format!("{:?}", POINTS));
+///
+/// // the fields of each `Point` are indented two levels
+/// assert_eq!(fmt(FmtArg::ALT_DEBUG), *format!("{:#?}", POINTS));
+/// ```
+///
+/// ### Padding flags
+///
+/// Arguments can be padded with spaces to a minimum width by passing
+/// `key = value` flags before the `:`,
+/// after the [`$format_override`](#formatting-overrides) if there is one,
+/// eg: `width = 8: value` or `debug width = 8 align = left: value`.
+///
+/// These are the accepted flags:
+/// - `width = $width`: the minimum amount of bytes that the argument is padded to,
+///   `$width` must be a single token (eg: `8`, `WIDTH`, or `(N + 1)`) of type `u8`.
+///   Arguments that are already at least this long are not padded (nor truncated).
+/// - `align = right` (the default): writes the padding before the argument,
+///   like [`PanicVal::with_leftpad`] does.
+/// - `align = left`: writes the padding after the argument,
+///   like [`PanicVal::with_rightpad`] does.
+///
+/// The padding is written by an extra [`PanicVal`] whose
+/// [`with_leftpad`](crate::PanicVal::with_leftpad) is set to the
+/// [`FmtArg::indentation`] that its length requires,
+/// so padded arguments take up one more element in the returned array.
+///
+/// [`PanicVal`]: crate::PanicVal
+/// [`PanicVal::with_leftpad`]: crate::PanicVal::with_leftpad
+/// [`PanicVal::with_rightpad`]: crate::PanicVal::with_rightpad
+/// [`FmtArg::indentation`]: crate::FmtArg::indentation
+///
+/// ```rust
+/// use const_panic::{ArrayString, FmtArg, PanicVal, flatten_panicvals};
+///
+/// const fn row(name: &str, count: u32) -> [PanicVal<'_>; 7] {
+///     flatten_panicvals!(FmtArg::DEBUG;
+///         "|",
+///         display width = 6 align = left: name,
+///         "|",
+///         width = 4: count,
+///         "|",
+///     )
+/// }
+///
+/// let fmt = |name, count| ArrayString::<99>::from_panicvals(&row(name, count)).unwrap();
+///
+/// assert_eq!(fmt("foo", 3), "|foo   |   3|");
+/// assert_eq!(fmt("barbaz", 1000), "|barbaz|1000|");
+/// assert_eq!(fmt("qux quux", 10000), "|qux quux|10000|");
+/// ```
+///
 /// ### Parsing limitation
 ///
 /// Because of limitations of `macro_rules!` macros,
@@ -74,10 +324,12 @@
 ///
 ///
 #[doc = formatting_docs!("
-- `open`: increments `$fmtarg`'s indentation by [`fmt::INDENTATION_STEP`]
+- `open`: increments `$fmtarg`'s indentation by its `indentation_step`
+([`fmt::INDENTATION_STEP`] by default)
 before formatting the argument, and uses Display formatting for that argument.
 
-- `close`: decrements `$fmtarg`'s indentation by [`fmt::INDENTATION_STEP`]
+- `close`: decrements `$fmtarg`'s indentation by its `indentation_step`
+([`fmt::INDENTATION_STEP`] by default)
 before formatting the argument, and uses Display formatting for that argument.
 
 [`fmt::INDENTATION_STEP`]: crate::fmt::INDENTATION_STEP
@@ -251,7 +503,8 @@ before formatting the argument, and uses Display formatting for that argument.
 ///         flatten_panicvals! {fmtarg;
 ///             "Foo",
 ///             // the `open:` format override increments `fmtarg.indentation`
-///             // by `const_panic::fmt::INDENTATION_STEP` spaces.
+///             // by `fmtarg.indentation_step()` spaces
+///             // (`const_panic::fmt::INDENTATION_STEP` by default).
 ///             // The indentation field is used by these constants when the
 ///             // `fmtarg.is_alternate` flag is enabled.
 ///             open: fmt::OpenBrace,
@@ -421,7 +674,8 @@ before formatting the argument, and uses Display formatting for that argument.
 ///                     flatten_panicvals! {fmtarg, <Qux<$T>>::PV_COUNT;
 ///                         "Down",
 ///                         // the `open:` format override increments `fmtarg.indentation`
-///                         // by `const_panic::fmt::INDENTATION_STEP` spaces.
+///                         // by `fmtarg.indentation_step()` spaces
+///                         // (`const_panic::fmt::INDENTATION_STEP` by default).
 ///                         // The indentation field is used by these constants when the
 ///                         // `fmtarg.is_alternate` flag is enabled.
 ///                         open: fmt::OpenBrace,
@@ -485,6 +739,20 @@ macro_rules! __to_pvf_inner {
         ])
     });
 
+    ($fmtargs:ident $prev:tt [if $($rem:tt)*]) => {
+        $crate::__to_pvf_if!{$fmtargs $prev () [$($rem)*]}
+    };
+
+    ($fmtargs:ident $prev:tt [variant: $($rem:tt)*]) => {
+        $crate::__to_pvf_variant!{__to_pvf_inner ($fmtargs $prev) [$($rem)*]}
+    };
+
+    ($fmtargs:ident $prev:tt [fields: {$($fields:tt)*} $(, $($rem:tt)*)?]) => {
+        $crate::__to_pvf_fields!{
+            __to_pvf_inner ($fmtargs $prev) [$($($rem)*)?] [] [$($fields)*]
+        }
+    };
+
     // Had to add these workarounds
     // to avoid getting stuck being parsed as a type in the `$ty:ty =>` branch.
     ($fmtargs:ident $prev:tt [$tt:tt, $($rem:tt)*]) => {
@@ -503,7 +771,23 @@ macro_rules! __to_pvf_inner {
             [&$tt, $($rem)*]
         }
     };
+    ($fmtargs:ident $prev:tt [($($fmt:tt)*): $($rem:tt)*]) => {
+        $crate::__to_pvf_expr!{
+            $fmtargs
+            $prev
+            (1, single())
+            [($($fmt)*): $($rem)*]
+        }
+    };
 
+    ($fmtargs:ident $prev:tt [array($ty:ty; $len:expr) => $($rem:tt)*]) => {
+        $crate::__to_pvf_expr!{
+            $fmtargs
+            $prev
+            ($crate::__to_pvf_array_len!($ty, $len), array($ty, $len))
+            [$($rem)*]
+        }
+    };
     ($fmtargs:ident $prev:tt [$ty:ty => $($rem:tt)*]) => {
         $crate::__to_pvf_expr!{
             $fmtargs
@@ -525,6 +809,48 @@ macro_rules! __to_pvf_inner {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __to_pvf_expr {
+    (
+        $fmtargs:ident
+        [$($prev:tt)*]
+        ($len:expr, $kind:ident $args:tt)
+        [$kw:tt $($flag:ident = $val:tt)+ : $reff:expr, $($rem:tt)*]
+    ) => {
+        $crate::__to_pvf_inner!{
+            $fmtargs
+
+            [$($prev)* (($len + 1, padded($kind $args ($len) [$($flag = $val)+])), $kw, $reff)]
+
+            [$($rem)*]
+        }
+    };
+    (
+        $fmtargs:ident
+        [$($prev:tt)*]
+        ($len:expr, $kind:ident $args:tt)
+        [$($flag:ident = $val:tt)+ : $reff:literal, $($rem:tt)*]
+    ) => {
+        $crate::__to_pvf_inner!{
+            $fmtargs
+
+            [$($prev)* (($len + 1, padded($kind $args ($len) [$($flag = $val)+])), __literal, $reff)]
+
+            [$($rem)*]
+        }
+    };
+    (
+        $fmtargs:ident
+        [$($prev:tt)*]
+        ($len:expr, $kind:ident $args:tt)
+        [$($flag:ident = $val:tt)+ : $reff:expr, $($rem:tt)*]
+    ) => {
+        $crate::__to_pvf_inner!{
+            $fmtargs
+
+            [$($prev)* (($len + 1, padded($kind $args ($len) [$($flag = $val)+])), _, $reff)]
+
+            [$($rem)*]
+        }
+    };
     ($fmtargs:ident [$($prev:tt)*] $other:tt [$kw:tt: $reff:expr, $($rem:tt)*]) => {
         $crate::__to_pvf_inner!{
             $fmtargs
@@ -538,7 +864,7 @@ macro_rules! __to_pvf_expr {
         $crate::__to_pvf_inner!{
             $fmtargs
 
-            [$($prev)* ($other, display, $reff)]
+            [$($prev)* ($other, __literal, $reff)]
 
             [$($rem)*]
         }
@@ -560,9 +886,313 @@ macro_rules! __to_pvf_expr {
     };
 }
 
+// Parses the `if $condition => ($($args)*)` syntax,
+// falling back to parsing an `if` expression argument if there's no `=>`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_pvf_if {
+    (
+        $fmtargs:ident
+        [$($prev:tt)*]
+        ($($cond:tt)*)
+        [=> ($($group:tt)*), $($rem:tt)*]
+    ) => {
+        $crate::__to_pvf_inner!{
+            $fmtargs
+
+            [
+                $($prev)*
+                (
+                    (
+                        $crate::__to_pvf_group_len!([$($group)* ,]),
+                        cond(($crate::__to_pvf_group_len!([$($group)* ,])) ($($cond)*) [$($group)*])
+                    ),
+                    _,
+                    ()
+                )
+            ]
+
+            [$($rem)*]
+        }
+    };
+    ($fmtargs:ident $prev:tt ($($cond:tt)*) [, $($rem:tt)*]) => {
+        $crate::__to_pvf_expr!{
+            $fmtargs
+            $prev
+            (1, single())
+            [if $($cond)*, $($rem)*]
+        }
+    };
+    ($fmtargs:ident $prev:tt ($($cond:tt)*) [$tt:tt $($rem:tt)*]) => {
+        $crate::__to_pvf_if!{$fmtargs $prev ($($cond)* $tt) [$($rem)*]}
+    };
+}
+
+// Computes the amount of `PanicVal`s that a group of
+// `flatten_panicvals` arguments produces,
+// mirroring how `__to_pvf_inner` parses arguments.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_pvf_group_len {
+    ([$(,)*]) => {
+        0
+    };
+    ([if $($rem:tt)*]) => {
+        $crate::__to_pvf_group_len_if!(() [$($rem)*])
+    };
+    ([variant: $($rem:tt)*]) => {
+        $crate::__to_pvf_variant!{__to_pvf_group_len () [$($rem)*]}
+    };
+    ([fields: {$($fields:tt)*} $(, $($rem:tt)*)?]) => {
+        $crate::__to_pvf_fields!{__to_pvf_group_len () [$($($rem)*)?] [] [$($fields)*]}
+    };
+    ([$tt:tt, $($rem:tt)*]) => {
+        1 + $crate::__to_pvf_group_len!([$($rem)*])
+    };
+    ([&$tt:tt, $($rem:tt)*]) => {
+        1 + $crate::__to_pvf_group_len!([$($rem)*])
+    };
+    ([array($ty:ty; $len:expr) => $($rem:tt)*]) => {
+        $crate::__to_pvf_array_len!($ty, $len) + $crate::__to_pvf_group_len_skip!([$($rem)*])
+    };
+    ([$ty:ty => $($rem:tt)*]) => {
+        <$ty as $crate::__::PanicFmt>::PV_COUNT + $crate::__to_pvf_group_len_skip!([$($rem)*])
+    };
+    ([$($rem:tt)*]) => {
+        1 + $crate::__to_pvf_group_len_skip!([$($rem)*])
+    };
+}
+
+// Expands the `variant: $Name $fields` syntax into the arguments that format it,
+// passing them to the `$callback` macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_pvf_variant {
+    ($callback:ident $prefix:tt [$name:ident () $(, $($rem:tt)*)?]) => {
+        $crate::__to_pvf_variant!{$callback $prefix [$name, $($($rem)*)?]}
+    };
+    ($callback:ident $prefix:tt [$name:ident {} $(, $($rem:tt)*)?]) => {
+        $crate::__to_pvf_variant!{$callback $prefix [$name, $($($rem)*)?]}
+    };
+    ($callback:ident ($($prefix:tt)*) [$name:ident $(, $($rem:tt)*)?]) => {
+        $crate::$callback!{
+            $($prefix)*
+            [display: $crate::__::stringify!($name), $($($rem)*)?]
+        }
+    };
+    (
+        $callback:ident ($($prefix:tt)*)
+        [$name:ident ($first:expr $(, $field:expr)* $(,)?) $(, $($rem:tt)*)?]
+    ) => {
+        $crate::$callback!{
+            $($prefix)*
+            [
+                ($crate::__::StructName($crate::__::stringify!($name))),
+                __struct_open: $crate::__::StructOpen($crate::fmt::OpenParen),
+                    $first,
+                    $($crate::fmt::COMMA_SEP, $field,)*
+                    $crate::fmt::COMMA_TERM,
+                close: $crate::fmt::CloseParen,
+                $($($rem)*)?
+            ]
+        }
+    };
+    // the fields are parsed by `__to_pvf_fields`, which passes them to the `@braced` branch.
+    //
+    // The field values are wrapped in parentheses to format them as expressions,
+    // rather than as `__to_pvf_fields` arguments.
+    (
+        $callback:ident $prefix:tt
+        [
+            $name:ident {
+                $($field_name:ident $(: $field:expr)?),+
+                $(,)?
+            }
+            $(, $($rem:tt)*)?
+        ]
+    ) => {
+        $crate::__to_pvf_fields!{
+            __to_pvf_variant
+            (@braced $callback $prefix $name)
+            [close: $crate::fmt::CloseBrace, $($($rem)*)?]
+            []
+            [$($field_name $(: ($field))?),+]
+        }
+    };
+    (@braced $callback:ident ($($prefix:tt)*) $name:ident [$($args:tt)*]) => {
+        $crate::$callback!{
+            $($prefix)*
+            [
+                ($crate::__::StructName($crate::__::stringify!($name))),
+                __struct_open: $crate::__::StructOpen($crate::fmt::OpenBrace),
+                $($args)*
+            ]
+        }
+    };
+    ($callback:ident $prefix:tt [$($rem:tt)*]) => {
+        $crate::__::compile_error!(concat!(
+            "expected `variant: Name`, `variant: Name(...)`, or `variant: Name{...}`, found: ",
+            stringify!($($rem)*)
+        ))
+    };
+}
+
+// Expands the `fields: { $($field_name: $field_args),* }` syntax into the arguments
+// that format the fields, passing them (followed by `$rem`) to the `$callback` macro.
+//
+// `$fields` accumulates the arguments for the parsed fields,
+// with a `fmt::COMMA_SEP` before every field but the first.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_pvf_fields {
+    // `$name` shorthand for `$name: $name`
+    ($callback:ident $prefix:tt $rem:tt $fields:tt [$name:ident $(, $($unparsed:tt)*)?]) => {
+        $crate::__to_pvf_fields!{
+            $callback $prefix $rem $fields [$name: $name $(, $($unparsed)*)?]
+        }
+    };
+    ($callback:ident $prefix:tt $rem:tt $fields:tt [$name:ident: $($unparsed:tt)*]) => {
+        $crate::__to_pvf_fields!{
+            @field $callback $prefix $rem $fields $name () [$($unparsed)*]
+        }
+    };
+    ($callback:ident ($($prefix:tt)*) [$($rem:tt)*] [] [$(,)?]) => {
+        $crate::$callback!{$($prefix)* [$($rem)*]}
+    };
+    ($callback:ident ($($prefix:tt)*) [$($rem:tt)*] [$($fields:tt)*] [$(,)?]) => {
+        $crate::$callback!{
+            $($prefix)*
+            [$($fields)* $crate::fmt::COMMA_TERM, $($rem)*]
+        }
+    };
+    ($callback:ident $prefix:tt $rem:tt $fields:tt [$($unparsed:tt)*]) => {
+        $crate::__::compile_error!(concat!(
+            "expected `field_name: value` or `field_name`, found: ",
+            stringify!($($unparsed)*)
+        ))
+    };
+
+    (@field $callback:ident $prefix:tt $rem:tt [] $name:ident ($($args:tt)*) [$(, $($unparsed:tt)*)?]) => {
+        $crate::__to_pvf_fields!{
+            $callback $prefix $rem
+            [
+                ($crate::__::FieldName(
+                    $crate::__::concat!($crate::__::stringify!($name), ": ")
+                )),
+                $($args)*,
+            ]
+            [$($($unparsed)*)?]
+        }
+    };
+    (@field $callback:ident $prefix:tt $rem:tt [$($fields:tt)*] $name:ident ($($args:tt)*) [$(, $($unparsed:tt)*)?]) => {
+        $crate::__to_pvf_fields!{
+            $callback $prefix $rem
+            [
+                $($fields)*
+                $crate::fmt::COMMA_SEP,
+                ($crate::__::FieldName(
+                    $crate::__::concat!($crate::__::stringify!($name), ": ")
+                )),
+                $($args)*,
+            ]
+            [$($($unparsed)*)?]
+        }
+    };
+    (@field $callback:ident $prefix:tt $rem:tt $fields:tt $name:ident ($($args:tt)*) [$tt:tt $($unparsed:tt)*]) => {
+        $crate::__to_pvf_fields!{
+            @field $callback $prefix $rem $fields $name ($($args)* $tt) [$($unparsed)*]
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_pvf_group_len_if {
+    (($($cond:tt)*) [=> ($($group:tt)*), $($rem:tt)*]) => {
+        $crate::__to_pvf_group_len!([$($group)* ,]) + $crate::__to_pvf_group_len!([$($rem)*])
+    };
+    (($($cond:tt)*) [, $($rem:tt)*]) => {
+        1 + $crate::__to_pvf_group_len!([$($rem)*])
+    };
+    (($($cond:tt)*) [$tt:tt $($rem:tt)*]) => {
+        $crate::__to_pvf_group_len_if!(($($cond)* $tt) [$($rem)*])
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_pvf_group_len_skip {
+    ([$kw:tt $($flag:ident = $val:tt)+ : $reff:expr, $($rem:tt)*]) => {
+        1 + $crate::__to_pvf_group_len!([$($rem)*])
+    };
+    ([$($flag:ident = $val:tt)+ : $reff:expr, $($rem:tt)*]) => {
+        1 + $crate::__to_pvf_group_len!([$($rem)*])
+    };
+    ([$kw:tt: $reff:expr, $($rem:tt)*]) => {
+        $crate::__to_pvf_group_len!([$($rem)*])
+    };
+    ([$reff:expr, $($rem:tt)*]) => {
+        $crate::__to_pvf_group_len!([$($rem)*])
+    };
+    ([$($rem:tt)*]) => {
+        $crate::__::compile_error!(concat!(
+            "expected expression, found:",
+            stringify!($($rem)*)
+        ))
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __to_pvf_kind {
+    (
+        $fmtargs:ident cond(($len:expr) ($($cond:tt)*) [$($group:tt)*]),
+        $fmt_override:tt,
+        $reff:tt
+    ) => {
+        &if {
+            let cond: $crate::__::bool = $($cond)*;
+            cond
+        } {
+            $crate::flatten_panicvals!($fmtargs, $len; $($group)*)
+        } else {
+            [$crate::PanicVal::EMPTY; $len]
+        }
+    };
+    (
+        $fmtargs:ident padded($kind:ident $args:tt ($len:expr) [$($flags:tt)*]),
+        $fmt_override:tt,
+        $reff:tt
+    ) => {
+        &$crate::__::pad_panicvals::<{ $len + 1 }>(
+            $crate::__to_pvf_kind!($fmtargs $kind $args, $fmt_override, $reff),
+            $crate::__to_pvf_padding!($crate::__::PvfPadding::NEW; $($flags)*),
+        )
+    };
+    ($fmtargs:ident array($ty:ty, $len:expr), $fmt_override:tt, $reff:tt) => {
+        &match &$reff {
+            reff => {
+                let fmtarg = $crate::__set_fmt_from_kw!($fmt_override, $fmtargs);
+                let elem_fmtarg = fmtarg.indent();
+
+                let mut elems =
+                    [[$crate::PanicVal::EMPTY; <$ty as $crate::__::PanicFmt>::PV_COUNT]; $len];
+                let mut i = 0;
+                while i < $len {
+                    elems[i] = <$ty as $crate::__::PanicFmt>::PROOF
+                        .coerce(&reff[i])
+                        .to_panicvals(elem_fmtarg);
+                    i += 1;
+                }
+
+                $crate::__::flatten_array_panicvals::<
+                    { <$ty as $crate::__::PanicFmt>::PV_COUNT },
+                    { $len },
+                    { $crate::__to_pvf_array_len!($ty, $len) },
+                >(&elems, fmtarg)
+            }
+        }
+    };
     ($fmtargs:ident single (), $fmt_override:tt, $reff:tt) => {
         &match &$reff {
             reff => [$crate::__::PanicFmt::PROOF
@@ -580,6 +1210,40 @@ macro_rules! __to_pvf_kind {
     };
 }
 
+// The amount of `PanicVal`s that the `array($ty; $len) => $array` argument produces:
+// the elements, a separator after each element, and the delimiters.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_pvf_array_len {
+    ($ty:ty, $len:expr) => {
+        $len * (<$ty as $crate::__::PanicFmt>::PV_COUNT + 1) + 2
+    };
+}
+
+// Parses the `key = value` padding flags that can precede the `:` of an argument
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __to_pvf_padding {
+    ($padding:expr;) => {
+        $padding
+    };
+    ($padding:expr; width = $width:tt $($rem:tt)*) => {
+        $crate::__to_pvf_padding!($padding.width($width); $($rem)*)
+    };
+    ($padding:expr; align = left $($rem:tt)*) => {
+        $crate::__to_pvf_padding!($padding.left_align(true); $($rem)*)
+    };
+    ($padding:expr; align = right $($rem:tt)*) => {
+        $crate::__to_pvf_padding!($padding.left_align(false); $($rem)*)
+    };
+    ($padding:expr; $($rem:tt)*) => {
+        $crate::__::compile_error!(concat!(
+            "expected `width = <u8>`, `align = left`, or `align = right`, found: ",
+            stringify!($($rem)*)
+        ))
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __to_pvf_used_length {
@@ -185,3 +185,56 @@ macro_rules! unwrap_err {
         }
     };
 }
+
+/// Formats only the error of a `Result`, writing a placeholder for `Ok`.
+///
+/// This takes a [`FmtArg`](crate::FmtArg) expression, followed by a `;`,
+/// then a `Result` expression, and evaluates to a `[PanicVal<'_>; N]` array,
+/// where `N` is the [`PV_COUNT`](crate::PanicFmt::PV_COUNT) of the error type.
+///
+/// This is useful for error messages where the context already implies that
+/// something failed, so writing the `Err(...)` around the error is redundant.
+///
+/// ### `Ok` values
+///
+/// `Ok` values are never formatted (the `Ok` type doesn't need to implement `PanicFmt`),
+/// the `"<no error>"` placeholder is written instead.
+///
+/// # Example
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{just_err, ArrayString, FmtArg, PanicVal};
+///
+/// const fn parse_digit(b: u8) -> Result<u8, &'static str> {
+///     if b.is_ascii_digit() {
+///         Ok(b - b'0')
+///     } else {
+///         Err("expected an ascii digit")
+///     }
+/// }
+///
+/// let message = |res: Result<u8, &str>| {
+///     ArrayString::<64>::concat_panicvals(&[
+///         &[PanicVal::write_str("could not parse digit: ")],
+///         &just_err!(FmtArg::DISPLAY; res),
+///     ])
+///     .unwrap()
+/// };
+///
+/// assert_eq!(
+///     message(parse_digit(b'a')),
+///     "could not parse digit: expected an ascii digit",
+/// );
+/// assert_eq!(message(parse_digit(b'3')), "could not parse digit: <no error>");
+///
+/// ```
+#[macro_export]
+macro_rules! just_err {
+    ($fmtarg:expr; $res:expr $(,)?) => {
+        match &$res {
+            $crate::__::Err(e) => $crate::coerce_fmt!(e).to_panicvals($fmtarg),
+            $crate::__::Ok(_) => $crate::__::no_error_panicvals(),
+        }
+    };
+}
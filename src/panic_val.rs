@@ -1,12 +1,14 @@
 use crate::{
     fmt::{FmtArg, FmtKind, NumberFmt},
     utils::{string_cap, Packed, PreFmtString, RangedBytes, Sign, TailShortString, WasTruncated},
+    wrapped_str::WrappedStr,
 };
 
 #[cfg(feature = "non_basic")]
 use crate::{
     array_string::TinyString,
     fmt::{IsLast, ShortString},
+    NotEnoughSpace,
 };
 
 /// An opaque enum of the values that this crate knows how to format,
@@ -15,16 +17,82 @@ use crate::{
 /// This has constructor functions to make a `PanicVal` from:
 /// - `bool`
 /// - Integers
+/// - Addresses, as `usize`s or raw pointers
 /// - `&str`
 /// - Arrays/Slices of primitives (with the "non_basic" feature, enabled by default)
+/// - Slices of slices of primitives, eg: `&[&[u8]]` (with the "non_basic" feature)
 /// - [`ShortString`](crate::fmt::ShortString)
 /// (with the "non_basic" feature, enabled by default)
+/// - [`StrOrShort`](crate::fmt::StrOrShort), either of the two kinds of strings above
+/// (with the "non_basic" feature)
+///
+/// # Formatting
+///
+/// The [`FmtArg`] passed to a `PanicVal` constructor determines how the value is formatted,
+/// callers of a `to_panicvals` method can't change the formatting of the `PanicVal`s it returns.
+///
+/// `to_panicvals` methods receive the `FmtArg` of their caller,
+/// so to always format a field one way regardless of the caller,
+/// construct its `PanicVal`(s) with a modified `FmtArg`
+/// (eg: `f.set_debug()`, `f.set_display()`, or `f.set_hex()`),
+/// which keeps the indentation and alternate-ness that the caller passed.
+///
+/// Strings can also be pinned to `Debug` or `Display` formatting after they're constructed,
+/// with the [`force_debug`](Self::force_debug) and
+/// [`force_display`](Self::force_display) methods.
+///
+#[cfg_attr(feature = "non_basic", doc = "```rust")]
+#[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+/// use const_panic::{ArrayString, FmtArg, PanicFmt, PanicVal, impl_panicfmt};
+///
+/// const FILE: File = File { name: "foo.txt", hash: Hash(0xFEED) };
+///
+/// assert_eq!(
+///     const_panic::concat_!(FILE),
+///     r#"File { name: "foo.txt", hash: 0xFEED }"#,
+/// );
+/// assert_eq!(
+///     const_panic::concat_!(FmtArg::DISPLAY; FILE),
+///     "File { name: foo.txt, hash: 0xFEED }",
+/// );
+///
+/// struct File {
+///     name: &'static str,
+///     hash: Hash,
+/// }
+///
+/// impl_panicfmt!{
+///     struct File {
+///         name: &'static str,
+///         hash: Hash,
+///     }
+/// }
+///
+/// struct Hash(u64);
+///
+/// impl PanicFmt for Hash {
+///     type This = Self;
+///     type Kind = const_panic::IsCustomType;
+///     const PV_COUNT: usize = 1;
+/// }
+///
+/// impl Hash {
+///     const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; 1] {
+///         // hashes are always formatted as prefixed hexadecimal
+///         [PanicVal::from_u64(self.0, f.set_hex().set_alternate(true))]
+///     }
+/// }
+/// ```
 ///
 #[derive(Copy, Clone)]
 pub struct PanicVal<'a> {
     pub(crate) var: PanicVariant<'a>,
 }
 
+// Every formatted value is stored as an array of `PanicVal`s,
+// so the formatting options are packed to keep them from growing it.
+const _: () = assert!(core::mem::size_of::<PanicVal<'static>>() <= 23);
+
 #[derive(Copy, Clone)]
 pub(crate) enum PanicVariant<'a> {
     Str(StrFmt, Packed<&'a str>),
@@ -32,6 +100,7 @@ pub(crate) enum PanicVariant<'a> {
     ShortString(StrFmt, TinyString<{ string_cap::TINY }>),
     PreFmt(PreFmtString),
     Int(IntVal),
+    WrappedStr(WrappedStr<'a>),
     #[cfg(feature = "non_basic")]
     Slice(crate::slice_stuff::Slice<'a>),
 }
@@ -39,6 +108,7 @@ pub(crate) enum PanicVariant<'a> {
 pub(crate) enum PanicClass<'a> {
     PreFmt(RangedBytes<&'a [u8]>),
     Int(IntVal),
+    Wrapped(WrappedStr<'a>),
     #[cfg(feature = "non_basic")]
     Slice(crate::slice_stuff::Slice<'a>),
 }
@@ -47,21 +117,55 @@ pub(crate) enum PanicClass<'a> {
 pub(crate) struct StrFmt {
     pub(crate) leftpad: u8,
     pub(crate) rightpad: u8,
+    // the byte that `leftpad` and `rightpad` are written with
+    pub(crate) pad_byte: u8,
     pub(crate) fmt_kind: FmtKind,
+    // the `StrFmt::ESCAPE_CONTROL` and `StrFmt::SINGLE_QUOTES` bitflags
+    pub(crate) flags: u8,
 }
 
 impl StrFmt {
-    const DISPLAY: Self = Self {
+    pub(crate) const DISPLAY: Self = Self {
         leftpad: 0,
         rightpad: 0,
+        pad_byte: b' ',
         fmt_kind: FmtKind::Display,
+        flags: 0,
     };
 
+    // whether control characters are escaped with `Display` formatting
+    pub(crate) const ESCAPE_CONTROL: u8 = 1;
+    // whether `Debug`-formatted strings are delimited with `'` instead of `"`
+    pub(crate) const SINGLE_QUOTES: u8 = 2;
+
     pub const fn new(fmtarg: FmtArg) -> Self {
+        let mut flags = 0;
+        if fmtarg.escape_control_in_display() {
+            flags |= Self::ESCAPE_CONTROL;
+        }
+        if fmtarg.single_quotes() {
+            flags |= Self::SINGLE_QUOTES;
+        }
+
         Self {
             leftpad: 0,
             rightpad: 0,
+            pad_byte: fmtarg.indentation_byte(),
             fmt_kind: fmtarg.fmt_kind,
+            flags,
+        }
+    }
+
+    pub(crate) const fn escape_control(self) -> bool {
+        (self.flags & Self::ESCAPE_CONTROL) != 0
+    }
+
+    // the quote character that `Debug`-formatted strings are delimited with
+    pub(crate) const fn quote(self) -> u8 {
+        if (self.flags & Self::SINGLE_QUOTES) != 0 {
+            b'\''
+        } else {
+            b'"'
         }
     }
 }
@@ -118,16 +222,68 @@ macro_rules! mutate_strfmt {
 impl<'a> PanicVal<'a> {
     /// Sets the amount of spaces printed before this to `fmtarg.indentation`.
     ///
-    /// Note that only strings can be padded.
+    /// Tabs are printed instead of spaces if `fmtarg.tab_indentation()` is enabled.
+    ///
+    /// Note that only strings can be padded,
+    /// and that [wrapped](crate::FmtArg::set_wrap_width) strings aren't.
     pub const fn with_leftpad(self, fmtarg: FmtArg) -> Self {
-        mutate_strfmt! {self, |strfmt| strfmt.leftpad = fmtarg.indentation}
+        mutate_strfmt! {self, |strfmt| {
+            strfmt.leftpad = fmtarg.indentation;
+            strfmt.pad_byte = fmtarg.indentation_byte();
+        }}
     }
 
     /// Sets the amount of spaces printed after this to `fmtarg.indentation`.
     ///
-    /// Note that only strings can be padded.
+    /// Tabs are printed instead of spaces if `fmtarg.tab_indentation()` is enabled.
+    ///
+    /// Note that only strings can be padded,
+    /// and that [wrapped](crate::FmtArg::set_wrap_width) strings aren't.
     pub const fn with_rightpad(self, fmtarg: FmtArg) -> Self {
-        mutate_strfmt! {self, |strfmt| strfmt.rightpad = fmtarg.indentation}
+        mutate_strfmt! {self, |strfmt| {
+            strfmt.rightpad = fmtarg.indentation;
+            strfmt.pad_byte = fmtarg.indentation_byte();
+        }}
+    }
+
+    /// Makes this be `Debug`-formatted, regardless of the `FmtArg` it was constructed with.
+    ///
+    /// Note that only strings are affected,
+    /// other values don't change how they're formatted.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{FmtArg, PanicVal};
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(PanicVal::from_str("foo", FmtArg::DISPLAY).force_debug()),
+    ///     r#""foo""#,
+    /// );
+    /// ```
+    pub const fn force_debug(self) -> Self {
+        mutate_strfmt! {self, |strfmt| strfmt.fmt_kind = FmtKind::Debug}
+    }
+
+    /// Makes this be `Display`-formatted, regardless of the `FmtArg` it was constructed with.
+    ///
+    /// Note that only strings are affected,
+    /// other values don't change how they're formatted.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{FmtArg, PanicVal};
+    ///
+    /// assert_eq!(
+    ///     const_panic::concat_!(PanicVal::from_str("foo", FmtArg::DEBUG).force_display()),
+    ///     "foo",
+    /// );
+    /// ```
+    pub const fn force_display(self) -> Self {
+        mutate_strfmt! {self, |strfmt| strfmt.fmt_kind = FmtKind::Display}
     }
 
     /// Constructs a PanicVal which outputs the contents of `string` verbatim.
@@ -139,6 +295,70 @@ impl<'a> PanicVal<'a> {
         }
     }
 
+    /// Constructs a PanicVal which outputs `bytes` verbatim.
+    ///
+    /// This is like [`write_str`](Self::write_str),
+    /// for bytes that are already known to be UTF-8,
+    /// eg: the output of another const formatter.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be valid UTF-8.
+    ///
+    /// The panic message is converted to a `&str` without checking that it's valid UTF-8,
+    /// so passing invalid UTF-8 is undefined behavior.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, PanicVal};
+    ///
+    /// const BYTES: &[u8] = b"hello world";
+    ///
+    /// // SAFETY: `BYTES` is ASCII, which is valid UTF-8
+    /// const PV: PanicVal<'_> = unsafe { PanicVal::write_bytes_unchecked(BYTES) };
+    ///
+    /// assert_eq!(ArrayString::<99>::from_panicvals(&[PV]).unwrap(), "hello world");
+    /// ```
+    pub const unsafe fn write_bytes_unchecked(bytes: &'a [u8]) -> Self {
+        Self::write_str(core::str::from_utf8_unchecked(bytes))
+    }
+
+    /// Constructs a `PanicVal` which outputs `fmtarg.indentation` spaces.
+    ///
+    /// In alternate mode, the indentation of a value is only written after newlines,
+    /// this can be put before the value to indent its first line as well,
+    /// for embedding it in already-indented text.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "non_basic", doc = "```rust")]
+    #[cfg_attr(not(feature = "non_basic"), doc = "```ignore")]
+    /// use const_panic::{ArrayString, FmtArg, PanicVal};
+    ///
+    /// const FMT: FmtArg = FmtArg::ALT_DEBUG.indent();
+    ///
+    /// let string = ArrayString::<99>::concat_panicvals(&[
+    ///     &[PanicVal::write_str("values:\n"), PanicVal::write_indentation(FMT)],
+    ///     &[PanicVal::from_slice_u8(&[3, 5], FMT)],
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     string,
+    ///     concat!(
+    ///         "values:\n",
+    ///         "    [\n",
+    ///         "        3,\n",
+    ///         "        5,\n",
+    ///         "    ]",
+    ///     )
+    /// );
+    /// ```
+    pub const fn write_indentation(fmtarg: FmtArg) -> Self {
+        PanicVal::write_str("").with_leftpad(fmtarg)
+    }
+
     /// Constructs a PanicVal from a [`ShortString`], which outputs the string verbatim.
     #[cfg(feature = "non_basic")]
     pub const fn write_short_str(string: ShortString) -> Self {
@@ -147,6 +367,41 @@ impl<'a> PanicVal<'a> {
         }
     }
 
+    /// Constructs a PanicVal from a `&str` that's stored inline as a [`ShortString`],
+    /// which outputs the string verbatim.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NotEnoughSpace` error if `string` is longer than
+    /// [`SHORT_STRING_CAP`](crate::fmt::SHORT_STRING_CAP) bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_panic::{fmt::ShortString, ArrayString, NotEnoughSpace, PanicVal};
+    ///
+    /// const fn separator(sep: &str) -> PanicVal<'static> {
+    ///     match PanicVal::try_write_short_str(sep) {
+    ///         Ok(pv) => pv,
+    ///         Err(NotEnoughSpace) => PanicVal::write_short_str(ShortString::new(", ")),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(ArrayString::<20>::from_panicvals(&[separator(" | ")]).unwrap(), " | ");
+    ///
+    /// assert_eq!(
+    ///     ArrayString::<20>::from_panicvals(&[separator("a very long separator")]).unwrap(),
+    ///     ", ",
+    /// );
+    /// ```
+    #[cfg(feature = "non_basic")]
+    pub const fn try_write_short_str(string: &str) -> Result<Self, NotEnoughSpace> {
+        match ShortString::try_new(string) {
+            Ok(x) => Ok(Self::write_short_str(x)),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Constructs a `PanicVal` usable as a separator between fields or elements.
     ///
     /// This is sensitive to the [`fmtarg.is_alternate`] flag,
@@ -174,13 +429,31 @@ impl<'a> PanicVal<'a> {
         let strfmt = StrFmt {
             leftpad: 0,
             rightpad,
+            pad_byte: fmtarg.indentation_byte(),
             fmt_kind: FmtKind::Display,
+            flags: 0,
         };
         Self {
             var: PanicVariant::ShortString(strfmt, concat.to_compact()),
         }
     }
 
+    // Whether this writes nothing, and can be skipped when writing to a buffer.
+    pub(crate) const fn is_elided(&self) -> bool {
+        match self.var {
+            PanicVariant::Str(
+                StrFmt {
+                    leftpad: 0,
+                    rightpad: 0,
+                    fmt_kind: FmtKind::Display,
+                    ..
+                },
+                Packed(str),
+            ) => str.is_empty(),
+            _ => false,
+        }
+    }
+
     #[inline(always)]
     pub(crate) const fn __new(var: PanicVariant<'a>) -> Self {
         Self { var }
@@ -201,6 +474,7 @@ impl<'a> PanicVal<'a> {
             PanicVariant::ShortString(strfmt, str) => (*strfmt, PanicClass::PreFmt(str.ranged())),
             PanicVariant::PreFmt(str) => (StrFmt::DISPLAY, PanicClass::PreFmt(str.ranged())),
             PanicVariant::Int(int) => (StrFmt::DISPLAY, PanicClass::Int(*int)),
+            PanicVariant::WrappedStr(wrapped) => (StrFmt::DISPLAY, PanicClass::Wrapped(*wrapped)),
             #[cfg(feature = "non_basic")]
             PanicVariant::Slice(slice) => (
                 StrFmt::new(slice.fmtarg.unpack()),
@@ -220,7 +494,9 @@ impl<'a> PanicVal<'a> {
                 StrFmt {
                     leftpad: strfmt.leftpad - truncate_to as u8,
                     rightpad: 0,
+                    pad_byte: strfmt.pad_byte,
                     fmt_kind: FmtKind::Display,
+                    flags: 0,
                 },
                 PanicClass::PreFmt(RangedBytes::EMPTY),
                 WasTruncated::Yes(0),
@@ -241,10 +517,16 @@ impl<'a> PanicVal<'a> {
                         WasTruncated::Yes(0)
                     }
                 } else {
-                    if let FmtKind::Display = strfmt.fmt_kind {
-                        crate::utils::truncated_str_len(str, truncate_to)
-                    } else {
-                        crate::utils::truncated_debug_str_len(str, truncate_to)
+                    match (strfmt.fmt_kind, strfmt.escape_control()) {
+                        (FmtKind::Display, false) => {
+                            crate::utils::truncated_str_len(str, truncate_to)
+                        }
+                        (FmtKind::Display, true) => {
+                            crate::utils::truncated_control_escaped_str_len(str, truncate_to)
+                        }
+                        (FmtKind::Debug, _) => {
+                            crate::utils::truncated_debug_str_len(str, truncate_to, strfmt.quote())
+                        }
                     }
                 };
                 orig_len = str.len();
@@ -258,6 +540,10 @@ impl<'a> PanicVal<'a> {
                 };
                 orig_len = int.len();
             }
+            PanicClass::Wrapped(_) => {
+                was_trunc = WasTruncated::No;
+                orig_len = 0;
+            }
             #[cfg(feature = "non_basic")]
             PanicClass::Slice(_) => {
                 was_trunc = WasTruncated::No;
@@ -274,9 +560,14 @@ impl<'a> PanicVal<'a> {
 
 #[derive(Copy, Clone)]
 pub(crate) struct IntVal {
-    sign: Sign,
-    number_fmt: NumberFmt,
-    is_alternate: bool,
+    // the sign, `NumberFmt`, and boolean formatting options, as `IntVal::*` bits,
+    // stored together to keep `PanicVal` small.
+    flags: u8,
+    // the amount of digits between `_` separators, `0` if they're not grouped.
+    group_len: u8,
+    // the radix passed to `PanicVal::from_u128_radix`,
+    // `0` when `number_fmt` determines the radix.
+    radix: u8,
     // the size of the integer in bits
     bits: u8,
     // the length of the integer in bytes, once written.
@@ -286,6 +577,12 @@ pub(crate) struct IntVal {
 }
 
 impl IntVal {
+    const NEGATIVE: u8 = 1 << 0;
+    const IS_ALTERNATE: u8 = 1 << 1;
+    const PLUS_SIGN: u8 = 1 << 2;
+    const SIGNED_HEX_BIN: u8 = 1 << 3;
+    const NUMBER_FMT_OFFSET: u8 = 4;
+
     pub(crate) const fn from_u128(n: u128, bits: u8, f: FmtArg) -> PanicVal<'static> {
         Self::new(Sign::Positive, n, bits, f)
     }
@@ -298,15 +595,38 @@ impl IntVal {
         Self::new(is_neg, n.unsigned_abs(), bits, f)
     }
 
+    pub(crate) const fn from_u128_radix(n: u128, radix: u8, f: FmtArg) -> PanicVal<'static> {
+        use crate::int_formatting::compute_radix_len;
+
+        let len = compute_radix_len(n, radix, f.plus_sign());
+
+        Self::with_len(Sign::Positive, n, radix, 128, len, f)
+    }
+
     const fn new(sign: Sign, n: u128, bits: u8, fmtarg: FmtArg) -> PanicVal<'static> {
         use crate::int_formatting::compute_len;
 
         let len = compute_len(sign, n, bits, fmtarg);
 
+        Self::with_len(sign, n, 0, bits, len, fmtarg)
+    }
+
+    const fn with_len(
+        sign: Sign,
+        n: u128,
+        radix: u8,
+        bits: u8,
+        len: u8,
+        fmtarg: FmtArg,
+    ) -> PanicVal<'static> {
         let this = IntVal {
-            sign,
-            number_fmt: fmtarg.number_fmt,
-            is_alternate: fmtarg.is_alternate,
+            flags: (sign as u8 * Self::NEGATIVE)
+                | (fmtarg.is_alternate as u8 * Self::IS_ALTERNATE)
+                | (fmtarg.plus_sign() as u8 * Self::PLUS_SIGN)
+                | (fmtarg.signed_hex_bin() as u8 * Self::SIGNED_HEX_BIN)
+                | ((fmtarg.number_fmt as u8) << Self::NUMBER_FMT_OFFSET),
+            group_len: crate::int_formatting::group_len(fmtarg.number_fmt, fmtarg),
+            radix,
             bits,
             len,
             value: Packed(n),
@@ -321,26 +641,49 @@ impl IntVal {
     }
 
     pub(crate) const fn fmt<const N: usize>(self) -> TailShortString<N> {
-        use crate::int_formatting::{fmt_binary, fmt_decimal, fmt_hexadecimal};
+        use crate::int_formatting::{fmt_binary, fmt_decimal, fmt_hexadecimal, fmt_radix};
 
         let IntVal {
-            sign,
-            number_fmt,
-            is_alternate,
-            len: _,
+            flags,
+            group_len,
+            radix,
+            len,
             bits,
             value: Packed(n),
         } = self;
 
+        let sign = if (flags & Self::NEGATIVE) != 0 {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        let is_alternate = (flags & Self::IS_ALTERNATE) != 0;
+        let plus_sign = (flags & Self::PLUS_SIGN) != 0;
+        let signed_hex_bin = (flags & Self::SIGNED_HEX_BIN) != 0;
+        let number_fmt = match (flags >> Self::NUMBER_FMT_OFFSET) & 0b11 {
+            1 => NumberFmt::Binary,
+            2 => NumberFmt::Hexadecimal,
+            _ => NumberFmt::Decimal,
+        };
+
+        debug_assert!(
+            len as usize <= N,
+            "the formatted integer doesn't fit in the buffer"
+        );
+
+        if radix != 0 {
+            return fmt_radix::<N>(n, radix, plus_sign);
+        }
+
         match number_fmt {
-            NumberFmt::Decimal => fmt_decimal::<N>(sign, n),
+            NumberFmt::Decimal => fmt_decimal::<N>(sign, n, plus_sign, group_len),
             NumberFmt::Binary => {
-                let masked = apply_mask(sign, n, bits);
-                fmt_binary::<N>(masked, is_alternate)
+                let (sign, n) = to_bit_pattern(sign, n, bits, signed_hex_bin);
+                fmt_binary::<N>(sign, n, is_alternate, plus_sign, group_len)
             }
             NumberFmt::Hexadecimal => {
-                let masked = apply_mask(sign, n, bits);
-                fmt_hexadecimal::<N>(masked, is_alternate)
+                let (sign, n) = to_bit_pattern(sign, n, bits, signed_hex_bin);
+                fmt_hexadecimal::<N>(sign, n, is_alternate, plus_sign, group_len)
             }
         }
     }
@@ -350,6 +693,16 @@ impl IntVal {
     }
 }
 
+// Negative integers are formatted as their two's complement in hexadecimal and binary,
+// unless `FmtArg::signed_hex_bin` is enabled.
+const fn to_bit_pattern(sign: Sign, n: u128, bits: u8, signed_hex_bin: bool) -> (Sign, u128) {
+    if signed_hex_bin {
+        (sign, n)
+    } else {
+        (Sign::Positive, apply_mask(sign, n, bits))
+    }
+}
+
 const fn apply_mask(sign: Sign, n: u128, bits: u8) -> u128 {
     if let Sign::Negative = sign {
         let mask: u128 = if bits == 128 { !0 } else { (1 << bits) - 1 };
@@ -0,0 +1,69 @@
+/// Panics with a message built from a format string, like `std::panic!`.
+///
+/// This requires the `"derive"` feature, disabled by default.
+///
+/// This is an alternative syntax for [`concat_panic`](macro@crate::concat_panic),
+/// which the format string is converted into at compile-time.
+///
+/// # Syntax
+///
+/// This macro takes a format string literal,
+/// followed by positional arguments, then by `name = value` named arguments.
+///
+/// The format string supports these placeholders:
+/// - `{}`: `Display` formats the next positional argument.
+/// - `{0}`: formats the positional argument at that index.
+/// - `{name}`: formats the `name` named argument,
+///   or the `name` variable in scope if there's no argument with that name.
+///
+/// Each of the above can be followed by one of these formatting specifiers:
+/// - `:#`: alternate-`Display`
+/// - `:?`: `Debug`
+/// - `:#?`: alternate-`Debug`
+/// - `:b`: binary
+/// - `:#b`: alternate-binary
+/// - `:X`: hexadecimal
+/// - `:#X`: alternate-hexadecimal
+///
+/// Lowercase hexadecimal (`:x` and `:#x`) is not supported,
+/// because this crate only writes hexadecimal numbers with uppercase digits.
+///
+/// `{{` and `}}` write a literal `{` and `}`.
+///
+/// Every argument must be used in the format string, otherwise this causes a compile-time error.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use const_panic::fmt_panic;
+///
+/// const _: () = check_range(3, 10, 20);
+///
+/// const fn check_range(value: u32, min: u32, max: u32) {
+///     if value < min || value > max {
+///         fmt_panic!(
+///             "expected {value} to be in the {{{}..={}}} range, mask: {mask:#b}",
+///             min,
+///             max,
+///             mask = value ^ min,
+///         )
+///     }
+/// }
+/// ```
+///
+/// The above code fails to compile with this error:
+/// ```text
+/// error[E0080]: evaluation of constant value failed
+///  --> src/lib.rs:3:15
+///   |
+/// 3 | const _: () = check_range(3, 10, 20);
+///   |               ^^^^^^^^^^^^^^^^^^^^^^ the evaluated program panicked at 'expected 3 to be in the {10..=20} range, mask: 0b1001', src/lib.rs:7:9
+/// ```
+///
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "derive")))]
+#[macro_export]
+macro_rules! fmt_panic {
+    ($($args:tt)*) => {
+        $crate::__::__fmt_panic!{($crate) $($args)*}
+    };
+}
@@ -13,6 +13,18 @@ from scratch, but requires repeating the type definition.
 
 [Jump straight to examples](#examples)
 
+# Output
+
+With `Debug` and alternate `Debug` formatting,
+the output is the same as the `{:?}` and `{:#?}` output of `#[derive(Debug)]`,
+as long as the fields are also formatted the same as their `Debug` impls.
+
+The exception are non-ASCII characters in `char`s and `&str`s,
+which are never escaped (`core::fmt` escapes the non-printable ones).
+
+With `Display` formatting, the type and field names can be omitted with
+[`FmtArg::set_struct_style`](crate::FmtArg::set_struct_style).
+
 # Limitations
 
 
@@ -38,6 +50,15 @@ Const parameters must not affect the value of the `PanicFmt::PV_COUNT`  of this
 since the const parameter [must be replaceable with a concrete value](#concrete-pv-count).
 <br>Note that arrays have a `PV_COUNT` of `1` for all lengths.
 
+### Tuples
+
+Tuples don't implement [`PanicFmt`](trait@crate::PanicFmt),
+because formatting their generic elements would require trait bounds in const fns.
+Tuple structs can be used instead,
+with `PhantomData` fields being formatted as `PhantomData`
+(the type parameters used only in `PhantomData` can be
+[ignored](#pfmt-ignored-attr)).
+
 <a id = "concrete-pv-count"></a>
 ### Concrete `Self` type for `PanicFmt::PV_COUNT`
 
@@ -111,6 +132,36 @@ this also requires a [`#[pfmt(ignored(T))]`](#pfmt-ignored-attr) attribute
 
 ([more conplete example](#type-parameter-example))
 
+<a id = "pfmt-rename-attr"></a>
+### `#[pfmt(rename = "Foo")]`
+
+Writes `Foo` as the name of the struct, instead of its Rust identifier.
+
+This attribute can also be used above enum variants, to rename them.
+
+[example](#rename-example)
+
+### Field attributes
+
+Attributes used above fields.
+
+<a id = "pfmt-fmtarg-attr"></a>
+### `#[pfmt(fmtarg = EXPR)]`
+
+Formats the field with the options of the [`FmtArg`](crate::FmtArg) that `EXPR` evaluates to,
+combined with the `FmtArg` passed to `to_panicvals`.
+
+The indentation comes from the `FmtArg` passed to `to_panicvals`,
+alternate formatting is used if either `FmtArg` enables it,
+and all the other options are taken from `EXPR`,
+so options enabled by the `FmtArg` passed to `to_panicvals` (eg: `plus_sign`)
+don't apply to the field unless `EXPR` also enables them.
+
+This allows picking the formatting of each field from an array of `FmtArg`s,
+eg: `#[pfmt(fmtarg = FIELD_FMTS[0])]`.
+
+[example](#fmtarg-example)
+
 # Examples
 
 ### Basic struct
@@ -258,6 +309,62 @@ struct Foo<const LEN: usize>([u8; LEN]);
 ```
 
 
+<a id = "rename-example"></a>
+### Renaming
+
+This example demonstrates how the names of structs and enum variants
+can be changed with the `#[pfmt(rename = "...")]` attribute.
+
+```rust
+use const_panic::{ArrayString, FmtArg, PanicFmt};
+
+assert_eq!(
+    const_panic::concat_!(InternalPoint { x: 3, ord: Ordering::Less }),
+    "Point { x: 3, ord: less }",
+);
+
+#[derive(PanicFmt)]
+#[pfmt(rename = "Point")]
+struct InternalPoint {
+    x: u32,
+    ord: Ordering,
+}
+
+#[derive(PanicFmt)]
+enum Ordering {
+    #[pfmt(rename = "less")]
+    Less,
+    #[pfmt(rename = "greater")]
+    Greater,
+}
+```
+
+<a id = "fmtarg-example"></a>
+### Per-field formatting
+
+This example demonstrates how fields can be formatted differently from each other,
+with the `#[pfmt(fmtarg = ...)]` attribute.
+
+```rust
+use const_panic::{ArrayString, FmtArg, PanicFmt};
+
+assert_eq!(
+    const_panic::concat_!(Reg { addr: 64, value: 255 }),
+    "Reg { addr: 0x40, value: 255 }",
+);
+
+// the formatting for each field, indexed by field position
+const FIELD_FMTS: [FmtArg; 2] = [FmtArg::ALT_HEX, FmtArg::DEBUG];
+
+#[derive(PanicFmt)]
+struct Reg {
+    #[pfmt(fmtarg = FIELD_FMTS[0])]
+    addr: u32,
+    #[pfmt(fmtarg = FIELD_FMTS[1])]
+    value: u8,
+}
+```
+
 <a id = "crate-example"></a>
 ### Crate renaming
 
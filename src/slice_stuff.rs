@@ -1,12 +1,187 @@
 use crate::{
-    fmt::{FmtArg, PackedFmtArg, PanicFmt},
+    fmt::{FmtArg, FmtKind, IsCustomType, PackedFmtArg, PanicFmt},
     panic_val::{PanicVal, PanicVariant},
     utils::Packed,
     StdWrapper,
 };
 
+/// Formats a slice with a custom separator between its elements.
+///
+/// The separator is written as-is between every pair of elements,
+/// and the elements are surrounded with `[` `]` unless
+/// [`set_bare`](Self::set_bare)`(true)` is called.
+///
+/// This is always formatted on a single line,
+/// the [alternate flag](crate::FmtArg#structfield.is_alternate) only affects
+/// how the elements are formatted (eg: adding the `0x` prefix to hexadecimal integers).
+///
+/// This can format slices of the same element types that
+/// [`PanicVal`] has slice constructors for (eg: [`PanicVal::from_slice_u8`]).
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{fmt::SliceWith, ArrayString, FmtArg};
+///
+/// const ROW: &[u16] = &[3, 5, 8, 13];
+///
+/// assert_eq!(
+///     const_panic::concat_!(SliceWith::new(ROW, "; ")),
+///     "[3; 5; 8; 13]",
+/// );
+///
+/// assert_eq!(
+///     const_panic::concat_!(FmtArg::ALT_HEX; SliceWith::new(ROW, ",").set_bare(true)),
+///     "0x3,0x5,0x8,0xD",
+/// );
+///
+/// const NAMES: &[&str] = &["foo", "bar"];
+///
+/// assert_eq!(
+///     const_panic::concat_!(FmtArg::DISPLAY; SliceWith::new(NAMES, " | ")),
+///     "[foo | bar]",
+/// );
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub struct SliceWith<'s, T> {
+    slice: &'s [T],
+    separator: &'s str,
+    bare: bool,
+}
+
+impl<T> Copy for SliceWith<'_, T> {}
+
+impl<T> Clone for SliceWith<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'s, T> SliceWith<'s, T> {
+    /// Constructs a `SliceWith`, which writes `separator` between the elements of `slice`.
+    pub const fn new(slice: &'s [T], separator: &'s str) -> Self {
+        Self {
+            slice,
+            separator,
+            bare: false,
+        }
+    }
+
+    /// Sets whether the `[` `]` delimiters around the elements are omitted.
+    pub const fn set_bare(mut self, bare: bool) -> Self {
+        self.bare = bare;
+        self
+    }
+}
+
+impl<T> PanicFmt for SliceWith<'_, T> {
+    type This = Self;
+    type Kind = IsCustomType;
+    const PV_COUNT: usize = 1;
+}
+
+/// Formats a `&[u8]` or `&[i8]` as a byte string.
+///
+/// With `Debug` formatting, this is written like a byte string literal, eg: `b"hello\xff"`,
+/// escaping the non-printable and non-ASCII bytes, as well as `"` and `\`.
+///
+/// With `Display` formatting, ASCII bytes are written as the `char` they represent,
+/// while non-ASCII bytes are written escaped, eg: `hello\xff`.
+///
+/// `i8`s are reinterpreted as bytes, so `-1i8` is written as `\xff`.
+/// This allows formatting `&[c_char]` FFI buffers as byte strings,
+/// since `c_char` is either `i8` or `u8`, depending on the target.
+///
+/// Slices of `u8` and `i8` are formatted as lists of integers by default,
+/// this type must be used to format them as byte strings.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{fmt::ByteStr, ArrayString, FmtArg};
+///
+/// use std::os::raw::c_char;
+///
+/// const NAME: &[c_char] = &[b'f' as c_char, b'o' as c_char, 0xFF_u8 as c_char];
+///
+/// assert_eq!(
+///     const_panic::concat_!("name: ", ByteStr::new(NAME)),
+///     r#"name: b"fo\xff""#,
+/// );
+///
+/// assert_eq!(
+///     const_panic::concat_!(FmtArg::DISPLAY; "name: ", ByteStr::new(NAME)),
+///     r#"name: fo\xff"#,
+/// );
+///
+/// const SIGNED: &[i8] = &[72, 105, -128, 10];
+///
+/// assert_eq!(const_panic::concat_!(ByteStr::new(SIGNED)), r#"b"Hi\x80\n""#);
+///
+/// assert_eq!(const_panic::concat_!(SIGNED), "[72, 105, -128, 10]");
+/// ```
+#[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+pub struct ByteStr<'s, T> {
+    bytes: &'s [T],
+}
+
+impl<T> Copy for ByteStr<'_, T> {}
+
+impl<T> Clone for ByteStr<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'s, T> ByteStr<'s, T> {
+    /// Constructs a `ByteStr`, which formats `bytes` as a byte string.
+    pub const fn new(bytes: &'s [T]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl<T> PanicFmt for ByteStr<'_, T> {
+    type This = Self;
+    type Kind = IsCustomType;
+    const PV_COUNT: usize = 3;
+}
+
+macro_rules! impl_byte_str {
+    ($($variant:ident, $ty:ty;)*) => {
+        $(
+            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+            impl<'s> ByteStr<'s, $ty> {
+                /// Converts this `ByteStr` to a `PanicVal` array.
+                pub const fn to_panicvals(self, f: FmtArg) -> [PanicVal<'s>; 3] {
+                    let slice = PanicVal::__new(PanicVariant::Slice(Slice {
+                        fmtarg: f.pack(),
+                        vari: SliceV::$variant(Packed(self.bytes)),
+                    }));
+
+                    match f.fmt_kind {
+                        FmtKind::Debug => {
+                            [PanicVal::write_str("b\""), slice, PanicVal::write_str("\"")]
+                        }
+                        FmtKind::Display => [PanicVal::EMPTY, slice, PanicVal::EMPTY],
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_str! {
+    ByteStrU8, u8;
+    ByteStrI8, i8;
+}
+
 macro_rules! impl_panicfmt_array {
-    ($(($variant:ident, $panicval_ctor:ident, $ty:ty)),* $(,)*) => {
+    ($((
+        $variant:ident, $panicval_ctor:ident,
+        $nested_variant:ident, $nested_panicval_ctor:ident,
+        $with_variant:ident,
+        $ty:ty
+    )),* $(,)*) => {
 
         #[derive(Copy, Clone)]
         #[repr(packed)]
@@ -20,7 +195,11 @@ macro_rules! impl_panicfmt_array {
         pub(crate) enum SliceV<'s> {
             $(
                 $variant(Packed<&'s [$ty]>),
+                $nested_variant(Packed<&'s [&'s [$ty]]>),
+                $with_variant(Packed<&'s SliceWith<'s, $ty>>),
             )*
+            ByteStrU8(Packed<&'s [u8]>),
+            ByteStrI8(Packed<&'s [i8]>),
         }
 
 
@@ -30,7 +209,11 @@ macro_rules! impl_panicfmt_array {
                 match self.vari {
                     $(
                         SliceV::$variant(Packed(arr)) => arr.len(),
+                        SliceV::$nested_variant(Packed(arr)) => arr.len(),
+                        SliceV::$with_variant(Packed(with)) => with.slice.len(),
                     )*
+                    SliceV::ByteStrU8(Packed(arr)) => arr.len(),
+                    SliceV::ByteStrI8(Packed(arr)) => arr.len(),
                 }
             }
         }
@@ -43,7 +226,30 @@ macro_rules! impl_panicfmt_array {
                             let elem: &'s <$ty as PanicFmt>::This = &arr[index];
                             StdWrapper(elem).to_panicval(fmtarg)
                         },
+                        SliceV::$nested_variant(Packed(arr)) => {
+                            PanicVal::$panicval_ctor(arr[index], fmtarg)
+                        },
+                        SliceV::$with_variant(Packed(with)) => {
+                            let elem: &'s <$ty as PanicFmt>::This = &with.slice[index];
+                            StdWrapper(elem).to_panicval(fmtarg)
+                        },
+                    )*
+                    SliceV::ByteStrU8(Packed(arr)) => PanicVal::from_byte_str_byte(arr[index], fmtarg),
+                    SliceV::ByteStrI8(Packed(arr)) => {
+                        PanicVal::from_byte_str_byte(arr[index] as u8, fmtarg)
+                    }
+                }
+            }
+
+            // the custom separator, and whether the delimiters are omitted
+            const fn custom_separator(self) -> Option<(&'s str, bool)> {
+                match self {
+                    $(
+                        SliceV::$with_variant(Packed(with)) => Some((with.separator, with.bare)),
                     )*
+                    // the `b"` `"` delimiters of byte strings are written by `ByteStr`
+                    SliceV::ByteStrU8(_) | SliceV::ByteStrI8(_) => Some(("", true)),
+                    _ => None,
                 }
             }
         }
@@ -64,10 +270,47 @@ macro_rules! impl_panicfmt_array {
                         })
                     )
                 }
+
+                /// Constructs a `PanicVal` from a slice of slices.
+                ///
+                /// Each inner slice is indented one level further than the outer slice
+                /// when using alternate formatting.
+                pub const fn $nested_panicval_ctor(
+                    this: &'s [&'s [$ty]],
+                    mut fmtarg: FmtArg,
+                ) -> PanicVal<'s> {
+                    fmtarg = fmtarg.indent();
+                    if this.is_empty() {
+                        fmtarg = fmtarg.set_alternate(false);
+                    }
+                    PanicVal::__new(
+                        PanicVariant::Slice(Slice{
+                            fmtarg: fmtarg.pack(),
+                            vari: SliceV::$nested_variant(Packed(this)),
+                        })
+                    )
+                }
             )*
         }
 
         $(
+            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+            impl<'s> SliceWith<'s, $ty> {
+                /// Converts this `SliceWith` to a single-element `PanicVal` array.
+                pub const fn to_panicvals<'b>(&'b self, f: FmtArg) -> [PanicVal<'b>; 1] {
+                    [self.to_panicval(f)]
+                }
+                /// Converts this `SliceWith` to a `PanicVal`.
+                pub const fn to_panicval<'b>(&'b self, f: FmtArg) -> PanicVal<'b> {
+                    PanicVal::__new(
+                        PanicVariant::Slice(Slice{
+                            fmtarg: f.indent().pack(),
+                            vari: SliceV::$with_variant(Packed(self)),
+                        })
+                    )
+                }
+            }
+
             impl<'s> PanicFmt for [$ty] {
                 type This = Self;
                 type Kind = crate::fmt::IsStdType;
@@ -102,27 +345,63 @@ macro_rules! impl_panicfmt_array {
                     PanicVal::$panicval_ctor(self.0, f)
                 }
             }
+
+            impl<'s> PanicFmt for [&'s [$ty]] {
+                type This = Self;
+                type Kind = crate::fmt::IsStdType;
+                const PV_COUNT: usize = 1;
+            }
+            impl<'s, const LEN: usize> PanicFmt for [&'s [$ty]; LEN] {
+                type This = Self;
+                type Kind = crate::fmt::IsStdType;
+                const PV_COUNT: usize = 1;
+            }
+
+            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+            impl<'s> StdWrapper<&'s [&'s [$ty]]> {
+                /// Converts the slice to a single-element `PanicVal` array.
+                pub const fn to_panicvals(self: Self, f:FmtArg) -> [PanicVal<'s>;1] {
+                    [PanicVal::$nested_panicval_ctor(self.0, f)]
+                }
+                /// Converts the slice to a `PanicVal`.
+                pub const fn to_panicval(self: Self, f:FmtArg) -> PanicVal<'s> {
+                    PanicVal::$nested_panicval_ctor(self.0, f)
+                }
+            }
+
+            #[cfg_attr(feature = "docsrs", doc(cfg(feature = "non_basic")))]
+            impl<'s, const LEN: usize> StdWrapper<&'s [&'s [$ty]; LEN]> {
+                /// Converts the array to a single-element `PanicVal` array.
+                pub const fn to_panicvals(self: Self, f:FmtArg) -> [PanicVal<'s>;1] {
+                    [PanicVal::$nested_panicval_ctor(self.0, f)]
+                }
+                /// Converts the array to a `PanicVal`.
+                pub const fn to_panicval(self: Self, f:FmtArg) -> PanicVal<'s> {
+                    PanicVal::$nested_panicval_ctor(self.0, f)
+                }
+            }
         )*
 
     };
 }
 
 impl_panicfmt_array! {
-    (U8, from_slice_u8, u8),
-    (U16, from_slice_u16, u16),
-    (U32, from_slice_u32, u32),
-    (U64, from_slice_u64, u64),
-    (U128, from_slice_u128, u128),
-    (Usize, from_slice_usize, usize),
-    (I8, from_slice_i8, i8),
-    (I16, from_slice_i16, i16),
-    (I32, from_slice_i32, i32),
-    (I64, from_slice_i64, i64),
-    (I128, from_slice_i128, i128),
-    (Isize, from_slice_isize, isize),
-    (Bool, from_slice_bool, bool),
-    (Char, from_slice_char, char),
-    (Str, from_slice_str, &'s str),
+    (U8, from_slice_u8, NestedU8, from_nested_slice_u8, WithU8, u8),
+    (U16, from_slice_u16, NestedU16, from_nested_slice_u16, WithU16, u16),
+    (U32, from_slice_u32, NestedU32, from_nested_slice_u32, WithU32, u32),
+    (U64, from_slice_u64, NestedU64, from_nested_slice_u64, WithU64, u64),
+    (U128, from_slice_u128, NestedU128, from_nested_slice_u128, WithU128, u128),
+    (Usize, from_slice_usize, NestedUsize, from_nested_slice_usize, WithUsize, usize),
+    (I8, from_slice_i8, NestedI8, from_nested_slice_i8, WithI8, i8),
+    (I16, from_slice_i16, NestedI16, from_nested_slice_i16, WithI16, i16),
+    (I32, from_slice_i32, NestedI32, from_nested_slice_i32, WithI32, i32),
+    (I64, from_slice_i64, NestedI64, from_nested_slice_i64, WithI64, i64),
+    (I128, from_slice_i128, NestedI128, from_nested_slice_i128, WithI128, i128),
+    (Isize, from_slice_isize, NestedIsize, from_nested_slice_isize, WithIsize, isize),
+    (Bool, from_slice_bool, NestedBool, from_nested_slice_bool, WithBool, bool),
+    (OptionBool, from_slice_option_bool, NestedOptionBool, from_nested_slice_option_bool, WithOptionBool, Option<bool>),
+    (Char, from_slice_char, NestedChar, from_nested_slice_char, WithChar, char),
+    (Str, from_slice_str, NestedStr, from_nested_slice_str, WithStr, &'s str),
 }
 
 #[derive(Copy, Clone)]
@@ -131,6 +410,8 @@ pub(crate) struct SliceIter<'s> {
     fmtarg: FmtArg,
     state: IterState,
     arr_len: u32,
+    // the separator and whether the delimiters are omitted, for `SliceWith`
+    custom_separator: Option<(&'s str, bool)>,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -149,6 +430,7 @@ impl<'s> Slice<'s> {
             fmtarg: self.fmtarg.unpack(),
             state: IterState::Start,
             arr_len: self.arr_len() as u32,
+            custom_separator: self.vari.custom_separator(),
         }
     }
 }
@@ -165,11 +447,34 @@ impl<'s> SliceIter<'s> {
                     IterState(0)
                 };
 
-                [crate::fmt::OpenBracket.to_panicval(fmtarg), PanicVal::EMPTY]
+                let open = match self.custom_separator {
+                    Some((_, true)) => PanicVal::EMPTY,
+                    Some((_, false)) => open_delimiter(fmtarg.set_alternate(false)),
+                    None => open_delimiter(fmtarg),
+                };
+                [open, PanicVal::EMPTY]
             }
             IterState::End => {
-                let close_brace = crate::fmt::CloseBracket.to_panicval(fmtarg.unindent());
-                return ([close_brace, PanicVal::EMPTY], None);
+                let close = match self.custom_separator {
+                    Some((_, true)) => PanicVal::EMPTY,
+                    Some((_, false)) => close_delimiter(fmtarg.set_alternate(false)),
+                    None => close_delimiter(fmtarg.unindent()),
+                };
+                return ([close, PanicVal::EMPTY], None);
+            }
+            IterState(x) if self.custom_separator.is_some() => {
+                let separator = if x + 1 == self.arr_len {
+                    self.state = IterState::End;
+                    PanicVal::EMPTY
+                } else {
+                    self.state = IterState(x + 1);
+                    match self.custom_separator {
+                        Some((sep, _)) => PanicVal::write_str(sep),
+                        None => PanicVal::EMPTY,
+                    }
+                };
+
+                [self.slice.get(x as usize, fmtarg), separator]
             }
             IterState(x) => {
                 let comma = if x + 1 == self.arr_len {
@@ -188,3 +493,68 @@ impl<'s> SliceIter<'s> {
         (ret, Some(self))
     }
 }
+
+const fn open_delimiter(fmtarg: FmtArg) -> PanicVal<'static> {
+    match (fmtarg.brace_slices(), fmtarg.is_alternate) {
+        (false, _) => crate::fmt::OpenBracket.to_panicval(fmtarg),
+        (true, false) => PanicVal::write_str("{"),
+        (true, true) => PanicVal::write_str("{\n").with_rightpad(fmtarg),
+    }
+}
+
+const fn close_delimiter(fmtarg: FmtArg) -> PanicVal<'static> {
+    match (fmtarg.brace_slices(), fmtarg.is_alternate) {
+        (false, _) => crate::fmt::CloseBracket.to_panicval(fmtarg),
+        (true, false) => PanicVal::write_str("}"),
+        (true, true) => PanicVal::write_str("}").with_leftpad(fmtarg),
+    }
+}
+
+// Flattens the already formatted elements of an array into
+// `[PanicVal<'a>; OUT]`, surrounded by delimiters and separated with commas,
+// for the `array($Type; $len) => $array` argument of `flatten_panicvals`.
+//
+// `elems` must've been formatted with `fmtarg.indent()`,
+// so that they're one level deeper than the delimiters in alternate mode.
+#[doc(hidden)]
+pub const fn flatten_array_panicvals<'a, const PV: usize, const LEN: usize, const OUT: usize>(
+    elems: &[[PanicVal<'a>; PV]; LEN],
+    fmtarg: FmtArg,
+) -> [PanicVal<'a>; OUT] {
+    assert!(
+        LEN * (PV + 1) + 2 == OUT,
+        "OUT must be the amount of PanicVals in the elements, separators, and delimiters",
+    );
+
+    let mut fmtarg = fmtarg.indent();
+    if LEN == 0 {
+        fmtarg = fmtarg.set_alternate(false);
+    }
+
+    let mut out = [PanicVal::EMPTY; OUT];
+    out[0] = open_delimiter(fmtarg);
+
+    let mut o = 1;
+    let mut i = 0;
+    while i < LEN {
+        let mut j = 0;
+        while j < PV {
+            out[o] = elems[i][j];
+            o += 1;
+            j += 1;
+        }
+
+        out[o] = if i + 1 == LEN {
+            crate::fmt::COMMA_TERM
+        } else {
+            crate::fmt::COMMA_SEP
+        }
+        .to_panicval(fmtarg);
+        o += 1;
+        i += 1;
+    }
+
+    out[o] = close_delimiter(fmtarg.unindent());
+
+    out
+}
@@ -82,3 +82,34 @@ impl<T: ?Sized> MyPhantomData<T> {
         [crate::PanicVal::write_str("PhantomData")]
     }
 }
+
+/// Checks that [`PanicVal::EMPTY`](crate::PanicVal::EMPTY) only appears as
+/// trailing padding in `pvs`,
+/// returning the index of the first `EMPTY` that's followed by a non-`EMPTY` value.
+///
+/// This treats any unpadded empty `&str` as `EMPTY`,
+/// so it's only meaningful for formatters that don't write empty strings.
+pub const fn check_empty_padding(pvs: &[crate::PanicVal<'_>]) -> Result<(), usize> {
+    let mut first_empty = None;
+    let mut i = 0;
+    while i < pvs.len() {
+        match (is_empty_padding(&pvs[i]), first_empty) {
+            (true, None) => first_empty = Some(i),
+            (false, Some(index)) => return Err(index),
+            _ => {}
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+const fn is_empty_padding(pv: &crate::PanicVal<'_>) -> bool {
+    use crate::{panic_val::PanicVariant, utils::Packed};
+
+    match pv.var {
+        PanicVariant::Str(strfmt, Packed(str)) => {
+            strfmt.leftpad == 0 && strfmt.rightpad == 0 && str.is_empty()
+        }
+        _ => false,
+    }
+}
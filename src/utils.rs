@@ -1,6 +1,6 @@
 //! Utility functions
 
-use crate::debug_str_fmt::ForEscaping;
+use crate::{debug_str_fmt::ForEscaping, FmtArg, PanicVal};
 
 #[cfg(feature = "rust_1_64")]
 mod utils_1_64_tests;
@@ -19,6 +19,14 @@ pub(crate) const fn min_usize(l: usize, r: usize) -> usize {
     }
 }
 
+pub(crate) const fn min_u8(l: u8, r: u8) -> u8 {
+    if l < r {
+        l
+    } else {
+        r
+    }
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct TailShortString<const LEN: usize> {
     start: u8,
@@ -28,7 +36,8 @@ pub(crate) struct TailShortString<const LEN: usize> {
 pub(crate) type PreFmtString = TailShortString<{ string_cap::PREFMT }>;
 
 pub(crate) mod string_cap {
-    /// The capacity of a [`ShortString`](crate::fmt::ShortString).
+    /// The capacity of a [`ShortString`](crate::fmt::ShortString),
+    /// the maximum length in bytes of the strings it can hold.
     #[cfg(feature = "non_basic")]
     pub const TINY: usize = 16;
 
@@ -38,8 +47,9 @@ pub(crate) mod string_cap {
     // length of string to alternate binary format a 64 bit integer
     pub(crate) const MEDIUM: usize = 66;
 
-    // length of string to alternate binary format a 128 bit integer
-    pub(crate) const LARGE: usize = 130;
+    // length of string to alternate binary format a 128 bit integer,
+    // with a plus sign and digit grouping
+    pub(crate) const LARGE: usize = 162;
 }
 
 impl<const LEN: usize> TailShortString<LEN> {
@@ -122,10 +132,34 @@ impl WasTruncated {
     }
 }
 
-const fn is_char_boundary(b: u8) -> bool {
+pub(crate) const fn is_char_boundary(b: u8) -> bool {
     (b as i8) >= -0x40
 }
 
+// Whether `string` needs to be quoted to be used as a single shell argument.
+//
+// Non-ASCII characters don't cause quoting, nor do the ASCII punctuation characters
+// that shells treat as regular argument characters.
+pub(crate) const fn needs_shell_quoting(string: &str) -> bool {
+    let mut bytes = string.as_bytes();
+
+    if bytes.is_empty() {
+        return true;
+    }
+
+    while let [b, ref rem @ ..] = *bytes {
+        bytes = rem;
+        match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' => {}
+            b'-' | b'_' | b'.' | b'/' | b':' | b'=' | b',' | b'+' | b'@' | b'%' => {}
+            0x80..=0xFF => {}
+            _ => return true,
+        }
+    }
+
+    false
+}
+
 // truncates a utf8-encoded string to the character before the `truncate_to` index
 //
 pub(crate) const fn truncated_str_len(
@@ -148,15 +182,59 @@ pub(crate) const fn truncated_str_len(
     }
 }
 
+// like `truncated_debug_str_len`, but for `Display` formatting that only escapes
+// control characters, and isn't surrounded by quotes.
+pub(crate) const fn truncated_control_escaped_str_len(
+    ranged: RangedBytes<&[u8]>,
+    truncate_to: usize,
+) -> WasTruncated {
+    let blen = ranged.end;
+
+    // `* 6` because the longest escape is written like `\u{NN}` which is 6 bytes
+    if ranged.len() * 6 <= truncate_to {
+        WasTruncated::No
+    } else if truncate_to == 0 {
+        WasTruncated::Yes(0)
+    } else {
+        let mut i = ranged.start;
+        let mut fmtlen = 0;
+        loop {
+            let next_i = next_char_boundary(ranged, min_usize(i + 1, ranged.end));
+
+            let mut j = i;
+            while j < next_i {
+                fmtlen += ForEscaping::control_byte_len(ranged.bytes[j]);
+                j += 1;
+            }
+
+            if fmtlen > truncate_to {
+                break;
+            } else if next_i == ranged.end {
+                i = next_i;
+                break;
+            } else {
+                i = next_i;
+            }
+        }
+
+        if i == blen && fmtlen <= truncate_to {
+            WasTruncated::No
+        } else {
+            WasTruncated::Yes(i - ranged.start)
+        }
+    }
+}
+
 pub(crate) const fn truncated_debug_str_len(
     ranged: RangedBytes<&[u8]>,
     truncate_to: usize,
+    quote: u8,
 ) -> WasTruncated {
     let blen = ranged.end;
 
-    // `* 4` because the longest escape is written like `\xNN` which is 4 bytes
+    // `* 6` because the longest escape is written like `\u{NN}` which is 6 bytes
     // `+ 2` for the quote characters
-    if blen * 4 + 2 <= truncate_to {
+    if ranged.len() * 6 + 2 <= truncate_to {
         WasTruncated::No
     } else if truncate_to == 0 {
         WasTruncated::Yes(0)
@@ -169,7 +247,7 @@ pub(crate) const fn truncated_debug_str_len(
 
             let mut j = i;
             while j < next_i {
-                fmtlen += ForEscaping::byte_len(ranged.bytes[j]);
+                fmtlen += ForEscaping::byte_len(ranged.bytes[j], quote);
                 j += 1;
             }
 
@@ -228,3 +306,97 @@ pub(crate) const fn tail_byte_array<const TO: usize>(
         bytes,
     }
 }
+
+// How many bytes around the first difference `assert_str_eq` prints.
+const STR_DIFF_CONTEXT: usize = 8;
+
+#[doc(hidden)]
+#[track_caller]
+pub const fn assert_str_eq(left: &str, right: &str) {
+    let lbytes = left.as_bytes();
+    let rbytes = right.as_bytes();
+
+    let mut i = 0;
+    while i < lbytes.len() && i < rbytes.len() && lbytes[i] == rbytes[i] {
+        i += 1;
+    }
+
+    if i == lbytes.len() && i == rbytes.len() {
+        return;
+    }
+
+    let lwin = str_window(left, i);
+    let rwin = str_window(right, i);
+
+    crate::concat_panic(&[
+        &[
+            PanicVal::write_str("assertion failed: `left == right`\nfirst difference at byte "),
+            PanicVal::from_usize(i, FmtArg::DISPLAY),
+            PanicVal::write_str("\n  left: "),
+        ],
+        &lwin,
+        &[PanicVal::write_str("\n right: ")],
+        &rwin,
+    ])
+}
+
+#[doc(hidden)]
+pub const fn option_panicvals<'a, 'b, const N: usize>(
+    group: &'a Option<[PanicVal<'b>; N]>,
+) -> Option<&'a [PanicVal<'b>]> {
+    match group {
+        Some(x) => Some(x),
+        None => None,
+    }
+}
+
+// The placeholder that `just_err` writes for `Ok` values,
+// padded to the `PV_COUNT` of the error type.
+#[doc(hidden)]
+pub const fn no_error_panicvals<const N: usize>() -> [PanicVal<'static>; N] {
+    let mut out = [PanicVal::EMPTY; N];
+    out[0] = PanicVal::write_str("<no error>");
+    out
+}
+
+#[doc(hidden)]
+pub const EMPTY_PVS: &[PanicVal<'static>] = &[];
+
+#[doc(hidden)]
+pub const NEWLINE_PVS: &[PanicVal<'static>] = &[PanicVal::write_str("\n")];
+
+// The part of `string` around the `at` byte index, with `...` for the elided parts.
+const fn str_window(string: &str, at: usize) -> [PanicVal<'_>; 3] {
+    let bytes = string.as_bytes();
+
+    let mut start = at.saturating_sub(STR_DIFF_CONTEXT);
+    while start != 0 && !is_char_boundary(bytes[start]) {
+        start -= 1;
+    }
+
+    let mut end = min_usize(at + STR_DIFF_CONTEXT, bytes.len());
+    while end != bytes.len() && !is_char_boundary(bytes[end]) {
+        end += 1;
+    }
+
+    let mut window = bytes;
+    let mut to_remove = bytes.len() - end;
+    while let ([rem @ .., _], 1..) = (window, to_remove) {
+        window = rem;
+        to_remove -= 1;
+    }
+    let mut to_skip = start;
+    while let ([_, rem @ ..], 1..) = (window, to_skip) {
+        window = rem;
+        to_skip -= 1;
+    }
+
+    // safety: `start` and `end` are both at char boundaries
+    let window = unsafe { core::str::from_utf8_unchecked(window) };
+
+    [
+        PanicVal::write_str(if start != 0 { "..." } else { "" }),
+        PanicVal::from_str(window, FmtArg::DEBUG),
+        PanicVal::write_str(if end != bytes.len() { "..." } else { "" }),
+    ]
+}
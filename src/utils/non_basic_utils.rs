@@ -78,6 +78,47 @@ pub const fn slice_max_usize(mut slice: &[usize]) -> usize {
     max
 }
 
+/// Computes the length in bytes of `string` when `Debug`-formatted,
+/// including the quotes and escapes.
+///
+/// This can be used to compute the exact capacity required to `Debug`-format a string.
+///
+/// This assumes that the string is delimited with double quotes,
+/// so it doesn't account for
+/// [`FmtArg::set_single_quotes`](crate::FmtArg::set_single_quotes),
+/// which escapes single quotes instead of double quotes.
+///
+/// # Example
+///
+/// ```rust
+/// use const_panic::{utils::debug_escaped_len, ArrayString, FmtArg, PanicVal};
+///
+/// const STR: &str = "hello\n\t\"world\"\x00";
+/// const LEN: usize = debug_escaped_len(STR);
+///
+/// assert_eq!(LEN, 22);
+///
+/// assert_eq!(
+///     ArrayString::<LEN>::from_panicvals(&[PanicVal::from_str(STR, FmtArg::DEBUG)]).unwrap(),
+///     r#""hello\n\t\"world\"\0""#,
+/// );
+///
+/// ```
+pub const fn debug_escaped_len(string: &str) -> usize {
+    use crate::debug_str_fmt::ForEscaping;
+
+    let mut bytes = string.as_bytes();
+    // the quote characters
+    let mut len = 2;
+
+    while let [b, ref rem @ ..] = *bytes {
+        len += ForEscaping::byte_len(b, b'"');
+        bytes = rem;
+    }
+
+    len
+}
+
 #[doc(hidden)]
 #[track_caller]
 pub const fn assert_flatten_panicvals_length(expected_larger: usize, actual_value: usize) {
@@ -91,3 +132,66 @@ pub const fn assert_flatten_panicvals_length(expected_larger: usize, actual_valu
         ]]);
     }
 }
+
+// The padding flags of a `flatten_panicvals` argument, eg: `width = 8, align = left`
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct PvfPadding {
+    width: u8,
+    left_align: bool,
+}
+
+impl PvfPadding {
+    pub const NEW: Self = Self {
+        width: 0,
+        left_align: false,
+    };
+
+    pub const fn width(mut self, width: u8) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub const fn left_align(mut self, left_align: bool) -> Self {
+        self.left_align = left_align;
+        self
+    }
+}
+
+// Pads `pvs` to `padding.width` bytes with an extra `PanicVal` of spaces,
+// `OUT` must be one more than the length of `pvs`.
+#[doc(hidden)]
+pub const fn pad_panicvals<'a, const OUT: usize>(
+    pvs: &[PanicVal<'a>],
+    padding: PvfPadding,
+) -> [PanicVal<'a>; OUT] {
+    assert!(
+        pvs.len() + 1 == OUT,
+        "OUT must be one more than the length of pvs"
+    );
+
+    let len = crate::concat_panic_::compute_length(&[pvs]);
+    let pad = if len < padding.width as usize {
+        padding.width - len as u8
+    } else {
+        0
+    };
+    let pad = PanicVal::EMPTY.with_leftpad(FmtArg::DISPLAY.set_indentation(pad));
+
+    let mut out = [PanicVal::EMPTY; OUT];
+    let offset = if padding.left_align {
+        out[OUT - 1] = pad;
+        0
+    } else {
+        out[0] = pad;
+        1
+    };
+
+    let mut i = 0;
+    while i < pvs.len() {
+        out[i + offset] = pvs[i];
+        i += 1;
+    }
+
+    out
+}
@@ -0,0 +1,76 @@
+use crate::utils::{is_char_boundary, Packed, RangedBytes};
+
+/// A `Display`-formatted string that's soft-wrapped at `width` columns,
+/// see [`FmtArg::wrap_width`](crate::FmtArg::wrap_width).
+#[derive(Copy, Clone)]
+#[repr(packed)]
+pub(crate) struct WrappedStr<'s> {
+    string: Packed<&'s str>,
+    start: u32,
+    width: u16,
+}
+
+impl<'s> WrappedStr<'s> {
+    pub(crate) const fn new(string: &'s str, width: u16) -> Self {
+        Self {
+            string: Packed(string),
+            start: 0,
+            width,
+        }
+    }
+
+    // Returns the next line (without the newline after it),
+    // whether a newline is written after that line,
+    // and the rest of the string, if there's any.
+    pub(crate) const fn next(self) -> (RangedBytes<&'s [u8]>, bool, Option<Self>) {
+        let Packed(string) = self.string;
+        let bytes = string.as_bytes();
+        let start = self.start as usize;
+        let width = self.width as usize;
+
+        let mut i = start;
+        let mut columns = 0usize;
+        let mut last_space = None;
+        while i < bytes.len() && columns < width {
+            match bytes[i] {
+                b'\n' => return (self.line(i), true, Some(self.rest(i + 1))),
+                b' ' => last_space = Some(i),
+                _ => {}
+            }
+
+            i += 1;
+            while i < bytes.len() && !is_char_boundary(bytes[i]) {
+                i += 1;
+            }
+            columns += 1;
+        }
+
+        if i == bytes.len() {
+            return (self.line(i), false, None);
+        }
+
+        match (bytes[i], last_space) {
+            // the wrapping newline replaces the whitespace
+            (b'\n' | b' ', _) => (self.line(i), true, Some(self.rest(i + 1))),
+            (_, Some(space)) => (self.line(space), true, Some(self.rest(space + 1))),
+            // hard-breaking words that are longer than `width`
+            (_, None) => (self.line(i), true, Some(self.rest(i))),
+        }
+    }
+
+    const fn line(self, end: usize) -> RangedBytes<&'s [u8]> {
+        let Packed(string) = self.string;
+        RangedBytes {
+            start: self.start as usize,
+            end,
+            bytes: string.as_bytes(),
+        }
+    }
+
+    const fn rest(self, start: usize) -> Self {
+        Self {
+            start: start as u32,
+            ..self
+        }
+    }
+}
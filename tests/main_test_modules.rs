@@ -2,9 +2,31 @@ macro_rules! overf_fmt {
     ($len:expr; $($args:tt)*) => ( const_panic::concat_fmt!($len, $len + 1; $($args)*) )
 }
 macro_rules! trunc_fmt {
+    (sep = $sep:expr; $len:expr; $($args:tt)*) => (
+        __trunc_fmt_sep!{$sep; $len; [] [] [$($args)*]}
+    );
     ($len:expr; $($args:tt)*) => ( const_panic::concat_fmt!($len, $len; $($args)*).unwrap() )
 }
 
+// Intersperses `PanicVal::write_str($sep)` between the comma-separated arguments,
+// `$done` is the arguments (and separators) before `$curr`, the argument being parsed.
+macro_rules! __trunc_fmt_sep {
+    ($sep:expr; $len:expr; [$($done:tt)*] [$($curr:tt)*] [$(,)?]) => (
+        const_panic::concat_fmt!($len, $len; $($done)* $($curr)*).unwrap()
+    );
+    ($sep:expr; $len:expr; [$($done:tt)*] [$($curr:tt)*] [, $($rem:tt)*]) => (
+        __trunc_fmt_sep!{
+            $sep; $len;
+            [$($done)* $($curr)*, const_panic::PanicVal::write_str($sep),]
+            []
+            [$($rem)*]
+        }
+    );
+    ($sep:expr; $len:expr; $done:tt [$($curr:tt)*] [$tt:tt $($rem:tt)*]) => (
+        __trunc_fmt_sep!{$sep; $len; $done [$($curr)* $tt] [$($rem)*]}
+    );
+}
+
 mod main_tests {
     #[cfg(feature = "non_basic")]
     mod array_tests;
@@ -34,6 +56,9 @@ mod main_tests {
     #[cfg(feature = "derive")]
     mod derive_tests;
 
+    #[cfg(feature = "derive")]
+    mod fmt_panic_tests;
+
     mod integer_tests;
 
     mod misc_macros_tests;
@@ -89,9 +89,9 @@ fn char_test() {
         (1..=3, "["),
         (4..=4, "['c'"),
         (5..=5, "['c',"),
-        (6..=11, "['c', "),
-        (12..=12, r#"['c', '\x01'"#),
-        (13..=14, r#"['c', '\x01']"#),
+        (6..=12, "['c', "),
+        (13..=13, r#"['c', '\u{1}'"#),
+        (14..=15, r#"['c', '\u{1}']"#),
     ] {
         for len in range {
             assert_eq!(trunc_fmt!(len; ['c', '\x01']), expected);
@@ -124,6 +124,30 @@ fn char_test() {
     }
 }
 
+#[test]
+fn char_slice_test() {
+    use const_panic::{FmtArg, PanicVal};
+
+    let chars: &[char] = &['a', '\n', '\'', '"', '\\', 'ö'];
+
+    assert_eq!(trunc_fmt!(1024; chars), *format!("{:?}", chars));
+    assert_eq!(
+        trunc_fmt!(1024; chars),
+        r#"['a', '\n', '\'', '"', '\\', 'ö']"#
+    );
+    assert_eq!(trunc_fmt!(1024; alt_debug: chars), *format!("{:#?}", chars));
+    assert_eq!(trunc_fmt!(1024; display: chars), "[a, \n, ', \", \\, ö]");
+
+    assert_eq!(
+        trunc_fmt!(1024; PanicVal::from_slice_char(chars, FmtArg::DEBUG)),
+        *format!("{:?}", chars)
+    );
+
+    let empty: &[char] = &[];
+    assert_eq!(trunc_fmt!(1024; empty), "[]");
+    assert_eq!(trunc_fmt!(1024; display: empty), "[]");
+}
+
 #[test]
 fn bin_integer_test() {
     let array = [-4, -3, -2, -1, 0i8, 1, 2, 3, 4];
@@ -256,3 +280,155 @@ fn test_bool_arrays() {
     assert_eq!(trunc_fmt!(11; [true, true]), "[true, true");
     assert_eq!(trunc_fmt!(12; [true, true]), "[true, true]");
 }
+
+#[test]
+fn brace_slices_test() {
+    use const_panic::FmtArg;
+
+    let braced = FmtArg::DEBUG.set_brace_slices(true);
+    let alt_braced = FmtArg::ALT_DEBUG.set_brace_slices(true);
+
+    assert_eq!(trunc_fmt!(99; braced; [1u8, 2, 3]), "{1, 2, 3}");
+    assert_eq!(trunc_fmt!(99; braced; [0u8; 0]), "{}");
+    assert_eq!(trunc_fmt!(99; alt_braced; [0u8; 0]), "{}");
+    assert_eq!(trunc_fmt!(99; braced; ["foo", "bar"]), r#"{"foo", "bar"}"#);
+    assert_eq!(trunc_fmt!(99; braced; Some([true])), "Some({true})");
+    assert_eq!(
+        trunc_fmt!(99; alt_braced; [3u16, 5]),
+        "{\n    3,\n    5,\n}"
+    );
+    assert_eq!(
+        trunc_fmt!(99; alt_braced; Some([3u16, 5])),
+        "Some(\n    {\n        3,\n        5,\n    },\n)"
+    );
+
+    // truncation works the same as with brackets
+    assert_eq!(trunc_fmt!(5; braced; [1u8, 2, 3]), "{1, 2");
+    overf_fmt!(8; braced; [1u8, 2, 3]).unwrap_err();
+}
+
+#[test]
+fn set_indentation_test() {
+    use const_panic::FmtArg;
+
+    let fmt = FmtArg::ALT_DEBUG.set_indentation(2);
+    assert_eq!(fmt.indentation, 2);
+    assert_eq!(fmt, FmtArg::ALT_DEBUG.indent().set_indentation(2));
+    assert_eq!(fmt.set_indentation(0), FmtArg::ALT_DEBUG);
+
+    assert_eq!(trunc_fmt!(99; fmt; [3u16, 5]), "[\n      3,\n      5,\n  ]");
+    assert_eq!(
+        trunc_fmt!(99; fmt; Some([3u16])),
+        "Some(\n      [\n          3,\n      ],\n  )"
+    );
+
+    // indentation only matters for alternate formatting
+    let non_alt = FmtArg::DEBUG.set_indentation(2);
+    assert_eq!(trunc_fmt!(99; non_alt; [3u16, 5]), "[3, 5]");
+}
+
+#[test]
+fn nested_slice_test() {
+    use const_panic::FmtArg;
+
+    let alt = FmtArg::ALT_DEBUG;
+    let braced = FmtArg::DEBUG.set_brace_slices(true);
+
+    let ints: [&[u8]; 3] = [&[3, 5], &[], &[8]];
+    let strs: &[&[&str]] = &[&["foo"], &["bar", "baz"]];
+    let empty: &[&[u16]] = &[];
+
+    assert_eq!(trunc_fmt!(999; ints), *format!("{:?}", ints));
+    assert_eq!(trunc_fmt!(999; alt; ints), *format!("{:#?}", ints));
+    assert_eq!(trunc_fmt!(999; strs), *format!("{:?}", strs));
+    assert_eq!(trunc_fmt!(999; alt; strs), *format!("{:#?}", strs));
+    assert_eq!(trunc_fmt!(999; empty), "[]");
+    assert_eq!(trunc_fmt!(999; alt; empty), "[]");
+
+    assert_eq!(trunc_fmt!(999; braced; ints), "{{3, 5}, {}, {8}}");
+
+    assert_eq!(trunc_fmt!(8; ints), "[[3, 5],");
+    overf_fmt!(8; ints).unwrap_err();
+    assert_eq!(overf_fmt!(18; ints).unwrap(), "[[3, 5], [], [8]]");
+}
+
+#[test]
+fn slice_with_separator_test() {
+    use const_panic::{fmt::SliceWith, FmtArg};
+
+    let ints = SliceWith::new(&[3u8, 5, 8], "; ");
+    let bare = SliceWith::new(&[3i32, -5, 8], ",").set_bare(true);
+    let strs = SliceWith::new(&["foo", "bar"], " ");
+    let single = SliceWith::new(&['a'], "-");
+    let empty = SliceWith::new(&[0u64; 0], ", ");
+
+    assert_eq!(trunc_fmt!(99; ints), "[3; 5; 8]");
+    assert_eq!(trunc_fmt!(99; bare), "3,-5,8");
+    assert_eq!(trunc_fmt!(99; strs), r#"["foo" "bar"]"#);
+    assert_eq!(trunc_fmt!(99; FmtArg::DISPLAY; strs), "[foo bar]");
+    assert_eq!(trunc_fmt!(99; single), "['a']");
+    assert_eq!(trunc_fmt!(99; empty), "[]");
+    assert_eq!(trunc_fmt!(99; empty.set_bare(true)), "");
+
+    // always on a single line
+    assert_eq!(trunc_fmt!(99; FmtArg::ALT_DEBUG; ints), "[3; 5; 8]");
+    assert_eq!(trunc_fmt!(99; FmtArg::ALT_HEX; bare), "0x3,0xFFFFFFFB,0x8");
+
+    let braced = FmtArg::DEBUG.set_brace_slices(true);
+    assert_eq!(trunc_fmt!(99; braced; ints), "{3; 5; 8}");
+    assert_eq!(trunc_fmt!(99; braced; bare), "3,-5,8");
+
+    assert_eq!(trunc_fmt!(5; ints), "[3; 5");
+    overf_fmt!(8; ints).unwrap_err();
+    assert_eq!(overf_fmt!(9; ints).unwrap(), "[3; 5; 8]");
+}
+
+#[test]
+fn byte_str_test() {
+    use const_panic::{fmt::ByteStr, FmtArg};
+
+    let all_bytes = (0..=u8::MAX).collect::<Vec<u8>>();
+    let as_i8 = all_bytes.iter().map(|&b| b as i8).collect::<Vec<i8>>();
+
+    // `escape_ascii` escapes `'` and uses `\x00` for nul, unlike byte string literals
+    let escaped = all_bytes
+        .iter()
+        .map(|&b| match b {
+            b'\0' => String::from("\\0"),
+            b'\'' => String::from("'"),
+            _ => std::ascii::escape_default(b).to_string(),
+        })
+        .collect::<String>();
+    let displayed = all_bytes
+        .iter()
+        .map(|&b| {
+            if b.is_ascii() {
+                (b as char).to_string()
+            } else {
+                format!("\\x{:02x}", b)
+            }
+        })
+        .collect::<String>();
+
+    let u8s = ByteStr::new(&all_bytes[..]);
+    let i8s = ByteStr::new(&as_i8[..]);
+
+    assert_eq!(trunc_fmt!(1024; u8s), *format!("b\"{}\"", escaped));
+    assert_eq!(trunc_fmt!(1024; i8s), *format!("b\"{}\"", escaped));
+    assert_eq!(trunc_fmt!(1024; FmtArg::DISPLAY; u8s), *displayed);
+    assert_eq!(trunc_fmt!(1024; FmtArg::DISPLAY; i8s), *displayed);
+
+    // always on a single line
+    let hello = ByteStr::new(b"hello\n");
+    assert_eq!(trunc_fmt!(99; FmtArg::ALT_DEBUG; hello), r#"b"hello\n""#);
+
+    let empty = ByteStr::new(&[0i8; 0]);
+    assert_eq!(trunc_fmt!(99; empty), r#"b"""#);
+    assert_eq!(trunc_fmt!(99; FmtArg::DISPLAY; empty), "");
+
+    // escapes aren't split when truncating
+    let signed = ByteStr::new(&[b'a' as i8, -1]);
+    assert_eq!(trunc_fmt!(4; signed), r#"b"a"#);
+    assert_eq!(trunc_fmt!(7; signed), r#"b"a\xff"#);
+    assert_eq!(trunc_fmt!(8; signed), r#"b"a\xff""#);
+}
@@ -1,6 +1,6 @@
 use const_panic::{
-    fmt::{ShortString, SHORT_STRING_CAP},
-    ArrayString, FmtArg, PanicVal,
+    fmt::{ShortString, StrOrShort, SHORT_STRING_CAP},
+    ArrayString, FmtArg, NotEnoughSpace, PanicVal,
 };
 
 #[test]
@@ -19,6 +19,112 @@ fn concat_test() {
     std::panic::catch_unwind(|| ArrayString::<10>::concat(&["abcd", "efghij", "k"])).unwrap_err();
 }
 
+#[test]
+fn try_concat_and_push_str_test() {
+    for strings in [
+        &[][..],
+        &[""],
+        &["hello", ""],
+        &["hello", "world"],
+        &["", "world"],
+    ] {
+        let string = ArrayString::<10>::try_concat(strings).unwrap();
+        assert_eq!(string, *strings.concat());
+        assert_eq!(string.len(), strings.concat().len());
+    }
+
+    assert_eq!(
+        ArrayString::<10>::try_concat(&["abcd", "efghij"]).unwrap(),
+        "abcdefghij"
+    );
+    assert_eq!(
+        ArrayString::<10>::try_concat(&["abcd", "efghij", "k"]).unwrap_err(),
+        NotEnoughSpace
+    );
+
+    const fn build() -> Result<ArrayString<10>, NotEnoughSpace> {
+        let string = ArrayString::new("ab");
+        let string = match string.try_push_str("cde") {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+        let string = match string.try_push_str("") {
+            Ok(x) => x,
+            Err(e) => return Err(e),
+        };
+        string.try_push_str("fghij")
+    }
+    const BUILT: ArrayString<10> = match build() {
+        Ok(x) => x,
+        Err(_) => panic!("didn't fit"),
+    };
+    assert_eq!(BUILT, "abcdefghij");
+    assert_eq!(BUILT.to_str(), "abcdefghij");
+
+    assert_eq!(BUILT.try_push_str("k").unwrap_err(), NotEnoughSpace);
+    assert_eq!(BUILT.try_push_str("").unwrap(), "abcdefghij");
+
+    let string = ArrayString::<10>::new("ñ个");
+    assert_eq!(string.try_push_str("人人").unwrap_err(), NotEnoughSpace);
+    assert_eq!(string.try_push_str("人").unwrap(), "ñ个人");
+}
+
+#[test]
+fn try_new_short_string_test() {
+    let max_len = "0123456789abcdef";
+    assert_eq!(max_len.len(), SHORT_STRING_CAP);
+
+    assert_eq!(ShortString::try_new("").unwrap(), "");
+    assert_eq!(ShortString::try_new(max_len).unwrap(), max_len);
+    assert_eq!(
+        ShortString::try_new("0123456789abcdefg").unwrap_err(),
+        NotEnoughSpace
+    );
+
+    const PV: PanicVal<'static> = match PanicVal::try_write_short_str("foo\nbar") {
+        Ok(x) => x,
+        Err(_) => panic!("didn't fit"),
+    };
+    assert_eq!(
+        ArrayString::<20>::from_panicvals(&[PV]).unwrap(),
+        "foo\nbar"
+    );
+
+    let pv = PanicVal::try_write_short_str(max_len).unwrap();
+    assert_eq!(ArrayString::<20>::from_panicvals(&[pv]).unwrap(), max_len);
+
+    assert!(PanicVal::try_write_short_str("0123456789abcdefg").is_err());
+}
+
+#[test]
+fn str_or_short_test() {
+    let string = "foo\nbar";
+    let short = ShortString::new(string);
+
+    for (fmt, expected) in [
+        (FmtArg::DISPLAY, "foo\nbar"),
+        (FmtArg::DEBUG, "\"foo\\nbar\""),
+    ] {
+        for sos in [StrOrShort::Str(string), StrOrShort::Short(short)] {
+            assert_eq!(sos.to_str(), string);
+
+            let pv = PanicVal::from_str_or_short(sos, fmt);
+            assert_eq!(ArrayString::<20>::from_panicvals(&[pv]).unwrap(), expected);
+            assert_eq!(
+                ArrayString::<20>::from_panicvals(&sos.to_panicvals(fmt)).unwrap(),
+                expected
+            );
+            assert_eq!(
+                ArrayString::<20>::from_panicvals(&[sos.to_panicval(fmt)]).unwrap(),
+                expected
+            );
+            assert_eq!(trunc_fmt!(20; fmt; sos), *expected);
+        }
+    }
+
+    assert_eq!(<StrOrShort<'_> as const_panic::PanicFmt>::PV_COUNT, 1);
+}
+
 #[test]
 fn concat_and_from_panicvals_test() {
     for (left, right, expected) in [
@@ -60,6 +166,142 @@ fn concat_and_from_panicvals_test() {
     }
 }
 
+#[test]
+fn try_concat_string_test() {
+    use const_panic::{try_concat_string, NotEnoughSpace};
+
+    const ARGS: &[&[PanicVal<'_>]] = &[
+        &[PanicVal::write_str("hello ")],
+        &[PanicVal::from_str("world", FmtArg::DEBUG)],
+    ];
+
+    const OK: Result<ArrayString<13>, NotEnoughSpace> = try_concat_string(ARGS);
+    assert_eq!(OK.unwrap(), "hello \"world\"");
+
+    assert_eq!(try_concat_string::<12>(ARGS).unwrap_err(), NotEnoughSpace);
+    assert_eq!(try_concat_string::<0>(&[]).unwrap(), "");
+}
+
+#[test]
+fn display_len_test() {
+    use const_panic::display_len;
+
+    let padded = FmtArg::DISPLAY.set_indentation(4);
+    let longer_than_panic_msg = "0123456789".repeat(1000);
+
+    let cases: &[&[&[PanicVal<'_>]]] = &[
+        &[],
+        &[&[]],
+        &[&[PanicVal::write_str("hello "), PanicVal::EMPTY]],
+        &[&[PanicVal::from_str("w\u{F6}r\nld\"\0", FmtArg::DEBUG)]],
+        &[&[PanicVal::from_char('\'', FmtArg::DEBUG)]],
+        &[&[PanicVal::from_str("\t", FmtArg::DISPLAY)]],
+        &[
+            &[PanicVal::write_str("a").with_leftpad(padded)],
+            &[PanicVal::write_str("b").with_rightpad(padded)],
+        ],
+        &[&[
+            PanicVal::from_u32(1000, FmtArg::ALT_HEX),
+            PanicVal::from_i8(-128, FmtArg::BIN),
+            PanicVal::from_slice_str(&["a\n", "b"], FmtArg::ALT_DEBUG),
+        ]],
+        &[&[PanicVal::write_str(&longer_than_panic_msg)]],
+    ];
+
+    for args in cases {
+        let formatted = ArrayString::<10240>::concat_panicvals(args).unwrap();
+        assert_eq!(display_len(args), formatted.len(), "{:?}", formatted);
+    }
+
+    const LEN: usize = display_len(&[&[PanicVal::from_str("\n", FmtArg::DEBUG)]]);
+    assert_eq!(LEN, 4);
+}
+
+#[test]
+fn concat_string_truncated_test() {
+    use const_panic::concat_string_truncated;
+
+    const ARGS: &[&[PanicVal<'_>]] = &[
+        &[PanicVal::write_str("hello ")],
+        &[PanicVal::from_str("wörld", FmtArg::DEBUG)],
+        &[PanicVal::from_u16(1000, FmtArg::DISPLAY)],
+    ];
+
+    const FULL: ArrayString<32> = concat_string_truncated(32, ARGS);
+    assert_eq!(FULL, "hello \"wörld\"1000");
+
+    // the budget is smaller than the capacity, truncating the later arguments
+    for (budget, expected) in [
+        (0, ""),
+        (4, "hell"),
+        (6, "hello "),
+        (7, "hello \""),
+        (8, "hello \"w"),
+        (9, "hello \"w"),
+        (10, "hello \"wö"),
+        (13, "hello \"wörld"),
+        (14, "hello \"wörld\""),
+        (17, "hello \"wörld\""),
+        (18, "hello \"wörld\"1000"),
+    ] {
+        assert_eq!(
+            concat_string_truncated::<32>(budget, ARGS),
+            expected,
+            "budget: {}",
+            budget
+        );
+    }
+
+    // budgets larger than the capacity are clamped to the capacity
+    assert_eq!(concat_string_truncated::<8>(100, ARGS), "hello \"w");
+}
+
+#[test]
+fn line_count_test() {
+    const MESSAGE: ArrayString<64> = ArrayString::concat(&["foo: 3\n", "bar: 5\n", "baz: 8"]);
+    const LINES: usize = MESSAGE.line_count();
+    assert_eq!(LINES, 3);
+
+    for string in [
+        "",
+        "\n",
+        "\n\n",
+        "a",
+        "a\n",
+        "a\nb",
+        "a\r\nb\r\n",
+        "\na",
+        "a\n\n\nb",
+        "ñ\n个\n人",
+    ] {
+        assert_eq!(
+            ArrayString::<16>::new(string).line_count(),
+            string.lines().count(),
+            "{:?}",
+            string
+        );
+    }
+}
+
+#[test]
+fn replace_ascii_test() {
+    const MESSAGE: ArrayString<64> = ArrayString::concat(&["hello world ", "ñ 个 "]);
+    const REPLACED: ArrayString<64> = MESSAGE.replace_ascii(b' ', b'_');
+    assert_eq!(REPLACED, "hello_world_ñ_个_");
+
+    let formatted = ArrayString::<64>::from_panicvals(&const_panic::flatten_panicvals!(
+        FmtArg::DEBUG;
+        "value is ", [3u8, 5]
+    ))
+    .unwrap();
+    assert_eq!(formatted.replace_ascii(b' ', b'_'), "value_is_[3,_5]");
+    assert_eq!(formatted.replace_ascii(b'z', b'_'), "value is [3, 5]");
+
+    for (from, to) in [(b' ', 0xC3), (0xC3, b' '), (0x80, 0x80)] {
+        std::panic::catch_unwind(|| MESSAGE.replace_ascii(from, to)).unwrap_err();
+    }
+}
+
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 
 fn strings_iter<'a>(cap: usize, rng: &'a mut SmallRng) -> impl Iterator<Item = String> + 'a {
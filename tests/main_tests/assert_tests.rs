@@ -1,5 +1,11 @@
 use const_panic::concat_assert;
 
+fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+    let payload = std::panic::catch_unwind(f).unwrap_err();
+    let msg = payload.downcast::<String>().unwrap();
+    msg.trim_end_matches('\0').to_string()
+}
+
 #[test]
 fn test_concat_assert() {
     let zero = 0;
@@ -9,3 +15,701 @@ fn test_concat_assert() {
     concat_assert!(zero == 0, "hello", 100u8);
     std::panic::catch_unwind(|| concat_assert!(zero == 1, "hello", 100u8)).unwrap_err();
 }
+
+#[test]
+fn test_truncation_marker() {
+    use const_panic::{concat_panic, PanicVal, MAX_PANIC_MSG_LEN, TRUNCATION_MARKER};
+
+    let chunk = "0123456789".repeat(100);
+    let chunk_count = MAX_PANIC_MSG_LEN / chunk.len() + 1;
+    let chunks = vec![PanicVal::write_str(&chunk); chunk_count];
+
+    let msg = panic_message(|| concat_panic(&[&chunks]));
+    assert!(
+        msg.ends_with(TRUNCATION_MARKER),
+        "{}",
+        &msg[msg.len() - 50..]
+    );
+    assert_eq!(msg.len(), MAX_PANIC_MSG_LEN);
+    assert!(chunk
+        .repeat(chunk_count)
+        .starts_with(&msg[..msg.len() - TRUNCATION_MARKER.len()]));
+
+    let msg = panic_message(|| concat_panic(&[&[PanicVal::write_str("hello")]]));
+    assert_eq!(msg, "hello");
+}
+
+#[test]
+fn test_concat_panic_capacity() {
+    use const_panic::{concat_panic, PanicVal, MAX_PANIC_MSG_LEN};
+
+    // the panic message is padded with nul bytes up to the capacity of its buffer
+    fn buffer_len(len: usize) -> usize {
+        let text = "0123456789".repeat(len / 10);
+        let args: &[&[PanicVal<'_>]] = &[&[PanicVal::write_str(&text)]];
+        let payload = std::panic::catch_unwind(|| concat_panic(args)).unwrap_err();
+        let msg = payload.downcast::<String>().unwrap();
+        assert_eq!(msg.trim_end_matches('\0'), text);
+        msg.len()
+    }
+
+    if cfg!(feature = "small_stack") {
+        assert_eq!(buffer_len(0), MAX_PANIC_MSG_LEN);
+        assert_eq!(buffer_len(500), MAX_PANIC_MSG_LEN);
+    } else {
+        assert_eq!(buffer_len(0), 1024);
+        assert_eq!(buffer_len(1020), 1024);
+        assert_eq!(buffer_len(1030), 6144);
+        assert_eq!(buffer_len(6140), 6144);
+        assert_eq!(buffer_len(6150), MAX_PANIC_MSG_LEN);
+        assert_eq!(buffer_len(20000), MAX_PANIC_MSG_LEN);
+    }
+}
+
+#[test]
+fn max_panic_msg_len_test() {
+    let expected = if cfg!(feature = "small_stack") {
+        512
+    } else {
+        32768
+    };
+    assert_eq!(const_panic::MAX_PANIC_MSG_LEN, expected);
+}
+
+#[test]
+fn test_concat_panic_bounded() {
+    use const_panic::{concat_panic_bounded, FmtArg, PanicVal, TRUNCATION_MARKER};
+
+    let text = "0123456789".repeat(3);
+    let args: &[&[PanicVal<'_>]] = &[&[PanicVal::write_str(&text)]];
+
+    // fits exactly
+    assert_eq!(panic_message(|| concat_panic_bounded::<30>(args)), text);
+    assert_eq!(panic_message(|| concat_panic_bounded::<31>(args)), text);
+
+    // truncated exactly at the bound, including the marker
+    for max in [14, 15, 20, 29] {
+        let msg = match max {
+            14 => panic_message(|| concat_panic_bounded::<14>(args)),
+            15 => panic_message(|| concat_panic_bounded::<15>(args)),
+            20 => panic_message(|| concat_panic_bounded::<20>(args)),
+            _ => panic_message(|| concat_panic_bounded::<29>(args)),
+        };
+        assert_eq!(msg.len(), max);
+        assert_eq!(
+            msg,
+            format!(
+                "{}{}",
+                &text[..max - TRUNCATION_MARKER.len()],
+                TRUNCATION_MARKER
+            )
+        );
+    }
+
+    let args: &[&[PanicVal<'_>]] = &[&[
+        PanicVal::write_str("the value is "),
+        PanicVal::from_u64(12345678901234, FmtArg::DISPLAY),
+    ]];
+    assert_eq!(
+        panic_message(|| concat_panic_bounded::<27>(args)),
+        "the value is 12345678901234"
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_bounded::<26>(args)),
+        "the value is...[truncated]"
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_bounded::<24>(args)),
+        "the value ...[truncated]"
+    );
+
+    // larger than the messages that the escalating buffers fit
+    let long = "0123456789".repeat(800);
+    let msg = panic_message(|| concat_panic_bounded::<8200>(&[&[PanicVal::write_str(&long)]]));
+    assert_eq!(msg.len(), 8000);
+    let msg = panic_message(|| concat_panic_bounded::<7000>(&[&[PanicVal::write_str(&long)]]));
+    assert_eq!(msg.len(), 7000);
+    assert!(msg.ends_with(TRUNCATION_MARKER));
+
+    assert_eq!(
+        panic_message(|| concat_panic_bounded::<13>(args)),
+        "the `MAX` argument of `concat_panic_bounded` must be at least 14"
+    );
+}
+
+#[test]
+fn test_concat_panic_bounded_with_marker() {
+    use const_panic::{concat_panic_bounded_with_marker, FmtArg, PanicVal};
+
+    let args: &[&[PanicVal<'_>]] = &[&[
+        PanicVal::write_str("the value is "),
+        PanicVal::from_u64(12345678901234, FmtArg::DISPLAY),
+    ]];
+
+    assert_eq!(
+        panic_message(|| concat_panic_bounded_with_marker::<27>("...", args)),
+        "the value is 12345678901234"
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_bounded_with_marker::<16>("...", args)),
+        "the value is ..."
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_bounded_with_marker::<3>("...", args)),
+        "..."
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_bounded_with_marker::<10>("", args)),
+        "the value "
+    );
+
+    // the message is truncated on a char boundary, before the marker
+    let args: &[&[PanicVal<'_>]] = &[&[PanicVal::write_str("aaaññ")]];
+    assert_eq!(
+        panic_message(|| concat_panic_bounded_with_marker::<5>("~", args)),
+        "aaa~"
+    );
+
+    let payload = std::panic::catch_unwind(|| concat_panic_bounded_with_marker::<2>("...", args))
+        .unwrap_err();
+    assert_eq!(
+        *payload.downcast::<&str>().unwrap(),
+        "the `MAX` argument of `concat_panic_bounded_with_marker` \
+         must be at least the length of `marker`"
+    );
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn test_concat_panic_max() {
+    use const_panic::{concat_panic_max, FmtArg, PanicVal};
+
+    let args: &[&[PanicVal<'_>]] = &[&[
+        PanicVal::write_str("the value is "),
+        PanicVal::from_u64(12345678901234, FmtArg::DISPLAY),
+    ]];
+    assert_eq!(
+        panic_message(|| concat_panic_max::<27>(args)),
+        "the value is 12345678901234"
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_max::<100>(args)),
+        "the value is 12345678901234"
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_max::<26>(args)),
+        "the panic message is 27 bytes long, which is longer than the maximum of 26 bytes"
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_max::<0>(args)),
+        "the panic message is 27 bytes long, which is longer than the maximum of 0 bytes"
+    );
+    assert_eq!(panic_message(|| concat_panic_max::<0>(&[])), "");
+
+    let len = 14usize;
+    let payload =
+        std::panic::catch_unwind(|| concat_panic_max!(32; "id is too long: ", len)).unwrap_err();
+    assert_eq!(*payload.downcast::<String>().unwrap(), "id is too long: 14");
+
+    let payload = std::panic::catch_unwind(|| concat_panic_max!(4; FmtArg::DEBUG; "id: ", "ABC"))
+        .unwrap_err();
+    assert_eq!(
+        *payload.downcast::<String>().unwrap(),
+        "the panic message is 7 bytes long, which is longer than the maximum of 4 bytes"
+    );
+}
+
+#[test]
+fn test_concat_panic_with_header() {
+    use const_panic::{concat_panic_with_header, FmtArg, MAX_PANIC_MSG_LEN};
+
+    let len = 3usize;
+    assert_eq!(
+        panic_message(|| concat_panic_with_header!("[const assert] "; "len: ", len)),
+        "[const assert] len: 3"
+    );
+
+    // the header is written with Display formatting regardless of the `$fmtarg`
+    const HEADER: &str = "\"header\": ";
+    let bar = "bar";
+    assert_eq!(
+        panic_message(|| concat_panic_with_header!(HEADER; FmtArg::DEBUG; "foo", bar)),
+        r#""header": foo"bar""#
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_with_header!("header";)),
+        "header"
+    );
+
+    // the header counts towards the maximum length of the panic message
+    let long = "a".repeat(MAX_PANIC_MSG_LEN);
+    let msg = panic_message(|| concat_panic_with_header!("[header] "; display: &*long));
+    assert_eq!(msg.len(), MAX_PANIC_MSG_LEN);
+    assert!(msg.starts_with("[header] aaa"), "{}", msg);
+}
+
+#[test]
+fn test_concat_panic_framed() {
+    use const_panic::{concat_panic_framed, FmtArg, MAX_PANIC_MSG_LEN, TRUNCATION_MARKER};
+
+    let len = 3usize;
+    assert_eq!(
+        panic_message(|| concat_panic_framed!("len: ", len)),
+        "\nlen: 3\n"
+    );
+
+    // the newlines are written with Display formatting regardless of the `$fmtarg`
+    let bar = "bar";
+    assert_eq!(
+        panic_message(|| concat_panic_framed!(FmtArg::DEBUG; "foo", bar,)),
+        "\nfoo\"bar\"\n"
+    );
+    assert_eq!(panic_message(|| concat_panic_framed!()), "\n\n");
+
+    // the newlines count towards the maximum length of the panic message
+    let long = "a".repeat(MAX_PANIC_MSG_LEN - 2);
+    let msg = panic_message(|| concat_panic_framed!(display: &*long));
+    assert_eq!(msg, format!("\n{}\n", long));
+
+    let long = "a".repeat(MAX_PANIC_MSG_LEN);
+    let msg = panic_message(|| concat_panic_framed!(display: &*long));
+    assert_eq!(msg.len(), MAX_PANIC_MSG_LEN);
+    assert!(msg.starts_with("\naaa"));
+    assert!(msg.ends_with(TRUNCATION_MARKER));
+}
+
+#[test]
+fn test_concat_panic_joined() {
+    use const_panic::{concat_panic_joined, FmtArg, PanicVal, MAX_PANIC_MSG_LEN};
+
+    let newline = &[PanicVal::write_str("\n")];
+    let groups: &[&[PanicVal<'_>]] = &[
+        &[PanicVal::write_str("port 0 is reserved")],
+        &[
+            PanicVal::write_str("port "),
+            PanicVal::from_u32(70000, FmtArg::DISPLAY),
+            PanicVal::write_str(" is out of range"),
+        ],
+        &[PanicVal::from_str("bar", FmtArg::DEBUG)],
+    ];
+
+    assert_eq!(
+        panic_message(|| concat_panic_joined(newline, groups)),
+        "port 0 is reserved\nport 70000 is out of range\n\"bar\""
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_joined(
+            &[PanicVal::write_str(", "), PanicVal::write_str("| ")],
+            groups
+        )),
+        "port 0 is reserved, | port 70000 is out of range, | \"bar\""
+    );
+    assert_eq!(
+        panic_message(|| concat_panic_joined(&[], groups)),
+        "port 0 is reservedport 70000 is out of range\"bar\""
+    );
+
+    // the separator is only written between groups
+    assert_eq!(
+        panic_message(|| concat_panic_joined(newline, &groups[..1])),
+        "port 0 is reserved"
+    );
+    assert_eq!(panic_message(|| concat_panic_joined(newline, &[])), "");
+    assert_eq!(
+        panic_message(|| concat_panic_joined(newline, &[&[], &[]])),
+        "\n"
+    );
+
+    // messages longer than the initial buffer include the separators in their length
+    if !cfg!(feature = "small_stack") {
+        let long = "a".repeat(1500);
+        let long_groups: &[&[PanicVal<'_>]] =
+            &[&[PanicVal::write_str(&long)], &[PanicVal::write_str(&long)]];
+        assert_eq!(
+            panic_message(|| concat_panic_joined(newline, long_groups)),
+            format!("{}\n{}", long, long)
+        );
+    }
+
+    let long = "a".repeat(MAX_PANIC_MSG_LEN / 2);
+    let long_groups: &[&[PanicVal<'_>]] =
+        &[&[PanicVal::write_str(&long)], &[PanicVal::write_str(&long)]];
+    let msg = panic_message(|| concat_panic_joined(newline, long_groups));
+    assert_eq!(msg.len(), MAX_PANIC_MSG_LEN);
+    assert_eq!(msg.find('\n'), Some(long.len()));
+}
+
+#[test]
+fn test_concat_panic_slice() {
+    use const_panic::{concat_panic_slice, FmtArg, PanicVal};
+
+    const fn out_of_bounds(index: usize, len: usize) -> [PanicVal<'static>; 4] {
+        [
+            PanicVal::write_str("index out of bounds: the len is "),
+            PanicVal::from_usize(len, FmtArg::DISPLAY),
+            PanicVal::write_str(" but the index is "),
+            PanicVal::from_usize(index, FmtArg::DISPLAY),
+        ]
+    }
+
+    assert_eq!(
+        panic_message(|| concat_panic_slice(&out_of_bounds(5, 3))),
+        "index out of bounds: the len is 3 but the index is 5"
+    );
+    assert_eq!(panic_message(|| concat_panic_slice(&[])), "");
+    assert_eq!(
+        panic_message(|| concat_panic_slice(&[PanicVal::from_str("foo", FmtArg::DEBUG)])),
+        "\"foo\""
+    );
+}
+
+#[test]
+fn test_const_assert_str_eq() {
+    use const_panic::const_assert_str_eq;
+
+    const _: () = const_assert_str_eq!("hello", "hello");
+    const_assert_str_eq!("", "");
+
+    assert_eq!(
+        panic_message(|| const_assert_str_eq!("Hello, world!", "Hello, World!")),
+        concat!(
+            "assertion failed: `left == right`\n",
+            "first difference at byte 7\n",
+            "  left: \"Hello, world!\"\n",
+            " right: \"Hello, World!\"",
+        )
+    );
+
+    assert_eq!(
+        panic_message(|| const_assert_str_eq!(
+            "abcdefghijklmnXpqrstuvwxyz",
+            "abcdefghijklmnYpqrstuvwxyz"
+        )),
+        concat!(
+            "assertion failed: `left == right`\n",
+            "first difference at byte 14\n",
+            "  left: ...\"ghijklmnXpqrstuv\"...\n",
+            " right: ...\"ghijklmnYpqrstuv\"...",
+        )
+    );
+
+    // one string is a prefix of the other
+    assert_eq!(
+        panic_message(|| const_assert_str_eq!("foo", "foobar\n")),
+        concat!(
+            "assertion failed: `left == right`\n",
+            "first difference at byte 3\n",
+            "  left: \"foo\"\n",
+            " right: \"foobar\\n\"",
+        )
+    );
+
+    // windows over multi-byte chars
+    assert_eq!(
+        panic_message(|| const_assert_str_eq!("ñññññññññññ", "ñññññññññño")),
+        concat!(
+            "assertion failed: `left == right`\n",
+            "first difference at byte 20\n",
+            "  left: ...\"ñññññ\"\n",
+            " right: ...\"ñññño\"",
+        )
+    );
+}
+
+#[test]
+fn test_const_assert_cmp() {
+    use const_panic::const_assert_cmp;
+
+    const _: () = const_assert_cmp!(2u8, <, 3u8);
+    const _: () = const_assert_cmp!('a', !=, 'b');
+
+    let (two, three) = (2i32, 3i32);
+    const_assert_cmp!(two, <, three);
+    const_assert_cmp!(two, <=, two);
+    const_assert_cmp!(three, >, two);
+    const_assert_cmp!(three, >=, three);
+    const_assert_cmp!(two, ==, two);
+    const_assert_cmp!(two, !=, three);
+
+    assert_eq!(
+        panic_message(|| const_assert_cmp!(three, <, two)),
+        "assertion failed: 3 < 2"
+    );
+    assert_eq!(
+        panic_message(|| const_assert_cmp!(three, <=, two)),
+        "assertion failed: 3 <= 2"
+    );
+    assert_eq!(
+        panic_message(|| const_assert_cmp!(two, >, three)),
+        "assertion failed: 2 > 3"
+    );
+    assert_eq!(
+        panic_message(|| const_assert_cmp!(-two, >=, two)),
+        "assertion failed: -2 >= 2"
+    );
+    assert_eq!(
+        panic_message(|| const_assert_cmp!(two, ==, three)),
+        "assertion failed: 2 == 3"
+    );
+    assert_eq!(
+        panic_message(|| const_assert_cmp!(true, !=, true)),
+        "assertion failed: true != true"
+    );
+    assert_eq!(
+        panic_message(|| const_assert_cmp!('a', ==, 'b')),
+        "assertion failed: 'a' == 'b'"
+    );
+}
+
+#[test]
+fn test_concat_panic_with_crash_buffer() {
+    use const_panic::{concat_panic_with_crash_buffer, PanicVal};
+    use std::panic::AssertUnwindSafe;
+
+    fn panic_with_buffer(buffer: &mut [u8], args: &[&[PanicVal<'_>]]) -> String {
+        let payload = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            concat_panic_with_crash_buffer(buffer, args)
+        }))
+        .unwrap_err();
+        let msg = payload.downcast::<String>().unwrap();
+        msg.trim_end_matches('\0').to_string()
+    }
+
+    let args: &[&[PanicVal<'_>]] = &[
+        &[PanicVal::write_str("sensor failed: ")],
+        &[PanicVal::from_u8(3, const_panic::FmtArg::DEBUG)],
+    ];
+
+    {
+        let mut buffer = [0xFFu8; 32];
+        let msg = panic_with_buffer(&mut buffer, args);
+        assert_eq!(msg, "sensor failed: 3");
+        assert_eq!(&buffer[..msg.len()], msg.as_bytes());
+        assert!(buffer[msg.len()..].iter().all(|&b| b == 0), "{:?}", buffer);
+    }
+    {
+        let mut buffer = [0xFFu8; 6];
+        let msg = panic_with_buffer(&mut buffer, args);
+        assert_eq!(msg, "sensor failed: 3");
+        assert_eq!(&buffer, b"sensor");
+    }
+    {
+        // only the last message is kept, overwriting the previous one
+        let mut buffer = [0xFFu8; 32];
+        panic_with_buffer(
+            &mut buffer,
+            &[&[PanicVal::write_str("a much longer message")]],
+        );
+        let msg = panic_with_buffer(&mut buffer, args);
+        assert_eq!(&buffer[..msg.len()], msg.as_bytes());
+        assert!(buffer[msg.len()..].iter().all(|&b| b == 0), "{:?}", buffer);
+    }
+    {
+        let mut buffer = [0u8; 0];
+        let msg = panic_with_buffer(&mut buffer, args);
+        assert_eq!(msg, "sensor failed: 3");
+    }
+}
+
+#[test]
+fn test_format_into() {
+    use const_panic::{format_into, FmtArg, NotEnoughSpace, PanicVal};
+
+    let args: &[&[PanicVal<'_>]] = &[
+        &[PanicVal::write_str("sensor ")],
+        &[PanicVal::from_str("ñ", FmtArg::DEBUG)],
+        &[PanicVal::write_str(" failed: ")],
+        &[PanicVal::from_u8(3, FmtArg::DEBUG)],
+    ];
+    let expected = "sensor \"ñ\" failed: 3";
+
+    let mut buffer = [0xFFu8; 64];
+    assert_eq!(format_into(&mut buffer, args), Ok(expected.len()));
+    assert_eq!(&buffer[..expected.len()], expected.as_bytes());
+    // the bytes after the message aren't written
+    assert!(buffer[expected.len()..].iter().all(|&b| b == 0xFF));
+
+    let mut exact = vec![0u8; expected.len()];
+    assert_eq!(format_into(&mut exact, args), Ok(expected.len()));
+    assert_eq!(exact, expected.as_bytes());
+
+    for len in 0..expected.len() {
+        let mut small = vec![0u8; len];
+        assert_eq!(
+            format_into(&mut small, args),
+            Err(NotEnoughSpace),
+            "{}",
+            len
+        );
+    }
+
+    assert_eq!(format_into(&mut [], &[]), Ok(0));
+}
+
+#[test]
+fn test_format_into_truncated() {
+    use const_panic::{format_into_truncated, FmtArg, FormatOutcome, PanicVal};
+
+    let args: &[&[PanicVal<'_>]] = &[
+        &[PanicVal::write_str("sensor ")],
+        &[PanicVal::from_str("ñ", FmtArg::DEBUG)],
+        &[PanicVal::write_str(" failed: ")],
+        &[PanicVal::from_u16(300, FmtArg::DEBUG)],
+    ];
+    let expected = "sensor \"ñ\" failed: 300";
+
+    let outcome = |len: usize, truncated: bool| FormatOutcome { len, truncated };
+
+    let mut buffer = [0xFFu8; 64];
+    assert_eq!(
+        format_into_truncated(&mut buffer, args),
+        outcome(expected.len(), false)
+    );
+    assert_eq!(&buffer[..expected.len()], expected.as_bytes());
+    assert!(buffer[expected.len()..].iter().all(|&b| b == 0xFF));
+
+    let mut exact = vec![0u8; expected.len()];
+    assert_eq!(
+        format_into_truncated(&mut exact, args),
+        outcome(expected.len(), false)
+    );
+
+    // the integer is either fully written or omitted
+    for len in expected.len() - 3..expected.len() {
+        let mut small = vec![0u8; len];
+        assert_eq!(
+            format_into_truncated(&mut small, args),
+            outcome(expected.len() - 3, true)
+        );
+    }
+
+    // strings are truncated on a char boundary
+    let mut small = [0u8; 9];
+    assert_eq!(format_into_truncated(&mut small, args), outcome(8, true));
+    assert_eq!(&small[..8], b"sensor \"");
+
+    assert_eq!(format_into_truncated(&mut [], args), outcome(0, true));
+    assert_eq!(format_into_truncated(&mut [], &[]), outcome(0, false));
+}
+
+#[test]
+fn test_for_each_chunk() {
+    use const_panic::{for_each_chunk, format_into, FmtArg, PanicVal};
+
+    let long = "ñ个a\n".repeat(100);
+    let args: &[&[PanicVal<'_>]] = &[
+        &[PanicVal::write_str("table: ")],
+        &[PanicVal::from_str(&long, FmtArg::DEBUG)],
+        &[PanicVal::from_u64(u64::MAX, FmtArg::DEBUG)],
+    ];
+
+    let mut expected = vec![0u8; 2048];
+    let len = format_into(&mut expected, args).unwrap();
+    let expected = &expected[..len];
+
+    let mut message = Vec::<u8>::new();
+    let mut chunks = 0usize;
+    for_each_chunk(args, |bytes| {
+        assert!(!bytes.is_empty());
+        assert!(bytes.len() <= 64);
+        std::str::from_utf8(bytes).unwrap();
+        message.extend_from_slice(bytes);
+        chunks += 1;
+    });
+    assert_eq!(message, expected);
+    assert!(chunks > len / 64, "{}", chunks);
+
+    let mut chunks = 0usize;
+    for_each_chunk(&[&[PanicVal::write_str("")]], |_| chunks += 1);
+    for_each_chunk(&[], |_| chunks += 1);
+    assert_eq!(chunks, 0);
+}
+
+#[test]
+fn test_concat_panic_all() {
+    use const_panic::{concat_panic_all, FmtArg, PanicVal};
+
+    fn check(n: u32, max: u32) -> Option<[PanicVal<'static>; 2]> {
+        if n <= max {
+            None
+        } else {
+            Some([
+                PanicVal::write_str("too large: "),
+                PanicVal::from_u32(n, FmtArg::DEBUG),
+            ])
+        }
+    }
+
+    fn panic_msg(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let msg = std::panic::catch_unwind(f)
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap();
+        msg.trim_end_matches('\0').to_string()
+    }
+
+    concat_panic_all!(check(3, 5), check(5, 5));
+    concat_panic_all!(None::<[PanicVal<'_>; 0]>,);
+    const _: () = concat_panic_all!(None::<[PanicVal<'_>; 1]>, None::<[PanicVal<'_>; 2]>);
+
+    assert_eq!(
+        panic_msg(|| concat_panic_all!(check(3, 5), check(8, 5), check(13, 5))),
+        "too large: 8\ntoo large: 13",
+    );
+    assert_eq!(
+        panic_msg(|| concat_panic_all!(check(21, 5), check(3, 5))),
+        "too large: 21",
+    );
+    assert_eq!(
+        panic_msg(|| concat_panic_all!(
+            check(3, 5),
+            Some([PanicVal::write_str("first")]),
+            check(3, 5),
+            Some([PanicVal::write_str("second"), PanicVal::write_str("!")]),
+        )),
+        "first\nsecond!",
+    );
+}
+
+#[test]
+fn empty_panicvals_are_skipped() {
+    use const_panic::{for_tests::format_panic_message, FmtArg, PanicVal};
+
+    fn output(args: &[&[PanicVal<'_>]]) -> String {
+        format_panic_message::<1024>(args, 1024, 1024)
+            .unwrap()
+            .as_str()
+            .to_string()
+    }
+
+    assert_eq!(output(&[]), "");
+    assert_eq!(output(&[&[PanicVal::EMPTY; 16]]), "");
+    assert_eq!(
+        output(&[
+            &[PanicVal::EMPTY; 8],
+            &[PanicVal::write_str("a"), PanicVal::EMPTY],
+            &[PanicVal::EMPTY, PanicVal::from_u8(3, FmtArg::DISPLAY)],
+        ]),
+        "a3"
+    );
+
+    // these write something despite formatting an empty string
+    let indented = FmtArg::DISPLAY.set_indentation(2);
+    assert_eq!(
+        output(&[&[
+            PanicVal::from_str("", FmtArg::DEBUG),
+            PanicVal::EMPTY.with_leftpad(indented),
+            PanicVal::EMPTY.with_rightpad(indented),
+        ]]),
+        "\"\"    "
+    );
+
+    // skipped values don't need any space in a full buffer
+    let full = [PanicVal::write_str("abcd")];
+    assert_eq!(
+        format_panic_message::<4>(&[&full, &[PanicVal::EMPTY; 4]], 4, 4)
+            .unwrap()
+            .as_str(),
+        "abcd"
+    );
+}
@@ -6,10 +6,10 @@ macro_rules! test_val {
     ($value:expr) => ({
         let val = $value;
         let display = format!("{}", val);
-        let debug = if val > '\u{7E}' {
+        let debug = if val > '\u{7F}' {
             format!("'{}'", val)
         } else {
-            format!("{:?}", val)
+            ascii_char_debug(val)
         };
         assert_eq!(
             trunc_fmt!(32; StdWrapper(&val).to_panicvals(FmtArg::DEBUG)),
@@ -24,9 +24,65 @@ macro_rules! test_val {
     })
 }
 
+// The `Debug` output of an ASCII char, written out instead of using std,
+// because std escaped NUL as `'\u{0}'` before Rust 1.61.
+fn ascii_char_debug(c: char) -> String {
+    match c {
+        '\0' => r"'\0'".to_string(),
+        '\t' => r"'\t'".to_string(),
+        '\n' => r"'\n'".to_string(),
+        '\r' => r"'\r'".to_string(),
+        '\'' => r"'\''".to_string(),
+        '\\' => r"'\\'".to_string(),
+        '\x00'..='\x1F' | '\x7F' => format!("'\\u{{{:x}}}'", c as u32),
+        _ => format!("'{}'", c),
+    }
+}
+
 #[test]
 fn basic_char_tests() {
-    for c in (' '..='\u{FFF}').chain([char::MAX]) {
+    for c in ('\0'..='\u{FFF}').chain([char::MAX]) {
         test_val! {c}
     }
 }
+
+#[test]
+fn ascii_byte_tests() {
+    use const_panic::PanicVal;
+
+    for b in 0..=u8::MAX {
+        // `escape_default` uses `\x00` for nul, and escapes `"`, unlike byte literals
+        let escaped = match b {
+            b'\0' => String::from("\\0"),
+            b'"' => String::from("\""),
+            _ => std::ascii::escape_default(b).to_string(),
+        };
+        let display = if b.is_ascii() {
+            (b as char).to_string()
+        } else {
+            escaped.clone()
+        };
+
+        assert_eq!(
+            trunc_fmt!(32; PanicVal::from_ascii_byte(b, FmtArg::DEBUG)),
+            *format!("b'{}'", escaped),
+        );
+        assert_eq!(
+            trunc_fmt!(32; PanicVal::from_ascii_byte(b, FmtArg::DISPLAY)),
+            *display,
+        );
+    }
+
+    assert_eq!(
+        trunc_fmt!(32; PanicVal::from_ascii_byte(b'\'', FmtArg::DEBUG)),
+        r"b'\''",
+    );
+    assert_eq!(
+        trunc_fmt!(32; PanicVal::from_ascii_byte(0, FmtArg::DEBUG)),
+        r"b'\0'",
+    );
+    assert_eq!(
+        trunc_fmt!(32; PanicVal::from_ascii_byte(0x80, FmtArg::DEBUG)),
+        r"b'\x80'",
+    );
+}
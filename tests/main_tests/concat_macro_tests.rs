@@ -52,4 +52,13 @@ fn concat_with_formatters() {
         concat_!(alt_bin: [32u32, 33]),
         "[\n    0b100000,\n    0b100001,\n]"
     );
+
+    assert_eq!(
+        concat_!(grouped_hex: [0xDEADBEEFu32]),
+        "[\n    0xDEAD_BEEF,\n]"
+    );
+    assert_eq!(
+        concat_!(grouped_bin: [0b1010_0101u8, 1]),
+        "[\n    0b1010_0101,\n    0b1,\n]"
+    );
 }
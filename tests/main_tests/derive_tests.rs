@@ -220,6 +220,58 @@ mod explicit_gpi {
     );
 }
 
+#[test]
+fn std_phantom_data_field_formatting() {
+    use core::marker::PhantomData;
+
+    struct NoFmt;
+
+    #[derive(PanicFmt)]
+    #[pfmt(ignore(T))]
+    struct WithPhantom<T> {
+        x: u8,
+        marker: PhantomData<T>,
+    }
+
+    let foo: WithPhantom<NoFmt> = WithPhantom {
+        x: 3,
+        marker: PhantomData,
+    };
+
+    // not comparing to std's Debug output,
+    // because newer versions print the type argument of `PhantomData`.
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; WithPhantom<NoFmt> => foo),
+        "WithPhantom { x: 3, marker: PhantomData }"
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG; WithPhantom<NoFmt> => foo),
+        "WithPhantom {\n    x: 3,\n    marker: PhantomData,\n}"
+    );
+}
+
+#[test]
+fn trailing_phantom_data_tuple_formatting() {
+    use core::marker::PhantomData;
+
+    struct NoFmt;
+
+    #[derive(PanicFmt)]
+    #[pfmt(ignore(T))]
+    struct Pair<T>(u8, PhantomData<T>);
+
+    let foo: Pair<NoFmt> = Pair(5, PhantomData);
+
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; Pair<NoFmt> => foo),
+        "Pair(5, PhantomData)"
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG; Pair<NoFmt> => foo),
+        "Pair(\n    5,\n    PhantomData,\n)"
+    );
+}
+
 #[test]
 fn ignored_generic_params_and_impl_formatting() {
     #[derive(Debug)]
@@ -256,3 +308,464 @@ pub struct IgnoredAndImpl<'a, A, B, const X: u32, const Y: char>(
     pub MyPhantomData<A>,
     pub MyPhantomData<B>,
 );
+
+// Asserts that the derived `PanicFmt` impl formats `$val` exactly like `#[derive(Debug)]`,
+// with both `{:?}` and `{:#?}`.
+macro_rules! assert_std_debug_compat {
+    ($ty:ty => $val:expr) => {{
+        let val: $ty = $val;
+
+        for (fmt, expected) in [
+            (FmtArg::DEBUG, format!("{:?}", val)),
+            (FmtArg::ALT_DEBUG, format!("{:#?}", val)),
+        ] {
+            assert_eq!(
+                const_panic::ArrayString::<1024>::from_panicvals(
+                    &const_panic::flatten_panicvals!(fmt; $ty => val)
+                )
+                .unwrap(),
+                *expected,
+            );
+        }
+    }};
+}
+
+#[test]
+fn std_debug_compat_structs() {
+    #[derive(Debug, PanicFmt)]
+    struct Unit;
+
+    #[derive(Debug, PanicFmt)]
+    struct Empty {}
+
+    #[derive(Debug, PanicFmt)]
+    struct EmptyTuple();
+
+    #[derive(Debug, PanicFmt)]
+    struct Primitives {
+        a: u8,
+        b: i64,
+        c: bool,
+        d: char,
+        e: &'static str,
+        f: (),
+    }
+
+    #[derive(Debug, PanicFmt)]
+    struct Tuple(i8, &'static str, char);
+
+    #[derive(Debug, PanicFmt)]
+    struct Nested {
+        unit: Unit,
+        tuple: Tuple,
+        prims: Primitives,
+    }
+
+    assert_std_debug_compat! {Unit => Unit}
+    assert_std_debug_compat! {Empty => Empty {}}
+    assert_std_debug_compat! {EmptyTuple => EmptyTuple()}
+
+    for (a, b, c, d, e) in [
+        (0, 0, false, 'a', ""),
+        (255, i64::MIN, true, '\n', "hello\tworld"),
+        (13, -8, false, '\'', "\"quoted\"\\"),
+        (21, i64::MAX, true, 'ñ', "\u{0}\u{7f}"),
+    ] {
+        assert_std_debug_compat! {Primitives => Primitives { a, b, c, d, e, f: () }}
+        assert_std_debug_compat! {Tuple => Tuple(b as i8, e, d)}
+    }
+
+    assert_std_debug_compat! {Nested => Nested {
+        unit: Unit,
+        tuple: Tuple(3, "", ' '),
+        prims: Primitives { a: 5, b: -8, c: true, d: '\\', e: "baz", f: () },
+    }}
+    assert_std_debug_compat! {Nested => Nested {
+        unit: Unit,
+        tuple: Tuple(0, "x", 'x'),
+        prims: Primitives { a: 0, b: 0, c: false, d: '0', e: "", f: () },
+    }}
+}
+
+#[test]
+fn std_debug_compat_reverse_fields() {
+    use core::cmp::Reverse;
+
+    #[derive(Debug, PanicFmt)]
+    struct Entry {
+        priority: Reverse<u32>,
+        name: Reverse<&'static str>,
+    }
+
+    assert_std_debug_compat! {Entry => Entry {
+        priority: Reverse(3),
+        name: Reverse("foo"),
+    }}
+}
+
+#[test]
+fn std_debug_compat_poll_fields() {
+    use core::task::Poll;
+
+    #[derive(Debug, PanicFmt)]
+    struct Progress {
+        done: Poll<u32>,
+        pending: Poll<&'static str>,
+    }
+
+    assert_std_debug_compat! {Progress => Progress {
+        done: Poll::Ready(3),
+        pending: Poll::Pending,
+    }}
+}
+
+#[test]
+fn std_debug_compat_control_flow_fields() {
+    use core::ops::ControlFlow;
+
+    #[derive(Debug, PanicFmt)]
+    struct Search {
+        found: ControlFlow<u32>,
+        pending: ControlFlow<(), &'static str>,
+    }
+
+    assert_std_debug_compat! {Search => Search {
+        found: ControlFlow::Break(3),
+        pending: ControlFlow::Continue("next"),
+    }}
+}
+
+#[test]
+fn std_debug_compat_enums() {
+    #[derive(Debug, PanicFmt)]
+    enum Shape {
+        Empty,
+        Point(i32, i32),
+        Rect { width: u32, height: u32 },
+        Labeled { label: &'static str, at: Point },
+        EmptyBraced {},
+        EmptyTuple(),
+    }
+
+    #[derive(Debug, PanicFmt)]
+    struct Point {
+        x: i16,
+        y: i16,
+    }
+
+    #[derive(Debug, PanicFmt)]
+    struct Canvas {
+        first: Shape,
+        second: Shape,
+        background: Option<u32>,
+        title: Option<&'static str>,
+    }
+
+    for shape in [
+        Shape::Empty,
+        Shape::Point(i32::MIN, i32::MAX),
+        Shape::Rect {
+            width: 8,
+            height: 13,
+        },
+        Shape::Labeled {
+            label: "a\npoint",
+            at: Point { x: -3, y: 5 },
+        },
+        Shape::EmptyBraced {},
+        Shape::EmptyTuple(),
+    ] {
+        assert_std_debug_compat! {Shape => shape}
+    }
+
+    assert_std_debug_compat! {Canvas => Canvas {
+        first: Shape::Empty,
+        second: Shape::Rect { width: 1, height: 2 },
+        background: Some(0xFFFFFF),
+        title: None,
+    }}
+    assert_std_debug_compat! {Canvas => Canvas {
+        first: Shape::Point(0, 0),
+        second: Shape::EmptyTuple(),
+        background: None,
+        title: Some("untitled"),
+    }}
+}
+
+#[test]
+fn std_debug_compat_slices() {
+    #[derive(Debug, PanicFmt)]
+    struct Slices<'a> {
+        bytes: &'a [u8],
+        strs: &'a [&'a str],
+        chars: &'a [char],
+        bools: [bool; 3],
+        nested: &'a [&'a [u16]],
+        option: Option<&'a [i8]>,
+    }
+
+    #[derive(Debug, PanicFmt)]
+    struct Wrapper<'a>(Slices<'a>, [u8; 0]);
+
+    let full = Slices {
+        bytes: &[3, 5, 8],
+        strs: &["foo", "b\"ar"],
+        chars: &['a', '\x7f', 'é'],
+        bools: [true, false, true],
+        nested: &[&[], &[1], &[2, 3]],
+        option: Some(&[-1, 1]),
+    };
+    let empty = Slices {
+        bytes: &[],
+        strs: &[],
+        chars: &[],
+        bools: [false; 3],
+        nested: &[],
+        option: None,
+    };
+
+    assert_std_debug_compat! {Wrapper<'_> => Wrapper(full, [])}
+    assert_std_debug_compat! {Wrapper<'_> => Wrapper(empty, [])}
+}
+
+#[test]
+fn struct_style_formatting() {
+    use const_panic::fmt::StructStyle;
+
+    #[derive(Debug, PanicFmt)]
+    enum Shape {
+        Point,
+        Circle(u32),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[derive(Debug, PanicFmt)]
+    struct Canvas {
+        a: Shape,
+        b: Shape,
+        c: Shape,
+    }
+
+    let canvas = Canvas {
+        a: Shape::Point,
+        b: Shape::Circle(3),
+        c: Shape::Rect {
+            width: 5,
+            height: 8,
+        },
+    };
+
+    let fmt = |style| FmtArg::DISPLAY.set_struct_style(style);
+
+    assert_eq!(
+        fmt_flatten!(fmt(StructStyle::Full); Canvas => canvas),
+        "Canvas { a: Point, b: Circle(3), c: Rect { width: 5, height: 8 } }",
+    );
+    assert_eq!(
+        fmt_flatten!(fmt(StructStyle::NoFieldNames); Canvas => canvas),
+        "Canvas { Point, Circle(3), Rect { 5, 8 } }",
+    );
+    assert_eq!(
+        fmt_flatten!(fmt(StructStyle::ValuesOnly); Canvas => canvas),
+        "{ Point, (3), { 5, 8 } }",
+    );
+    assert_eq!(
+        fmt_flatten!(fmt(StructStyle::ValuesOnly).set_alternate(true); Canvas => canvas),
+        "{\n    Point,\n    (\n        3,\n    ),\n    {\n        5,\n        8,\n    },\n}",
+    );
+
+    let debug = FmtArg::DEBUG.set_struct_style(StructStyle::ValuesOnly);
+    assert_eq!(
+        fmt_flatten!(debug; Canvas => canvas),
+        *format!("{:?}", canvas)
+    );
+}
+
+#[test]
+fn rename_attribute() {
+    use const_panic::fmt::StructStyle;
+
+    #[derive(PanicFmt)]
+    #[pfmt(rename = "Shape")]
+    struct ShapeInternal {
+        kind: Kind,
+        size: u32,
+    }
+
+    #[derive(PanicFmt)]
+    enum Kind {
+        #[pfmt(rename = "circle")]
+        Circle,
+        #[pfmt(rename = "square")]
+        Square(u8),
+        Rect {
+            width: u8,
+        },
+    }
+
+    let circle = ShapeInternal {
+        kind: Kind::Circle,
+        size: 3,
+    };
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; ShapeInternal => circle),
+        "Shape { kind: circle, size: 3 }"
+    );
+
+    let square = Kind::Square(5);
+    assert_eq!(fmt_flatten!(FmtArg::DEBUG; Kind => square), "square(5)");
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG; Kind => square),
+        "square(\n    5,\n)"
+    );
+
+    let rect = Kind::Rect { width: 8 };
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; Kind => rect),
+        "Rect { width: 8 }"
+    );
+
+    let values_only = FmtArg::DISPLAY.set_struct_style(StructStyle::ValuesOnly);
+    assert_eq!(
+        fmt_flatten!(values_only; ShapeInternal => circle),
+        "{ circle, 3 }"
+    );
+
+    // renaming doesn't change how many `PanicVal`s are needed
+    assert_eq!(
+        <ShapeInternal as PanicFmt>::PV_COUNT,
+        <Original as PanicFmt>::PV_COUNT
+    );
+
+    let original = Original {
+        kind: Kind::Circle,
+        size: 3,
+    };
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; Original => original),
+        "Original { kind: circle, size: 3 }"
+    );
+
+    #[derive(PanicFmt)]
+    struct Original {
+        kind: Kind,
+        size: u32,
+    }
+}
+
+#[test]
+fn infallible_fields() {
+    use core::convert::Infallible;
+
+    #[derive(PanicFmt)]
+    enum Outcome {
+        Done(u8),
+        #[allow(dead_code)]
+        Failed(Infallible),
+    }
+
+    #[derive(PanicFmt)]
+    #[allow(dead_code)]
+    enum Never {}
+
+    assert_eq!(<Outcome as PanicFmt>::PV_COUNT, 5);
+    assert_eq!(<Never as PanicFmt>::PV_COUNT, 0);
+
+    let done = Outcome::Done(3);
+    assert_eq!(fmt_flatten!(FmtArg::DEBUG; Outcome => done), "Done(3)");
+}
+
+#[test]
+fn fmtarg_attribute() {
+    // the formatting for each field, indexed by field position
+    const FIELD_FMTS: [FmtArg; 2] = [FmtArg::ALT_HEX, FmtArg::DEBUG];
+
+    #[derive(PanicFmt)]
+    struct Reg {
+        #[pfmt(fmtarg = FIELD_FMTS[0])]
+        addr: u32,
+        #[pfmt(fmtarg = FIELD_FMTS[1])]
+        value: u8,
+        flags: u8,
+    }
+
+    #[derive(PanicFmt)]
+    enum Access {
+        Read(#[pfmt(fmtarg = FmtArg::HEX)] u32),
+        Write {
+            #[pfmt(fmtarg = FmtArg::BIN)]
+            mask: u8,
+        },
+    }
+
+    let reg = Reg {
+        addr: 64,
+        value: 255,
+        flags: 10,
+    };
+
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; Reg => reg),
+        "Reg { addr: 0x40, value: 255, flags: 10 }"
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::HEX; Reg => reg),
+        "Reg { addr: 0x40, value: 255, flags: A }"
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG; Reg => reg),
+        "Reg {\n    addr: 0x40,\n    value: 255,\n    flags: 10,\n}"
+    );
+
+    #[derive(PanicFmt)]
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    #[derive(PanicFmt)]
+    struct Line {
+        #[pfmt(fmtarg = FmtArg::HEX)]
+        start: Point,
+        end: Point,
+    }
+
+    let line = Line {
+        start: Point { x: 10, y: 11 },
+        end: Point { x: 12, y: 13 },
+    };
+
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG; Line => line),
+        "Line {\n    \
+            start: Point {\n        x: 0xA,\n        y: 0xB,\n    },\n    \
+            end: Point {\n        x: 12,\n        y: 13,\n    },\n\
+        }"
+    );
+
+    let read = Access::Read(255);
+    assert_eq!(fmt_flatten!(FmtArg::DEBUG; Access => read), "Read(FF)");
+
+    // the options of the enclosing type don't apply to fields with a `fmtarg`
+    let plus = FmtArg::DEBUG.set_plus_sign(true);
+    assert_eq!(
+        fmt_flatten!(plus; Reg => reg),
+        "Reg { addr: 0x40, value: 255, flags: +10 }"
+    );
+    let grouped = FmtArg::DEBUG.set_digit_grouping(true);
+    let big = Reg {
+        addr: 0xDEAD_BEEF,
+        value: 200,
+        flags: 100,
+    };
+    assert_eq!(
+        fmt_flatten!(grouped; Reg => big),
+        "Reg { addr: 0xDEADBEEF, value: 200, flags: 100 }"
+    );
+
+    let write = Access::Write { mask: 5 };
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; Access => write),
+        "Write { mask: 101 }"
+    );
+}
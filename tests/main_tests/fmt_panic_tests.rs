@@ -0,0 +1,92 @@
+use const_panic::fmt_panic;
+
+macro_rules! panic_msg {
+    ($($args:tt)*) => {
+        std::panic::catch_unwind(|| -> () { fmt_panic!($($args)*) })
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap()
+            .trim_end_matches('\0')
+            .to_string()
+    };
+}
+
+#[test]
+fn fmt_panic_positional_test() {
+    let x = 3u8;
+    let y = "hello";
+    let arr = [5u16, 8];
+
+    assert_eq!(panic_msg!("no args"), "no args");
+    assert_eq!(panic_msg!("x={}, y={}", x, y), "x=3, y=hello");
+    assert_eq!(panic_msg!("x={:?}, y={:?}", x, y), r#"x=3, y="hello""#);
+    assert_eq!(panic_msg!("{1}{0}{1}", x, y,), "hello3hello");
+    assert_eq!(panic_msg!("{:?}", arr), "[5, 8]");
+    assert_eq!(panic_msg!("{:#?}", arr), "[\n    5,\n    8,\n]");
+    assert_eq!(panic_msg!("{:b} {:#b}", x, x), "11 0b11");
+    assert_eq!(panic_msg!("{:X} {:#X}", 255u8, 255u8), "FF 0xFF");
+    assert_eq!(panic_msg!("{}", x + 1), "4");
+}
+
+#[test]
+fn fmt_panic_complex_args_test() {
+    let s = "hello";
+    let arr = [3u8, 5, 8];
+    let cond = true;
+
+    assert_eq!(panic_msg!("{}", &s[1..]), "ello");
+    assert_eq!(panic_msg!("{:?}", &arr[..2]), "[3, 5]");
+    assert_eq!(panic_msg!("{:?}", [1u8, 2]), "[1, 2]");
+    assert_eq!(panic_msg!("{}", if cond { 1u8 } else { 2 }), "1");
+    assert_eq!(
+        panic_msg!(
+            "{a} {b}",
+            a = match arr[0] {
+                3 => "three",
+                _ => "other",
+            },
+            b = [s; 2][0]
+        ),
+        "three hello"
+    );
+}
+
+#[test]
+fn fmt_panic_named_test() {
+    let x = 3u8;
+    let name = "foo";
+
+    assert_eq!(panic_msg!("{a}-{b:?}", a = x, b = name), r#"3-"foo""#);
+    assert_eq!(panic_msg!("{name}={x}"), "foo=3");
+    assert_eq!(panic_msg!("{name:?}, {}", x), r#""foo", 3"#);
+    assert_eq!(panic_msg!("{x} {name}", name = 10u32), "3 10");
+}
+
+#[test]
+fn fmt_panic_escaping_test() {
+    let x = 3u8;
+
+    assert_eq!(panic_msg!("{{}}"), "{}");
+    assert_eq!(panic_msg!("{{{}}}", x), "{3}");
+    assert_eq!(panic_msg!("{{x}} {x}"), "{x} 3");
+}
+
+const fn const_fmt_panic(x: u32) -> u32 {
+    if x > 10 {
+        fmt_panic!("expected x <= 10, found {x}")
+    }
+    x
+}
+
+#[test]
+fn fmt_panic_const_fn_test() {
+    assert_eq!(const_fmt_panic(5), 5);
+    assert_eq!(
+        std::panic::catch_unwind(|| const_fmt_panic(11))
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap()
+            .trim_end_matches('\0'),
+        "expected x <= 10, found 11"
+    );
+}
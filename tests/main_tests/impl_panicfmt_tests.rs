@@ -36,6 +36,174 @@ fn struct_formatting() {
         foo.b.to_panicvals(FmtArg::DEBUG);
 }
 
+#[test]
+fn struct_style_formatting() {
+    use const_panic::fmt::StructStyle;
+
+    let array = [3, 5, 8, 13];
+
+    let foo = Foo {
+        x: &array,
+        y: 21,
+        z: Bar(false, true),
+        w: Baz,
+        a: Qux::Up,
+        b: Qux::Down { x: 21, y: 34 },
+        c: Qux::Left(55),
+    };
+
+    let full = FmtArg::DISPLAY.set_struct_style(StructStyle::Full);
+    assert_eq!(
+        trunc_fmt!(999; full; foo),
+        "Foo { x: [3, 5, 8, 13], y: 21, z: Bar(false, true), w: Baz, \
+         a: Up, b: Down { x: 21, y: 34 }, c: Left(55) }"
+    );
+
+    let no_names = FmtArg::DISPLAY.set_struct_style(StructStyle::NoFieldNames);
+    assert_eq!(
+        trunc_fmt!(999; no_names; foo),
+        "Foo { [3, 5, 8, 13], 21, Bar(false, true), Baz, Up, Down { 21, 34 }, Left(55) }"
+    );
+
+    let values = FmtArg::DISPLAY.set_struct_style(StructStyle::ValuesOnly);
+    assert_eq!(
+        trunc_fmt!(999; values; foo),
+        "{ [3, 5, 8, 13], 21, (false, true), Baz, Up, { 21, 34 }, (55) }"
+    );
+    let alt_values = values.set_alternate(true);
+    assert_eq!(trunc_fmt!(999; alt_values; foo.b), "{\n    21,\n    34,\n}");
+    let alt_no_names = no_names.set_alternate(true);
+    assert_eq!(
+        trunc_fmt!(999; alt_no_names; foo.b),
+        "Down {\n    21,\n    34,\n}"
+    );
+
+    // Debug formatting always writes the type and field names
+    for style in [StructStyle::NoFieldNames, StructStyle::ValuesOnly] {
+        let fmt = FmtArg::DEBUG.set_struct_style(style);
+        assert_eq!(trunc_fmt!(999; fmt; foo), *format!("{:?}", foo));
+
+        let fmt = FmtArg::ALT_DEBUG.set_struct_style(style);
+        assert_eq!(trunc_fmt!(999; fmt; foo), *format!("{:#?}", foo));
+
+        // `struct_style` doesn't affect slice elements, so it's not stored
+        assert_eq!(fmt.pack().unpack(), FmtArg::ALT_DEBUG);
+    }
+}
+
+#[test]
+fn indentation_step_formatting() {
+    // replaces the 4-space indentation of `{:#?}` with `per_level` for every level
+    fn reindent(std_output: &str, per_level: &str) -> String {
+        std_output
+            .lines()
+            .map(|line| {
+                let content = line.trim_start_matches(' ');
+                let levels = (line.len() - content.len()) / 4;
+                format!("{}{}", per_level.repeat(levels), content)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    let array = [3, 5, 8, 13];
+
+    let foo = Foo {
+        x: &array,
+        y: 21,
+        z: Bar(false, true),
+        w: Baz,
+        a: Qux::Up,
+        b: Qux::Down { x: 21, y: 34 },
+        c: Qux::Left(55),
+    };
+    let std_output = format!("{:#?}", foo);
+
+    let default_step = FmtArg::ALT_DEBUG.set_indentation_step(4);
+    assert_eq!(default_step, FmtArg::ALT_DEBUG);
+    assert_eq!(trunc_fmt!(999; default_step; foo), *std_output);
+
+    for step in [0u8, 1, 2, 8] {
+        let fmt = FmtArg::ALT_DEBUG.set_indentation_step(step);
+        assert_eq!(
+            trunc_fmt!(999; fmt; foo),
+            *reindent(&std_output, &" ".repeat(step.into()))
+        );
+        assert_eq!(fmt.pack().unpack(), fmt);
+    }
+
+    let tabs = FmtArg::ALT_DEBUG
+        .set_indentation_step(1)
+        .set_tab_indentation(true);
+    assert_eq!(trunc_fmt!(999; tabs; foo), *reindent(&std_output, "\t"));
+    assert_eq!(tabs.pack().unpack(), tabs);
+
+    // the indentation of the first line also uses tabs
+    let indented = tabs.indent().indent();
+    assert_eq!(
+        trunc_fmt!(999; const_panic::PanicVal::write_indentation(indented)),
+        "\t\t"
+    );
+}
+
+#[test]
+fn max_indentation_formatting() {
+    use const_panic::PanicVal;
+
+    let array = [3, 5, 8, 13];
+
+    let foo = Foo {
+        x: &array,
+        y: 21,
+        z: Bar(false, true),
+        w: Baz,
+        a: Qux::Up,
+        b: Qux::Down { x: 21, y: 34 },
+        c: Qux::Left(55),
+    };
+
+    // the lines that `{:#?}` indents by more than 4 spaces are only indented by 4
+    let std_output = format!("{:#?}", foo)
+        .lines()
+        .map(|line| {
+            let content = line.trim_start_matches(' ');
+            let indentation = (line.len() - content.len()).min(4);
+            format!("{}{}", " ".repeat(indentation), content)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let fmt = FmtArg::ALT_DEBUG.set_max_indentation(4);
+    assert_eq!(trunc_fmt!(999; fmt; foo), *std_output);
+    assert_eq!(fmt.pack().unpack(), fmt);
+
+    // 100 levels of nesting
+    for (max, expected_indentation) in [(u8::MAX, 252u8), (64, 64), (16, 16), (0, 0)] {
+        let fmt = FmtArg::ALT_DEBUG.set_max_indentation(max);
+        let mut nested = fmt;
+        for _ in 0..100 {
+            nested = nested.indent();
+        }
+        assert_eq!(nested.indentation, expected_indentation, "{}", max);
+        assert_eq!(
+            trunc_fmt!(999; PanicVal::write_indentation(nested)),
+            *" ".repeat(expected_indentation.into())
+        );
+        // the levels of nesting that didn't add indentation are stored as at most one level
+        let unpacked = nested.pack().unpack();
+        assert_eq!(unpacked.indentation, nested.indentation);
+        assert_eq!(
+            unpacked.unindent().indentation,
+            nested.unindent().indentation
+        );
+
+        for _ in 0..100 {
+            nested = nested.unindent();
+        }
+        assert_eq!(nested, fmt);
+    }
+}
+
 #[derive(Debug)]
 struct Foo<'a> {
     x: &'a [u8],
@@ -343,3 +511,126 @@ const_panic::impl_panicfmt! {
         }]:]
     )
 }
+
+#[test]
+fn pinned_fmtarg_field_formatting() {
+    let entry = Entry {
+        key: Key("k\ney"),
+        hash: Hash(0xFEED),
+        count: 10,
+    };
+
+    assert_eq!(
+        trunc_fmt!(999; FmtArg::DEBUG; entry),
+        r#"Entry { key: "k\ney", hash: 0xFEED, count: 10 }"#
+    );
+    assert_eq!(
+        trunc_fmt!(999; FmtArg::DISPLAY; entry),
+        r#"Entry { key: "k\ney", hash: 0xFEED, count: 10 }"#
+    );
+    assert_eq!(
+        trunc_fmt!(999; FmtArg::BIN; entry),
+        r#"Entry { key: "k\ney", hash: 0xFEED, count: 1010 }"#
+    );
+    assert_eq!(
+        trunc_fmt!(999; FmtArg::ALT_DEBUG; entry),
+        concat!(
+            "Entry {\n",
+            "    key: \"k\\ney\",\n",
+            "    hash: 0xFEED,\n",
+            "    count: 10,\n",
+            "}",
+        )
+    );
+}
+
+struct Entry {
+    key: Key,
+    hash: Hash,
+    count: u32,
+}
+
+const_panic::impl_panicfmt! {
+    struct Entry {
+        key: Key,
+        hash: Hash,
+        count: u32,
+    }
+}
+
+// always `Debug` formatted
+struct Key(&'static str);
+
+impl const_panic::PanicFmt for Key {
+    type This = Self;
+    type Kind = const_panic::IsCustomType;
+    const PV_COUNT: usize = 1;
+}
+
+impl Key {
+    const fn to_panicvals(&self, f: FmtArg) -> [const_panic::PanicVal<'static>; 1] {
+        [const_panic::PanicVal::from_str(self.0, f.set_debug())]
+    }
+}
+
+// always formatted as `0x`-prefixed hexadecimal
+struct Hash(u64);
+
+impl const_panic::PanicFmt for Hash {
+    type This = Self;
+    type Kind = const_panic::IsCustomType;
+    const PV_COUNT: usize = 1;
+}
+
+impl Hash {
+    const fn to_panicvals(&self, f: FmtArg) -> [const_panic::PanicVal<'static>; 1] {
+        [const_panic::PanicVal::from_u64(
+            self.0,
+            f.set_hex().set_alternate(true),
+        )]
+    }
+}
+
+#[test]
+fn unit_panicfmt_macro() {
+    struct Marker;
+    struct Renamed;
+    struct Sized<const N: usize>;
+
+    const_panic::impl_unit_panicfmt!(Marker);
+    const_panic::impl_unit_panicfmt!(Renamed, "<renamed>",);
+    const_panic::impl_unit_panicfmt!(Sized<4>, "Sized");
+
+    for fmt in [
+        FmtArg::DEBUG,
+        FmtArg::ALT_DEBUG,
+        FmtArg::DISPLAY,
+        FmtArg::ALT_HEX,
+    ] {
+        assert_eq!(trunc_fmt!(999; fmt; Marker), "Marker");
+        assert_eq!(trunc_fmt!(999; fmt; Renamed), "<renamed>");
+        assert_eq!(trunc_fmt!(999; fmt; Sized::<4>), "Sized");
+    }
+
+    assert_eq!(<Marker as const_panic::PanicFmt>::PV_COUNT, 1);
+
+    struct Holder {
+        marker: Marker,
+        x: u8,
+    }
+    const_panic::impl_panicfmt! {
+        struct Holder {
+            marker: Marker,
+            x: u8,
+        }
+    }
+
+    let holder = Holder {
+        marker: Marker,
+        x: 3,
+    };
+    assert_eq!(
+        trunc_fmt!(999; FmtArg::DEBUG; holder),
+        "Holder { marker: Marker, x: 3 }"
+    );
+}
@@ -4,7 +4,7 @@ use core::num::{
     NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
 };
 
-use const_panic::FmtArg;
+use const_panic::{FmtArg, PanicVal};
 
 fn tn_of_val<T>(_: T) -> &'static str {
     std::any::type_name::<T>()
@@ -23,6 +23,12 @@ macro_rules! test_case {
             (FmtArg::ALT_HEX, format!("{:#X}", int)),
             (FmtArg::BIN, format!("{:b}", int)),
             (FmtArg::ALT_BIN, format!("{:#b}", int)),
+            (FmtArg::DEBUG.set_plus_sign(true), format!("{:+?}", int)),
+            (FmtArg::DISPLAY.set_plus_sign(true), format!("{:+}", int)),
+            (FmtArg::HEX.set_plus_sign(true), format!("{:+X}", int)),
+            (FmtArg::ALT_HEX.set_plus_sign(true), format!("{:+#X}", int)),
+            (FmtArg::BIN.set_plus_sign(true), format!("{:+b}", int)),
+            (FmtArg::ALT_BIN.set_plus_sign(true), format!("{:+#b}", int)),
         ] {
             let msg = || format!(
                 "string.len(): {} num: {:?} fmt_override: {:?} type: {}",
@@ -102,3 +108,684 @@ fn nonzero_integer_test() {
     test_case! {NonZeroUsize::new(55).unwrap()}
     test_case! {NonZeroIsize::new(-55).unwrap()}
 }
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn nonzero_hex_test() {
+    const FF: NonZeroU8 = match NonZeroU8::new(255) {
+        Some(x) => x,
+        None => panic!(),
+    };
+
+    assert_eq!(trunc_fmt!(2; FmtArg::HEX; FF), "FF");
+    assert_eq!(trunc_fmt!(4; FmtArg::ALT_HEX; FF), "0xFF");
+    const PLUS_HEX: FmtArg = FmtArg::HEX.set_plus_sign(true);
+    assert_eq!(trunc_fmt!(3; PLUS_HEX; FF), "+FF");
+
+    let min = NonZeroI8::new(i8::MIN).unwrap();
+    assert_eq!(trunc_fmt!(2; FmtArg::HEX; min), "80");
+    assert_eq!(trunc_fmt!(10; FmtArg::ALT_BIN; min), "0b10000000");
+
+    let big = NonZeroU128::new(u128::MAX).unwrap();
+    assert_eq!(trunc_fmt!(34; FmtArg::ALT_HEX; big), *format!("{:#X}", big));
+}
+
+// Tests aren't so thorough, since `Wrapping` and `Saturating` just delegate to the wrapped integer.
+#[cfg(feature = "non_basic")]
+#[test]
+fn num_wrapper_integer_test() {
+    use core::num::Wrapping;
+
+    test_case! {Wrapping(5u8)}
+    test_case! {Wrapping(-5i8)}
+    test_case! {Wrapping(8u16)}
+    test_case! {Wrapping(-8i16)}
+    test_case! {Wrapping(13u32)}
+    test_case! {Wrapping(-13i32)}
+    test_case! {Wrapping(21u64)}
+    test_case! {Wrapping(-21i64)}
+    test_case! {Wrapping(u128::MAX)}
+    test_case! {Wrapping(i128::MIN)}
+    test_case! {Wrapping(55usize)}
+    test_case! {Wrapping(-55isize)}
+
+    #[cfg(feature = "rust_1_74")]
+    {
+        use core::num::Saturating;
+
+        test_case! {Saturating(5u8)}
+        test_case! {Saturating(-5i8)}
+        test_case! {Saturating(8u16)}
+        test_case! {Saturating(-8i16)}
+        test_case! {Saturating(13u32)}
+        test_case! {Saturating(-13i32)}
+        test_case! {Saturating(21u64)}
+        test_case! {Saturating(-21i64)}
+        test_case! {Saturating(u128::MAX)}
+        test_case! {Saturating(i128::MIN)}
+        test_case! {Saturating(55usize)}
+        test_case! {Saturating(-55isize)}
+    }
+}
+
+// inserts `_` separators between every `group_len` digits of a `core::fmt` formatted integer
+fn group_digits(string: &str, group_len: usize) -> String {
+    let digits_start = string
+        .find(|c: char| c.is_ascii_hexdigit())
+        .map(|i| match &string[i..] {
+            x if x.starts_with("0x") || x.starts_with("0b") => i + 2,
+            _ => i,
+        })
+        .unwrap();
+    let (prefix, digits) = string.split_at(digits_start);
+
+    let mut out = prefix.to_string();
+    for (i, c) in digits.chars().enumerate() {
+        if i != 0 && (digits.len() - i) % group_len == 0 {
+            out.push('_');
+        }
+        out.push(c);
+    }
+    out
+}
+
+macro_rules! grouping_test_case {
+    ($num:expr) => {
+        let int = $num;
+
+        for (fmt, string) in [
+            (FmtArg::DEBUG, group_digits(&format!("{:?}", int), 3)),
+            (FmtArg::DISPLAY, group_digits(&format!("{}", int), 3)),
+            (FmtArg::HEX, group_digits(&format!("{:X}", int), 4)),
+            (FmtArg::ALT_HEX, group_digits(&format!("{:#X}", int), 4)),
+            (FmtArg::BIN, group_digits(&format!("{:b}", int), 4)),
+            (FmtArg::ALT_BIN, group_digits(&format!("{:#b}", int), 4)),
+            (
+                FmtArg::DEBUG.set_plus_sign(true),
+                group_digits(&format!("{:+?}", int), 3),
+            ),
+            (
+                FmtArg::ALT_BIN.set_plus_sign(true),
+                group_digits(&format!("{:+#b}", int), 4),
+            ),
+        ] {
+            let fmt = fmt.set_digit_grouping(true);
+            let msg = || format!("num: {:?} fmt_override: {:?}", int, fmt);
+
+            assert_eq!(trunc_fmt!(string.len(); fmt; int), *string, "{}", msg());
+            assert_eq!(trunc_fmt!(string.len() - 1; fmt; int), "", "{}", msg());
+        }
+    };
+}
+
+#[test]
+fn digit_grouping_test() {
+    for int in [0u32, 1, 12, 123, 1234, 12345, 999_999, 1_000_000, u32::MAX] {
+        grouping_test_case! {int}
+    }
+    for int in [0i64, -1, -999, -1000, -123_456_789, i64::MIN, i64::MAX] {
+        grouping_test_case! {int}
+    }
+    grouping_test_case! {u128::MAX}
+    grouping_test_case! {i128::MIN}
+
+    let (million, neg_thousand, hex, neg_one) = (1_000_000u32, -1_000i16, 0x12345u32, -1i16);
+
+    const DEC: FmtArg = FmtArg::DEBUG.set_digit_grouping(true);
+    assert_eq!(trunc_fmt!(16; DEC; million), "1_000_000");
+    assert_eq!(trunc_fmt!(16; DEC; neg_thousand), "-1_000");
+
+    const ALT_HEX: FmtArg = FmtArg::ALT_HEX.set_digit_grouping(true);
+    assert_eq!(trunc_fmt!(16; ALT_HEX; hex), "0x1_2345");
+    assert_eq!(trunc_fmt!(16; ALT_HEX; neg_one), "0xFFFF");
+
+    // alternate binary of a 128 bit integer, with a plus sign and digit grouping,
+    // is the longest integer that can be formatted
+    const ALT_BIN: FmtArg = FmtArg::ALT_BIN.set_digit_grouping(true).set_plus_sign(true);
+    assert_eq!(trunc_fmt!(255; ALT_BIN; u128::MAX).as_str().len(), 162);
+    assert_eq!(trunc_fmt!(161; ALT_BIN; u128::MAX), "");
+}
+
+// Digit grouping is passed through to the integer wrapped by these types.
+#[cfg(feature = "non_basic")]
+#[test]
+fn digit_grouping_wrapper_test() {
+    use core::num::Wrapping;
+
+    const DEC: FmtArg = FmtArg::DEBUG.set_digit_grouping(true);
+    const HEX: FmtArg = FmtArg::ALT_HEX.set_digit_grouping(true);
+
+    assert_eq!(trunc_fmt!(16; DEC; Wrapping(1000000u32)), "1_000_000");
+    assert_eq!(trunc_fmt!(16; HEX; Wrapping(0xABCDEFu32)), "0xAB_CDEF");
+    assert_eq!(trunc_fmt!(16; HEX; Wrapping(-2i16)), "0xFFFE");
+
+    let nonzero = NonZeroU64::new(1 << 40).unwrap();
+    assert_eq!(trunc_fmt!(32; DEC; nonzero), "1_099_511_627_776");
+    assert_eq!(trunc_fmt!(32; HEX; nonzero), "0x100_0000_0000");
+
+    #[cfg(feature = "rust_1_74")]
+    {
+        use core::num::Saturating;
+
+        assert_eq!(trunc_fmt!(16; DEC; Saturating(-1234567i32)), "-1_234_567");
+        assert_eq!(trunc_fmt!(16; HEX; Saturating(u32::MAX)), "0xFFFF_FFFF");
+    }
+
+    let array = [1000u16, 10];
+    assert_eq!(trunc_fmt!(64; DEC; &array), "[1_000, 10]");
+}
+
+// The extremes fill the largest integer buffer exactly,
+// so they're tested separately from the rest.
+#[test]
+fn integer_extremes_test() {
+    test_case! {i128::MIN}
+    test_case! {i128::MAX}
+    test_case! {u128::MAX}
+    test_case! {i64::MIN}
+    test_case! {u64::MAX}
+
+    assert_eq!(
+        trunc_fmt!(130; FmtArg::ALT_BIN; u128::MAX).as_str().len(),
+        130
+    );
+    assert_eq!(
+        trunc_fmt!(130; FmtArg::ALT_BIN; i128::MIN).as_str().len(),
+        130
+    );
+    assert_eq!(
+        trunc_fmt!(40; FmtArg::DEBUG; i128::MIN),
+        "-170141183460469231731687303715884105728"
+    );
+    assert_eq!(
+        trunc_fmt!(34; FmtArg::ALT_HEX; i128::MIN),
+        "0x80000000000000000000000000000000"
+    );
+
+    // the sign is written right before the most significant digit,
+    // these are the longest decimal integers, at 40 bytes.
+    const PLUS: FmtArg = FmtArg::DEBUG.set_plus_sign(true);
+    for (fmt, int, expected) in [
+        (
+            FmtArg::DEBUG,
+            i128::MAX,
+            "170141183460469231731687303715884105727",
+        ),
+        (PLUS, i128::MAX, "+170141183460469231731687303715884105727"),
+        (PLUS, i128::MIN, "-170141183460469231731687303715884105728"),
+    ] {
+        assert_eq!(trunc_fmt!(40; fmt; int), expected);
+        assert_eq!(trunc_fmt!(expected.len() - 1; fmt; int), "");
+    }
+    assert_eq!(
+        trunc_fmt!(40; FmtArg::DEBUG; u128::MAX),
+        "340282366920938463463374607431768211455"
+    );
+    assert_eq!(
+        trunc_fmt!(40; PLUS; u128::MAX),
+        "+340282366920938463463374607431768211455"
+    );
+
+    for pv in [
+        PanicVal::from_i128(i128::MIN, FmtArg::DEBUG),
+        PanicVal::from_i128(i128::MAX, PLUS),
+        PanicVal::from_u128(u128::MAX, PLUS),
+    ] {
+        let msg = std::panic::catch_unwind(|| const_panic::concat_panic(&[&[pv]]))
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap();
+        assert_eq!(msg.trim_end_matches('\0').len(), 40);
+    }
+}
+
+#[test]
+fn radix_test() {
+    fn to_radix(mut n: u128, radix: u32) -> String {
+        let mut digits = Vec::new();
+        loop {
+            digits.push(
+                std::char::from_digit((n % radix as u128) as u32, radix)
+                    .unwrap()
+                    .to_ascii_uppercase(),
+            );
+            n /= radix as u128;
+            if n == 0 {
+                break;
+            }
+        }
+        digits.iter().rev().collect()
+    }
+
+    assert_eq!(
+        trunc_fmt!(1; PanicVal::from_u128_radix(35, 36, FmtArg::DEBUG)),
+        "Z"
+    );
+    assert_eq!(
+        trunc_fmt!(2; PanicVal::from_u128_radix(36, 36, FmtArg::DEBUG)),
+        "10"
+    );
+
+    let iter = (0u128..40)
+        .chain(std::iter::successors(Some(1u128), |n| n.checked_mul(7)))
+        .chain([u64::MAX as u128, u128::MAX - 1, u128::MAX]);
+
+    for n in iter {
+        for radix in 2..=36 {
+            let fmts = [
+                FmtArg::DEBUG,
+                FmtArg::ALT_HEX,
+                FmtArg::BIN.set_plus_sign(true),
+            ];
+            for fmt in fmts {
+                let mut expected = to_radix(n, radix);
+                if fmt.plus_sign() {
+                    expected.insert(0, '+');
+                }
+
+                let pv = PanicVal::from_u128_radix(n, radix, fmt);
+                assert_eq!(trunc_fmt!(expected.len(); pv), *expected);
+                assert_eq!(trunc_fmt!(expected.len() - 1; pv), "");
+            }
+        }
+    }
+
+    assert_eq!(
+        trunc_fmt!(1024; PanicVal::from_u128_radix(255, 16, FmtArg::DEBUG)),
+        *format!("{:X}", 255)
+    );
+
+    // same digit case as `FmtArg::HEX`
+    for n in [10u128, 0xFF, 0xDEAD_BEEF, u128::MAX] {
+        let hex = FmtArg::HEX;
+        assert_eq!(
+            trunc_fmt!(1024; PanicVal::from_u128_radix(n, 16, FmtArg::DEBUG)),
+            trunc_fmt!(1024; hex; n).as_str(),
+        );
+    }
+    assert_eq!(
+        trunc_fmt!(1024; PanicVal::from_u128_radix(0o777, 8, FmtArg::DEBUG)),
+        *format!("{:o}", 0o777)
+    );
+}
+
+#[test]
+fn radix_out_of_range_test() {
+    for radix in [0, 1, 37, 100, u32::MAX] {
+        let msg = std::panic::catch_unwind(|| PanicVal::from_u128_radix(10, radix, FmtArg::DEBUG))
+            .err()
+            .unwrap()
+            .downcast::<String>()
+            .unwrap();
+
+        assert_eq!(
+            msg.trim_end_matches('\0'),
+            format!("radix must be in the 2..=36 range, found: {}", radix),
+        );
+    }
+}
+
+#[test]
+fn dual_radix_test() {
+    use const_panic::fmt::NumberFmt;
+
+    macro_rules! fmt_pvs {
+        ($pvs:expr) => {
+            trunc_fmt!(1024; const_panic::StdWrapper(&$pvs).deref_panic_vals())
+        };
+    }
+
+    assert_eq!(
+        fmt_pvs!(PanicVal::from_u8_dual_radix(
+            255,
+            NumberFmt::Hexadecimal,
+            FmtArg::DEBUG
+        )),
+        "255 (0xFF)"
+    );
+    assert_eq!(
+        fmt_pvs!(PanicVal::from_u16_dual_radix(
+            5,
+            NumberFmt::Binary,
+            FmtArg::DEBUG
+        )),
+        "5 (0b101)"
+    );
+    assert_eq!(
+        fmt_pvs!(PanicVal::from_i8_dual_radix(
+            -1,
+            NumberFmt::Hexadecimal,
+            FmtArg::DEBUG
+        )),
+        "-1 (0xFF)"
+    );
+    assert_eq!(
+        fmt_pvs!(PanicVal::from_i32_dual_radix(
+            -2,
+            NumberFmt::Hexadecimal,
+            FmtArg::DEBUG
+        )),
+        "-2 (0xFFFFFFFE)"
+    );
+    assert_eq!(
+        fmt_pvs!(PanicVal::from_u64_dual_radix(
+            0x1F,
+            NumberFmt::Binary,
+            FmtArg::HEX
+        )),
+        "1F (0b11111)"
+    );
+    assert_eq!(
+        fmt_pvs!(PanicVal::from_usize_dual_radix(
+            3,
+            NumberFmt::Decimal,
+            FmtArg::DEBUG.set_plus_sign(true)
+        )),
+        "+3 (+3)"
+    );
+
+    for n in [0u128, 1, 255, u128::MAX] {
+        assert_eq!(
+            fmt_pvs!(PanicVal::from_u128_dual_radix(
+                n,
+                NumberFmt::Hexadecimal,
+                FmtArg::DEBUG
+            )),
+            *format!("{} ({:#X})", n, n)
+        );
+    }
+}
+
+#[test]
+fn u8_with_ascii_test() {
+    macro_rules! fmt_pvs {
+        ($pvs:expr) => {
+            trunc_fmt!(1024; const_panic::StdWrapper(&$pvs).deref_panic_vals())
+        };
+    }
+
+    for byte in 0..=u8::MAX {
+        // written out instead of using std's `Debug`,
+        // because std escaped NUL as `'\u{0}'` before Rust 1.61.
+        let expected = match byte {
+            b'\0' => r"0 ('\0')".to_string(),
+            b'\t' => r"9 ('\t')".to_string(),
+            b'\n' => r"10 ('\n')".to_string(),
+            b'\r' => r"13 ('\r')".to_string(),
+            b'\'' => r"39 ('\'')".to_string(),
+            b'\\' => r"92 ('\\')".to_string(),
+            0..=0x1F | 0x7F => format!("{0} ('\\u{{{0:x}}}')", byte),
+            0x20..=0x7E => format!("{} ('{}')", byte, byte as char),
+            _ => byte.to_string(),
+        };
+        assert_eq!(
+            fmt_pvs!(PanicVal::from_u8_with_ascii(byte, FmtArg::DEBUG)),
+            *expected
+        );
+    }
+
+    assert_eq!(
+        fmt_pvs!(PanicVal::from_u8_with_ascii(b'z', FmtArg::ALT_BIN)),
+        "0b1111010 ('z')"
+    );
+    assert_eq!(
+        fmt_pvs!(PanicVal::from_u8_with_ascii(0, FmtArg::DISPLAY)),
+        r"0 ('\0')"
+    );
+    assert_eq!(
+        fmt_pvs!(PanicVal::from_u8_with_ascii(0x7F, FmtArg::HEX)),
+        r"7F ('\u{7f}')"
+    );
+}
+
+// formats `int` as a `-` followed by its magnitude, with the `core::fmt` specifier in `$fmt`.
+macro_rules! signed_fmt {
+    ($fmt:literal, $int:expr) => {
+        match $int {
+            int if int < 0 => format!(concat!("-", $fmt), int.unsigned_abs()),
+            int => format!($fmt, int),
+        }
+    };
+}
+
+macro_rules! signed_hex_bin_test_case {
+    ($num:expr) => {
+        let int = $num;
+
+        for (fmt, string) in [
+            (FmtArg::DEBUG, format!("{:?}", int)),
+            (FmtArg::HEX, signed_fmt!("{:X}", int)),
+            (FmtArg::ALT_HEX, signed_fmt!("{:#X}", int)),
+            (FmtArg::BIN, signed_fmt!("{:b}", int)),
+            (FmtArg::ALT_BIN, signed_fmt!("{:#b}", int)),
+            (
+                FmtArg::ALT_HEX.set_plus_sign(true),
+                match int {
+                    int if int < 0 => signed_fmt!("{:#X}", int),
+                    int => format!("{:+#X}", int),
+                },
+            ),
+        ] {
+            let fmt = fmt.set_signed_hex_bin(true);
+            let msg = || format!("num: {:?} fmt_override: {:?}", int, fmt);
+
+            assert_eq!(trunc_fmt!(string.len(); fmt; int), *string, "{}", msg());
+            assert_eq!(trunc_fmt!(string.len() - 1; fmt; int), "", "{}", msg());
+        }
+    };
+}
+
+#[test]
+fn signed_hex_bin_test() {
+    for int in [0i8, 1, -1, 15, -16, i8::MIN, i8::MAX] {
+        signed_hex_bin_test_case! {int}
+    }
+    for int in [0i32, -1, -255, 256, i32::MIN, i32::MAX] {
+        signed_hex_bin_test_case! {int}
+    }
+    for int in [0i128, -1, i128::MIN, i128::MAX] {
+        signed_hex_bin_test_case! {int}
+    }
+    for int in [0isize, -1, isize::MIN] {
+        signed_hex_bin_test_case! {int}
+    }
+
+    // unsigned integers are unaffected
+    const HEX: FmtArg = FmtArg::HEX.set_signed_hex_bin(true);
+    assert_eq!(trunc_fmt!(8; HEX; u8::MAX), "FF");
+    assert_eq!(trunc_fmt!(64; HEX; u128::MAX), *format!("{:X}", u128::MAX));
+
+    // the default is the two's complement bit pattern of the type
+    let neg_one = -1i16;
+    assert_eq!(trunc_fmt!(8; FmtArg::HEX; neg_one), "FFFF");
+    assert_eq!(trunc_fmt!(8; HEX; neg_one), "-1");
+
+    const GROUPED: FmtArg = FmtArg::ALT_BIN
+        .set_signed_hex_bin(true)
+        .set_digit_grouping(true);
+    let neg_32 = -32i32;
+    assert_eq!(trunc_fmt!(16; GROUPED; neg_32), "-0b10_0000");
+
+    // the longest signed integer
+    let min = i128::MIN;
+    assert_eq!(trunc_fmt!(255; GROUPED; min).as_str().len(), 162);
+
+    assert_eq!(trunc_fmt!(16; PanicVal::from_i64(-255, HEX)), "-FF");
+}
+
+#[test]
+fn hex_padded_test() {
+    macro_rules! hex_padded_case {
+        ($ctor:ident, $ty:ty, $digits:expr) => {
+            for int in [0, 1, 0xF, 0x10, <$ty>::MAX / 3, <$ty>::MAX - 1, <$ty>::MAX] {
+                let ungrouped = format!("{:#0width$X}", int, width = $digits + 2);
+                let grouped = format!("0x{}", group_digits(&ungrouped[2..], 4));
+
+                assert_eq!(trunc_fmt!(64; PanicVal::$ctor(int, false)), *ungrouped);
+                assert_eq!(trunc_fmt!(64; PanicVal::$ctor(int, true)), *grouped);
+
+                assert_eq!(
+                    trunc_fmt!(ungrouped.len() - 1; PanicVal::$ctor(int, false)),
+                    ""
+                );
+            }
+        };
+    }
+
+    hex_padded_case! {from_u8_hex_padded, u8, 2}
+    hex_padded_case! {from_u16_hex_padded, u16, 4}
+    hex_padded_case! {from_u32_hex_padded, u32, 8}
+    hex_padded_case! {from_u64_hex_padded, u64, 16}
+
+    assert_eq!(
+        trunc_fmt!(64; PanicVal::from_u32_hex_padded(0xFF, true)),
+        "0x0000_00FF"
+    );
+    assert_eq!(
+        trunc_fmt!(64; PanicVal::from_u64_hex_padded(u64::MAX, true)),
+        "0xFFFF_FFFF_FFFF_FFFF"
+    );
+}
+
+#[test]
+fn signed_hex_padded_test() {
+    macro_rules! signed_hex_padded_case {
+        ($ctor:ident, $ty:ty) => {
+            for bits in 1..=<$ty>::BITS {
+                let min = -1i128 << (bits - 1);
+                let digits = (bits as usize + 3) / 4;
+
+                let ints = [min, min + 1, -1, 0, 1, !min - 1, !min];
+                for int in ints.into_iter().filter(|&x| min <= x && x <= !min) {
+                    let pattern = (int as u128) & ((1u128 << bits) - 1);
+                    let ungrouped = format!("{:#0width$X}", pattern, width = digits + 2);
+                    let grouped = format!("0x{}", group_digits(&ungrouped[2..], 4));
+
+                    let int = int as $ty;
+                    assert_eq!(trunc_fmt!(64; PanicVal::$ctor(int, bits, false)), *ungrouped);
+                    assert_eq!(trunc_fmt!(64; PanicVal::$ctor(int, bits, true)), *grouped);
+                }
+            }
+        };
+    }
+
+    signed_hex_padded_case! {from_i8_hex_padded, i8}
+    signed_hex_padded_case! {from_i16_hex_padded, i16}
+    signed_hex_padded_case! {from_i32_hex_padded, i32}
+    signed_hex_padded_case! {from_i64_hex_padded, i64}
+
+    assert_eq!(
+        trunc_fmt!(64; PanicVal::from_i32_hex_padded(-1, 32, true)),
+        "0xFFFF_FFFF"
+    );
+    assert_eq!(
+        trunc_fmt!(64; PanicVal::from_i16_hex_padded(-2, 12, false)),
+        "0xFFE"
+    );
+    assert_eq!(
+        trunc_fmt!(64; PanicVal::from_i64_hex_padded(0xFF, 40, true)),
+        "0x00_0000_00FF"
+    );
+}
+
+#[test]
+fn signed_hex_padded_panics_test() {
+    fn panic_message(f: impl FnOnce() -> PanicVal<'static> + std::panic::UnwindSafe) -> String {
+        let msg = std::panic::catch_unwind(f)
+            .err()
+            .unwrap()
+            .downcast::<String>()
+            .unwrap();
+        msg.trim_end_matches('\0').to_string()
+    }
+
+    assert_eq!(
+        panic_message(|| PanicVal::from_i8_hex_padded(0, 0, false)),
+        "`bits` must be in the 1..=8 range, found: 0"
+    );
+    assert_eq!(
+        panic_message(|| PanicVal::from_i32_hex_padded(0, 33, false)),
+        "`bits` must be in the 1..=32 range, found: 33"
+    );
+    assert_eq!(
+        panic_message(|| PanicVal::from_i16_hex_padded(2048, 12, false)),
+        "2048 doesn't fit in a 12-bit signed integer"
+    );
+    assert_eq!(
+        panic_message(|| PanicVal::from_i64_hex_padded(-129, 8, false)),
+        "-129 doesn't fit in a 8-bit signed integer"
+    );
+}
+
+#[test]
+fn radix_group_len_test() {
+    let (beef, dead, small, neg_small) = (0xDEADBEEFu32, 0xDEADu32, 0x1_2345i32, -0x1_2345i32);
+    let (bits, byte, neg_one, thousand) = (0b1010_0101_1111u16, 0b0101_1111u8, -1i16, 1000u32);
+
+    assert_eq!(FmtArg::DEBUG.radix_group_len(), 4);
+
+    const HEX: FmtArg = FmtArg::ALT_HEX
+        .set_digit_grouping(true)
+        .set_radix_group_len(4);
+    assert_eq!(trunc_fmt!(16; HEX; beef), "0xDEAD_BEEF");
+    assert_eq!(trunc_fmt!(16; HEX; dead), "0xDEAD");
+    assert_eq!(trunc_fmt!(16; HEX; small), "0x1_2345");
+    assert_eq!(trunc_fmt!(16; HEX; neg_one), "0xFFFF");
+    let (non_alt, plus, signed) = (
+        HEX.set_alternate(false),
+        HEX.set_plus_sign(true),
+        HEX.set_signed_hex_bin(true),
+    );
+    assert_eq!(trunc_fmt!(16; non_alt; beef), "DEAD_BEEF");
+    assert_eq!(trunc_fmt!(16; plus; beef), "+0xDEAD_BEEF");
+    assert_eq!(trunc_fmt!(16; signed; neg_small), "-0x1_2345");
+    assert_eq!(trunc_fmt!(10; HEX; beef), "");
+
+    const BIN8: FmtArg = FmtArg::ALT_BIN
+        .set_digit_grouping(true)
+        .set_radix_group_len(8);
+    assert_eq!(trunc_fmt!(32; BIN8; bits), "0b1010_01011111");
+    assert_eq!(trunc_fmt!(32; BIN8; byte), "0b1011111");
+    assert_eq!(trunc_fmt!(32; BIN8; neg_one), "0b11111111_11111111");
+
+    // the digits aren't grouped unless `digit_grouping` is enabled
+    let ungrouped = BIN8.set_digit_grouping(false);
+    assert_eq!(trunc_fmt!(32; ungrouped; bits), "0b101001011111");
+
+    // decimal integers are always grouped in 3 digits
+    let dec = FmtArg::DEBUG
+        .set_digit_grouping(true)
+        .set_radix_group_len(8);
+    assert_eq!(trunc_fmt!(16; dec; thousand), "1_000");
+
+    const BIN4: FmtArg = FmtArg::ALT_BIN
+        .set_digit_grouping(true)
+        .set_radix_group_len(4)
+        .set_plus_sign(true);
+    assert_eq!(trunc_fmt!(16; BIN4; byte), "+0b101_1111");
+
+    let max = u128::MAX;
+    assert_eq!(trunc_fmt!(255; BIN4; max).as_str().len(), 162);
+
+    #[cfg(feature = "non_basic")]
+    for len in [4u8, 8, 63] {
+        let fmt = BIN4.set_radix_group_len(len);
+        assert_eq!(fmt.pack().unpack(), fmt);
+    }
+
+    for len in [0u8, 1, 3, 64, 255] {
+        let msg = std::panic::catch_unwind(|| FmtArg::ALT_BIN.set_radix_group_len(len))
+            .err()
+            .unwrap()
+            .downcast::<String>()
+            .unwrap();
+
+        assert_eq!(
+            msg.trim_end_matches('\0'),
+            format!(
+                "`radix_group_len` must be in the 4..=63 range, found: {}",
+                len
+            ),
+        );
+    }
+}
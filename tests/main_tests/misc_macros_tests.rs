@@ -143,4 +143,157 @@ fn set_fmt_from_kw_test() {
         fmt.is_alternate = true;
         fmt.number_fmt = NumberFmt::Binary;
     }}
+
+    case! {plus, inita, |fmt| {
+        fmt = fmt.set_plus_sign(true);
+    }}
+    case! {plus, initd, |fmt| {
+        fmt = fmt.set_plus_sign(true);
+    }}
+}
+
+#[test]
+fn trunc_fmt_separator_test() {
+    let (a, b) = (3u8, "hi");
+    let c = 'c';
+
+    assert_eq!(trunc_fmt!(sep = ", "; 256; a, b, c), "3, \"hi\", 'c'");
+    assert_eq!(trunc_fmt!(sep = ", "; 256; a, b,), "3, \"hi\"");
+    assert_eq!(trunc_fmt!(sep = ", "; 256; a), "3");
+    assert_eq!(
+        trunc_fmt!(sep = " | "; 256; FmtArg::DISPLAY; a, b, hex: 255u8),
+        "3 | hi | FF"
+    );
+
+    // the separator counts towards the capacity
+    assert_eq!(trunc_fmt!(sep = ", "; 4; a, c), "3, ");
+
+    // the form without a separator still works
+    assert_eq!(trunc_fmt!(256; a, b), "3\"hi\"");
+}
+
+#[test]
+fn type_name_test() {
+    use const_panic::{fmt::type_name, PanicVal};
+
+    fn name_of<T: ?Sized>() -> String {
+        let mut buffer = [0u8; 128];
+        let len = const_panic::format_into(&mut buffer, &[&[type_name::<T>()]]).unwrap();
+        String::from_utf8(buffer[..len].to_vec()).unwrap()
+    }
+
+    assert_eq!(name_of::<u8>(), core::any::type_name::<u8>());
+    assert_eq!(name_of::<str>(), "str");
+    assert_eq!(name_of::<[u16]>(), "[u16]");
+    assert_eq!(
+        name_of::<Option<u32>>(),
+        core::any::type_name::<Option<u32>>()
+    );
+
+    let pvs: &[PanicVal<'_>] = &[PanicVal::write_str("type: "), type_name::<bool>()];
+    assert_eq!(trunc_fmt!(99; pvs), "type: bool");
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn custom_kind_dispatch_test() {
+    use const_panic::{coerce_fmt, IsCustomType, PanicFmt, PanicVal};
+
+    // wraps a std type, formatting it differently than the std type
+    struct Quoted<'a>(&'a str);
+
+    impl PanicFmt for Quoted<'_> {
+        type This = Self;
+        type Kind = IsCustomType;
+        const PV_COUNT: usize = 3;
+    }
+
+    impl<'a> Quoted<'a> {
+        const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'a>; 3] {
+            [
+                PanicVal::write_str("<<"),
+                PanicVal::from_str(self.0, f.set_display()),
+                PanicVal::write_str(">>"),
+            ]
+        }
+    }
+
+    let quoted = Quoted("foo\n");
+
+    for fmtarg in [FmtArg::DEBUG, FmtArg::ALT_DEBUG, FmtArg::DISPLAY] {
+        assert_eq!(trunc_fmt!(99; fmtarg; quoted), "<<foo\n>>");
+        assert_eq!(trunc_fmt!(99; fmtarg; &&quoted), "<<foo\n>>");
+    }
+
+    // `coerce_fmt` returns the reference as-is for `IsCustomType` types
+    let coerced: &Quoted<'_> = coerce_fmt!(&&&quoted);
+    assert_eq!(trunc_fmt!(99; coerced.0), "\"foo\\n\"");
+
+    let coerced: &Quoted<'_> = <Quoted<'_> as PanicFmt>::PROOF.coerce(&quoted);
+    assert_eq!(
+        const_panic::ArrayString::<99>::from_panicvals(&coerced.to_panicvals(FmtArg::DEBUG))
+            .unwrap(),
+        "<<foo\n>>",
+    );
+
+    assert_eq!(
+        const_panic::ArrayString::<99>::from_panicvals(&const_panic::flatten_panicvals!(
+            FmtArg::DEBUG;
+            Quoted => quoted,
+            "|",
+            &Quoted => &quoted,
+        ))
+        .unwrap(),
+        "<<foo\n>>|<<foo\n>>",
+    );
+}
+
+#[test]
+fn just_err_test() {
+    use const_panic::{just_err, PanicVal};
+
+    struct NotFormattable;
+
+    const fn check(n: u32) -> Result<NotFormattable, u32> {
+        if n < 10 {
+            Ok(NotFormattable)
+        } else {
+            Err(n)
+        }
+    }
+
+    const ERR: [PanicVal<'static>; 1] = just_err!(FmtArg::DISPLAY; check(100));
+    const OK: [PanicVal<'static>; 1] = just_err!(FmtArg::DISPLAY; check(3));
+
+    assert_eq!(trunc_fmt!(64; ERR[0]), "100");
+    assert_eq!(trunc_fmt!(64; OK[0]), "<no error>");
+
+    let hex = just_err!(FmtArg::HEX; check(255));
+    assert_eq!(trunc_fmt!(64; hex[0]), "FF");
+
+    let res: Result<u8, &str> = Err("oh\nno");
+    assert_eq!(trunc_fmt!(64; just_err!(FmtArg::DISPLAY; res)[0]), "oh\nno");
+    assert_eq!(
+        trunc_fmt!(64; just_err!(FmtArg::DEBUG; res,)[0]),
+        r#""oh\nno""#
+    );
+
+    // the placeholder is padded to the `PV_COUNT` of the error type
+    #[cfg(feature = "non_basic")]
+    {
+        let res: Result<(), Option<u8>> = Ok(());
+        let pvs = just_err!(FmtArg::DEBUG; res);
+        assert_eq!(pvs.len(), 5);
+        assert_eq!(format_pvs(&pvs), "<no error>");
+
+        let res: Result<(), Option<u8>> = Err(Some(3));
+        assert_eq!(format_pvs(&just_err!(FmtArg::DEBUG; res)), "Some(3)");
+    }
+
+    #[cfg(feature = "non_basic")]
+    fn format_pvs(pvs: &[PanicVal<'_>]) -> String {
+        let mut buffer = [0u8; 64];
+        let len = const_panic::format_into(&mut buffer, &[pvs]).unwrap();
+        String::from_utf8(buffer[..len].to_vec()).unwrap()
+    }
 }
@@ -102,3 +102,113 @@ fn test_option_fmt() {
         )
     }
 }
+
+#[test]
+fn test_option_alt_fmt() {
+    macro_rules! test_case {
+        ($expr:expr, $fmt:expr, $expected:expr) => {
+            assert_eq!(trunc_fmt!(1024; $fmt; $expr), $expected);
+        };
+    }
+
+    test_case! {Some(5u8), FmtArg::ALT_DEBUG, *format!("{:#?}", Some(5u8))}
+    test_case! {Some("foo"), FmtArg::ALT_DEBUG, *format!("{:#?}", Some("foo"))}
+    test_case! {Some(true), FmtArg::ALT_DEBUG, *format!("{:#?}", Some(true))}
+    test_case! {Some([3u8, 5]), FmtArg::ALT_DEBUG, *format!("{:#?}", Some([3u8, 5]))}
+    test_case! {NonZeroU8::new(5), FmtArg::ALT_DEBUG, *format!("{:#?}", NonZeroU8::new(5))}
+    test_case! {None::<u8>, FmtArg::ALT_DEBUG, "None"}
+
+    let compact = FmtArg::ALT_DEBUG.set_compact_option(true);
+
+    test_case! {Some(5u8), compact, "Some(5)"}
+    test_case! {Some(true), compact, "Some(true)"}
+    test_case! {Some("foo"), compact, "Some(\"foo\")"}
+    test_case! {NonZeroU8::new(5), compact, "Some(5)"}
+    test_case! {None::<u8>, compact, "None"}
+    test_case! {Some(-3i8), compact.set_hex(), "Some(0xFD)"}
+    test_case! {Some([3u8, 5]), compact, "Some([\n    3,\n    5,\n])"}
+    test_case! {Some(["foo"]), compact.indent(), "Some([\n        \"foo\",\n    ])"}
+
+    // non-alternate formatting is unaffected by the flag
+    test_case! {Some([3u8, 5]), FmtArg::DEBUG.set_compact_option(true), "Some([3, 5])"}
+
+    // `compact_option` doesn't affect slice elements, so it's not stored
+    assert_eq!(compact.pack().unpack(), FmtArg::ALT_DEBUG);
+}
+
+#[test]
+fn test_option_bool_yes_no_fmt() {
+    macro_rules! test_case {
+        ($expr:expr, $fmt:expr, $expected:expr) => {
+            assert_eq!(trunc_fmt!(1024; $fmt; $expr), $expected);
+        };
+    }
+
+    let yes_no = FmtArg::DEBUG.set_yes_no(true);
+    let alt_yes_no = FmtArg::ALT_DEBUG.set_yes_no(true);
+
+    test_case! {true, yes_no, "yes"}
+    test_case! {false, yes_no, "no"}
+    test_case! {Some(true), yes_no, "yes"}
+    test_case! {Some(false), yes_no, "no"}
+    test_case! {None::<bool>, yes_no, "unset"}
+    test_case! {Some(true), alt_yes_no, "yes"}
+    test_case! {None::<bool>, alt_yes_no, "unset"}
+
+    test_case! {Some(true), FmtArg::ALT_DEBUG, "Some(\n    true,\n)"}
+    test_case! {None::<bool>, FmtArg::DEBUG, "None"}
+
+    let slice: &[Option<bool>] = &[Some(true), None, Some(false)];
+    test_case! {slice, yes_no, "[yes, unset, no]"}
+    test_case! {slice, FmtArg::DEBUG, "[Some(true), None, Some(false)]"}
+    test_case! {
+        slice,
+        alt_yes_no,
+        "[\n    yes,\n    unset,\n    no,\n]"
+    }
+    test_case! {
+        slice,
+        FmtArg::ALT_DEBUG,
+        "[\n    Some(true),\n    None,\n    Some(false),\n]"
+    }
+    test_case! {Some([true, false]), yes_no, "Some([yes, no])"}
+}
+
+#[test]
+fn test_transparent_option_fmt() {
+    macro_rules! test_case {
+        ($expr:expr, $fmt:expr, $expected:expr) => {
+            assert_eq!(trunc_fmt!(1024; $fmt; $expr), $expected);
+        };
+    }
+
+    let transparent = FmtArg::DISPLAY.set_transparent_option(true);
+
+    test_case! {Some("hello"), transparent, "hello"}
+    test_case! {None::<&str>, transparent, ""}
+    test_case! {Some(3u8), transparent, "3"}
+    test_case! {Some(-3i64), transparent, "-3"}
+    test_case! {None::<u32>, transparent, ""}
+    test_case! {Some(true), transparent, "true"}
+    test_case! {None::<bool>, transparent, ""}
+    test_case! {Some([3u8, 5]), transparent, "[3, 5]"}
+    test_case! {NonZeroU8::new(5), transparent, "5"}
+    test_case! {NonZeroU8::new(0), transparent, ""}
+    test_case! {Some(Ordering::Less), transparent, "Less"}
+
+    let mut transparent_hex = transparent;
+    transparent_hex.number_fmt = const_panic::fmt::NumberFmt::Hexadecimal;
+    test_case! {Some(-3i8), transparent_hex, "FD"}
+
+    // yes_no formatting takes precedence
+    test_case! {None::<bool>, transparent.set_yes_no(true), "unset"}
+
+    // Debug formatting is unaffected by the flag
+    let debug = FmtArg::DEBUG.set_transparent_option(true);
+    test_case! {Some("hello"), debug, "Some(\"hello\")"}
+    test_case! {None::<u8>, debug, "None"}
+    test_case! {Some(false), debug, "Some(false)"}
+
+    // `transparent_option` doesn't affect slice elements, so it's not stored
+    assert_eq!(transparent.pack().unpack(), FmtArg::DISPLAY);
+}
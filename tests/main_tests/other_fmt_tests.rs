@@ -1,8 +1,12 @@
-use const_panic::{FmtArg, StdWrapper};
+use const_panic::{FmtArg, PanicVal, StdWrapper};
 
 use core::{
+    array::TryFromSliceError,
+    char::CharTryFromError,
     cmp::Ordering,
+    convert::TryFrom,
     marker::{PhantomData, PhantomPinned},
+    num::{FpCategory, TryFromIntError},
     ptr::NonNull,
     sync::atomic::Ordering as AtomicOrdering,
 };
@@ -36,14 +40,124 @@ fn fmt_pointer() {
     test_val! {NonNull::<[u8]>::from(&mut [][..]), "<pointer>"}
 }
 
+#[test]
+fn fmt_address() {
+    const WIDTH: usize = core::mem::size_of::<usize>() * 2 + 2;
+
+    for addr in [
+        0,
+        1,
+        0xF,
+        0x10,
+        0x2A0,
+        0xDEAD_BEEF,
+        usize::MAX / 3,
+        usize::MAX,
+    ] {
+        let expected = format!("{:#0w$x}", addr, w = WIDTH);
+        assert_eq!(trunc_fmt!(1024; PanicVal::from_address(addr)), *expected);
+        assert_eq!(
+            trunc_fmt!(1024; PanicVal::from_ptr(addr as *const u8)),
+            *expected
+        );
+        assert_eq!(
+            trunc_fmt!(1024; PanicVal::from_ptr(addr as *mut u8)),
+            *expected
+        );
+
+        assert_eq!(trunc_fmt!(WIDTH - 1; PanicVal::from_address(addr)), "");
+    }
+
+    let arr = [3u8, 5, 8];
+    assert_eq!(
+        trunc_fmt!(1024; PanicVal::from_ptr(&arr[..] as *const [u8])),
+        *format!("{:#0w$x}", arr.as_ptr() as usize, w = WIDTH),
+    );
+}
+
+#[test]
+fn fmt_c_void_ptr() {
+    use core::ffi::c_void;
+
+    const WIDTH: usize = core::mem::size_of::<usize>() * 2 + 2;
+
+    let mut value = 100u32;
+    let ptr = &mut value as *mut u32 as *mut c_void;
+    assert!(!ptr.is_null());
+
+    let expected = format!("{:#0w$x}", ptr as usize, w = WIDTH);
+    assert_eq!(trunc_fmt!(1024; PanicVal::from_ptr(ptr)), *expected);
+    assert_eq!(
+        trunc_fmt!(1024; PanicVal::from_ptr(ptr as *const c_void)),
+        *expected
+    );
+
+    // the const-compatible `PanicFmt` impl can't print the address
+    assert_eq!(trunc_fmt!(1024; ptr), "<pointer>");
+}
+
+#[test]
+fn fmt_non_null_address() {
+    const WIDTH: usize = core::mem::size_of::<usize>() * 2 + 2;
+
+    let mut value = 100u32;
+    let ptr = NonNull::from(&mut value);
+
+    let expected = format!("{:#0w$x}", ptr.as_ptr() as usize, w = WIDTH);
+    assert_eq!(trunc_fmt!(1024; PanicVal::from_non_null(ptr)), *expected);
+    assert_eq!(
+        trunc_fmt!(1024; PanicVal::from_non_null(ptr.cast::<u8>())),
+        *expected
+    );
+
+    let slice = NonNull::<[u8]>::from(&mut [3u8, 5][..]);
+    assert_eq!(
+        trunc_fmt!(1024; PanicVal::from_non_null(slice)),
+        *format!("{:#0w$x}", slice.as_ptr() as *mut u8 as usize, w = WIDTH)
+    );
+
+    // the const-compatible `PanicFmt` impl can't print the address
+    assert_eq!(trunc_fmt!(1024; StdWrapper(&ptr)), "<pointer>");
+}
+
 #[test]
 fn fmt_units() {
     test_val! {PhantomData::<u8>, "PhantomData"}
     test_val! {PhantomData::<str>, "PhantomData"}
+    test_val! {PhantomData::<[u8]>, "PhantomData"}
+    test_val! {PhantomData::<dyn Fn()>, "PhantomData"}
+
+    // Display and Debug formatting are the same for `PhantomData`
+    assert_eq!(
+        trunc_fmt!(1024; FmtArg::DISPLAY; PhantomData::<u8>),
+        "PhantomData"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; FmtArg::ALT_DISPLAY; PhantomData::<u8>),
+        "PhantomData"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; FmtArg::ALT_DEBUG; PhantomData::<u8>),
+        "PhantomData"
+    );
+    assert_eq!(<PhantomData<str> as const_panic::PanicFmt>::PV_COUNT, 1);
+    assert_eq!(<PhantomData<u128> as const_panic::PanicFmt>::PV_COUNT, 1);
 
     test_val! {PhantomPinned, "PhantomPinned"}
 
     test_val! {(), "()"}
+
+    // Display and Debug formatting are the same for `()`
+    for fmtarg in [
+        FmtArg::DISPLAY,
+        FmtArg::ALT_DISPLAY,
+        FmtArg::DEBUG,
+        FmtArg::ALT_DEBUG,
+    ] {
+        assert_eq!(trunc_fmt!(1024; fmtarg; ()), "()");
+        assert_eq!(trunc_fmt!(1024; StdWrapper(&()).to_panicvals(fmtarg)), "()");
+    }
+    assert_eq!(<() as const_panic::PanicFmt>::PV_COUNT, 1);
 }
 
 #[test]
@@ -52,6 +166,29 @@ fn fmt_orderings() {
     test_val! {Ordering::Equal, "Equal"}
     test_val! {Ordering::Greater, "Greater"}
 
+    for ord in [Ordering::Less, Ordering::Equal, Ordering::Greater] {
+        // Display, Debug, and their alternate forms are all the same as std's `Debug`
+        let expected = format!("{:?}", ord);
+        for fmt in [
+            FmtArg::DISPLAY,
+            FmtArg::ALT_DISPLAY,
+            FmtArg::DEBUG,
+            FmtArg::ALT_DEBUG,
+        ] {
+            assert_eq!(
+                trunc_fmt!(1024; StdWrapper(&ord).to_panicvals(fmt)),
+                *expected
+            );
+        }
+
+        let msg = std::panic::catch_unwind(|| const_panic::concat_panic!("cmp result: ", ord))
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap();
+        assert_eq!(msg.trim_end_matches('\0'), format!("cmp result: {:?}", ord));
+    }
+    assert_eq!(<Ordering as const_panic::PanicFmt>::PV_COUNT, 1);
+
     test_val! {AtomicOrdering::Relaxed, "Relaxed"}
     test_val! {AtomicOrdering::Release, "Release"}
     test_val! {AtomicOrdering::Acquire, "Acquire"}
@@ -59,12 +196,440 @@ fn fmt_orderings() {
     test_val! {AtomicOrdering::SeqCst, "SeqCst"}
 }
 
+#[test]
+fn fmt_fp_category() {
+    for (float, category) in [
+        (f64::NAN, FpCategory::Nan),
+        (f64::INFINITY, FpCategory::Infinite),
+        (-0.0, FpCategory::Zero),
+        (f64::MIN_POSITIVE / 2.0, FpCategory::Subnormal),
+        (1.5, FpCategory::Normal),
+    ] {
+        assert_eq!(float.classify(), category);
+
+        let expected = format!("{:?}", category);
+        test_val! {category, *expected}
+
+        // Display, Debug, and their alternate forms are all the same as std's `Debug`
+        for fmt in [
+            FmtArg::DISPLAY,
+            FmtArg::ALT_DISPLAY,
+            FmtArg::DEBUG,
+            FmtArg::ALT_DEBUG,
+        ] {
+            assert_eq!(
+                trunc_fmt!(1024; StdWrapper(&category).to_panicvals(fmt)),
+                *expected
+            );
+        }
+
+        assert_eq!(
+            trunc_fmt!(1024; StdWrapper(&Some(category))),
+            *format!("Some({:?})", category)
+        );
+    }
+    assert_eq!(<FpCategory as const_panic::PanicFmt>::PV_COUNT, 1);
+
+    let (expected, found) = (FpCategory::Normal, FpCategory::Subnormal);
+    assert_eq!(
+        trunc_fmt!(1024; "expected ", expected, ", got ", found),
+        "expected Normal, got Subnormal"
+    );
+}
+
 #[test]
 fn fmt_range() {
-    test_vals! {3..5, "3..5"}
-    test_vals! {3.., "3.."}
+    test_vals! {3usize..5, "3..5"}
+    test_vals! {3usize.., "3.."}
     test_vals! {.., ".."}
-    test_vals! {3..=5, "3..=5"}
-    test_vals! {..5, "..5"}
-    test_vals! {..=5, "..=5"}
+    test_vals! {3usize..=5, "3..=5"}
+    test_vals! {..5usize, "..5"}
+    test_vals! {..=5usize, "..=5"}
+}
+
+#[test]
+fn fmt_range_elem_types() {
+    macro_rules! range_case {
+        ($range:expr) => {{
+            let range = $range;
+            assert_eq!(trunc_fmt!(1024; range), *format!("{:?}", range));
+            assert_eq!(trunc_fmt!(1024; StdWrapper(&range)), *format!("{:?}", range));
+            assert_eq!(
+                trunc_fmt!(1024; FmtArg::ALT_DEBUG; StdWrapper(&range)),
+                *format!("{:#?}", range),
+            );
+        }};
+    }
+
+    range_case! {2u8..8}
+    range_case! {2u16..=8}
+    range_case! {2u32..}
+    range_case! {..8u64}
+    range_case! {..=u128::MAX}
+    range_case! {-8i8..-2}
+    range_case! {-8i16..=2}
+    range_case! {2i32..=8}
+    range_case! {i32::MIN..}
+    range_case! {..-8i64}
+    range_case! {..=i128::MIN}
+    range_case! {-3isize..3}
+    range_case! {'a'..'z'}
+    range_case! {'a'..}
+    range_case! {..'z'}
+    range_case! {..='z'}
+    range_case! {'\n'..='\''}
+
+    let hex = 10u32..=255;
+    assert_eq!(
+        trunc_fmt!(1024; FmtArg::ALT_HEX; StdWrapper(&hex)),
+        "0xA..=0xFF"
+    );
+
+    assert_eq!(<core::ops::Range<u8> as const_panic::PanicFmt>::PV_COUNT, 3);
+    assert_eq!(
+        <core::ops::RangeInclusive<char> as const_panic::PanicFmt>::PV_COUNT,
+        3
+    );
+    assert_eq!(
+        <core::ops::RangeTo<char> as const_panic::PanicFmt>::PV_COUNT,
+        2
+    );
+    assert_eq!(
+        <core::ops::Range<Option<u8>> as const_panic::PanicFmt>::PV_COUNT,
+        11
+    );
+    assert_eq!(
+        <core::ops::RangeFrom<core::ops::Range<u8>> as const_panic::PanicFmt>::PV_COUNT,
+        4
+    );
+}
+
+#[test]
+fn fmt_reverse() {
+    use core::cmp::Reverse;
+
+    macro_rules! reverse_case {
+        ($value:expr) => {{
+            let rev = Reverse($value);
+            assert_eq!(trunc_fmt!(1024; StdWrapper(&rev)), *format!("{:?}", rev));
+            assert_eq!(
+                trunc_fmt!(1024; FmtArg::ALT_DEBUG; StdWrapper(&rev)),
+                *format!("{:#?}", rev),
+            );
+            assert_eq!(
+                trunc_fmt!(1024; FmtArg::DISPLAY; StdWrapper(&rev)),
+                *format!("{}", rev.0),
+            );
+        }};
+    }
+
+    reverse_case! {123u8}
+    reverse_case! {-5i16}
+    reverse_case! {u128::MAX}
+    reverse_case! {i64::MIN}
+    reverse_case! {true}
+    reverse_case! {'\n'}
+    reverse_case! {"hello\tworld"}
+
+    let hex = Reverse(255u32);
+    assert_eq!(
+        trunc_fmt!(1024; FmtArg::HEX; StdWrapper(&hex)),
+        "Reverse(FF)"
+    );
+
+    assert_eq!(<Reverse<u8> as const_panic::PanicFmt>::PV_COUNT, 5);
+    assert_eq!(<Reverse<&str> as const_panic::PanicFmt>::PV_COUNT, 5);
+}
+
+#[test]
+fn fmt_poll() {
+    use core::task::Poll;
+
+    macro_rules! poll_case {
+        ($value:expr) => {{
+            let poll = $value;
+            assert_eq!(trunc_fmt!(1024; StdWrapper(&poll)), *format!("{:?}", poll));
+            assert_eq!(
+                trunc_fmt!(1024; FmtArg::ALT_DEBUG; StdWrapper(&poll)),
+                *format!("{:#?}", poll),
+            );
+        }};
+    }
+
+    poll_case! {Poll::Ready(123u8)}
+    poll_case! {Poll::<u8>::Pending}
+    poll_case! {Poll::Ready(-5i16)}
+    poll_case! {Poll::Ready(u128::MAX)}
+    poll_case! {Poll::Ready(i64::MIN)}
+    poll_case! {Poll::Ready(false)}
+    poll_case! {Poll::Ready('\n')}
+    poll_case! {Poll::Ready("hello\tworld")}
+    poll_case! {Poll::<&str>::Pending}
+
+    assert_eq!(
+        trunc_fmt!(1024; FmtArg::HEX; StdWrapper(&Poll::Ready(255u32))),
+        "Ready(FF)"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; FmtArg::DISPLAY; StdWrapper(&Poll::Ready("foo"))),
+        "Ready(foo)"
+    );
+
+    assert_eq!(<Poll<u8> as const_panic::PanicFmt>::PV_COUNT, 5);
+    assert_eq!(<Poll<&str> as const_panic::PanicFmt>::PV_COUNT, 5);
+    assert_eq!(
+        StdWrapper(&Poll::Ready(true))
+            .to_panicvals(FmtArg::DEBUG)
+            .len(),
+        <Poll<bool> as const_panic::PanicFmt>::PV_COUNT,
+    );
+}
+
+#[test]
+fn fmt_flags() {
+    use const_panic::{fmt, ArrayString};
+
+    const FLAGS: &[(u128, &str)] = &[
+        (0b0001, "A"),
+        (0b0100, "C"),
+        (0b0010, "B"),
+        (0, "NEVER"),
+        (0b0110, "B_AND_C"),
+    ];
+
+    fn fmt_flags<const LEN: usize>(value: u128, f: FmtArg) -> String {
+        let pvs: [PanicVal<'_>; LEN] = fmt::flags(FLAGS, value, f);
+        ArrayString::<256>::from_panicvals(&pvs)
+            .unwrap()
+            .to_str()
+            .to_string()
+    }
+
+    for f in [FmtArg::DEBUG, FmtArg::ALT_DEBUG, FmtArg::DISPLAY] {
+        assert_eq!(fmt_flags::<10>(0, f), "(empty)");
+        assert_eq!(fmt_flags::<10>(0b1000, f), "(empty)");
+        assert_eq!(fmt_flags::<10>(0b0001, f), "A");
+        assert_eq!(fmt_flags::<10>(0b0011, f), "A | B");
+        // written in declaration order, not bit order
+        assert_eq!(fmt_flags::<10>(0b0111, f), "A | C | B | B_AND_C");
+        assert_eq!(fmt_flags::<10>(0b1110, f), "C | B | B_AND_C");
+    }
+
+    // the exact amount of `PanicVal`s required
+    assert_eq!(fmt_flags::<1>(0, FmtArg::DEBUG), "(empty)");
+    assert_eq!(fmt_flags::<1>(0b0100, FmtArg::DEBUG), "C");
+    assert_eq!(fmt_flags::<3>(0b0101, FmtArg::DEBUG), "A | C");
+
+    std::panic::catch_unwind(|| fmt_flags::<0>(0, FmtArg::DEBUG)).unwrap_err();
+    std::panic::catch_unwind(|| fmt_flags::<2>(0b0101, FmtArg::DEBUG)).unwrap_err();
+}
+
+#[test]
+fn digit_buffer_test() {
+    use const_panic::{fmt::DigitBuffer, NotEnoughSpace};
+
+    let empty = DigitBuffer::new();
+    assert!(empty.is_empty());
+    assert_eq!(trunc_fmt!(64; empty), "");
+
+    let mut buffer = DigitBuffer::new();
+    for &b in b"-12.5e3" {
+        buffer = buffer.push(b);
+    }
+    assert_eq!(buffer.len(), 7);
+    assert_eq!(buffer.as_bytes(), b"-12.5e3");
+    assert_eq!(trunc_fmt!(64; buffer), "-12.5e3");
+    assert_eq!(
+        trunc_fmt!(64; FmtArg::ALT_DEBUG; [buffer.to_panicval()]),
+        "-12.5e3"
+    );
+    assert_eq!(trunc_fmt!(6; buffer), "");
+
+    let mut full = DigitBuffer::new();
+    for i in 0..DigitBuffer::CAPACITY {
+        full = full.try_push(b'0' + (i % 10) as u8).unwrap();
+    }
+    assert!(matches!(full.try_push(b'0'), Err(NotEnoughSpace)));
+    assert_eq!(trunc_fmt!(64; full), "012345678901234567890");
+
+    let msg = std::panic::catch_unwind(|| full.push(b'0'))
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    assert_eq!(
+        msg.trim_end_matches('\0'),
+        format!(
+            "DigitBuffer is full, its capacity is {}",
+            DigitBuffer::CAPACITY
+        )
+    );
+
+    let msg = std::panic::catch_unwind(|| DigitBuffer::new().push(0xC3))
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    assert_eq!(
+        msg.trim_end_matches('\0'),
+        "expected an ASCII byte, found: 195"
+    );
+}
+
+#[cfg(feature = "rust_1_74")]
+#[test]
+fn fmt_cstr() {
+    use core::ffi::CStr;
+
+    macro_rules! cstr_case {
+        ($bytes:expr, $debug:expr, $display:expr) => {{
+            let cstr: &CStr = CStr::from_bytes_with_nul($bytes).unwrap();
+            assert_eq!(trunc_fmt!(1024; cstr), $debug);
+            assert_eq!(trunc_fmt!(1024; FmtArg::DISPLAY; cstr), $display);
+            assert_eq!(
+                trunc_fmt!(1024; StdWrapper(cstr).to_panicvals(FmtArg::DEBUG)),
+                $debug
+            );
+            assert_eq!(
+                trunc_fmt!(1024; StdWrapper(cstr).to_panicvals(FmtArg::DISPLAY)),
+                $display
+            );
+        }};
+    }
+
+    cstr_case! {b"\0", r#""""#, ""}
+    cstr_case! {"hi\n\"ñ\0".as_bytes(), r#""hi\n\"ñ""#, "hi\n\"ñ"}
+    cstr_case! {b"a\xFFb\0", r#"b"a\xffb""#, r#"a\xffb"#}
+    cstr_case! {b"\"\n\xC3\0", r#"b"\"\n\xc3""#, "\"\n\\xc3"}
+
+    let not_utf8 = CStr::from_bytes_with_nul(b"a\xFFb\0").unwrap();
+    assert_eq!(
+        trunc_fmt!(1024; FmtArg::ALT_DEBUG; not_utf8),
+        r#"b"a\xffb""#
+    );
+
+    assert_eq!(<CStr as const_panic::PanicFmt>::PV_COUNT, 3);
+}
+
+#[test]
+fn fmt_infallible() {
+    use const_panic::PanicFmt;
+    use core::convert::Infallible;
+
+    assert_eq!(<Infallible as PanicFmt>::PV_COUNT, 1);
+
+    // `Infallible` can't be constructed, so only the `to_panicvals` signatures are checked
+    const fn _to_panicvals(this: &Infallible) -> [PanicVal<'static>; 1] {
+        StdWrapper(this).to_panicvals(FmtArg::DEBUG)
+    }
+    const fn _to_panicval(this: &Infallible) -> PanicVal<'static> {
+        StdWrapper(this).to_panicval(FmtArg::DEBUG)
+    }
+}
+
+#[test]
+fn is_last_from_bool_test() {
+    use const_panic::fmt::IsLast;
+
+    assert!(IsLast::from_bool(true) == IsLast::Yes);
+    assert!(IsLast::from_bool(false) == IsLast::No);
+
+    let elems = [3u8, 5, 8];
+    for (fmt, expected) in [
+        (FmtArg::DEBUG, "3| 5| 8"),
+        (FmtArg::ALT_DEBUG.indent(), "3|\n    5|\n    8|\n"),
+    ] {
+        let mut pvs = Vec::new();
+        for (i, elem) in elems.iter().enumerate() {
+            let is_last = IsLast::from_bool(i + 1 == elems.len());
+            pvs.push(PanicVal::from_u8(*elem, fmt));
+            pvs.push(PanicVal::from_element_separator("|", is_last, fmt));
+        }
+        let pvs: &[PanicVal<'_>] = &pvs;
+        assert_eq!(trunc_fmt!(99; pvs), expected);
+    }
+}
+
+#[test]
+fn fmt_unit_errors() {
+    macro_rules! test_error {
+        ($error:expr) => {{
+            let err = $error;
+            for (fmt, expected) in [
+                (FmtArg::DISPLAY, format!("{}", err)),
+                (FmtArg::ALT_DISPLAY, format!("{:#}", err)),
+                (FmtArg::DEBUG, format!("{:?}", err)),
+                (FmtArg::ALT_DEBUG, format!("{:#?}", err)),
+            ] {
+                assert_eq!(
+                    trunc_fmt!(1024; StdWrapper(&err).to_panicvals(fmt)),
+                    *expected
+                );
+            }
+        }};
+    }
+
+    let int_err: TryFromIntError = u8::try_from(300u32).unwrap_err();
+    test_error! {int_err}
+    assert_eq!(
+        trunc_fmt!(1024; StdWrapper(&int_err)),
+        "TryFromIntError(())"
+    );
+
+    let slice_err: TryFromSliceError = <[u8; 2]>::try_from(&[3u8][..]).unwrap_err();
+    test_error! {slice_err}
+
+    let char_err: CharTryFromError = char::try_from(0xD800u32).unwrap_err();
+    test_error! {char_err}
+}
+
+#[test]
+fn fmt_control_flow() {
+    use core::ops::ControlFlow;
+
+    macro_rules! test_flow {
+        ($value:expr) => {{
+            let flow = $value;
+            for (fmt, expected) in [
+                (FmtArg::DEBUG, format!("{:?}", flow)),
+                (FmtArg::ALT_DEBUG, format!("{:#?}", flow)),
+            ] {
+                assert_eq!(
+                    trunc_fmt!(1024; StdWrapper(&flow).to_panicvals(fmt)),
+                    *expected
+                );
+            }
+            assert_eq!(trunc_fmt!(1024; StdWrapper(&flow)), *format!("{:?}", flow));
+        }};
+    }
+
+    test_flow! {ControlFlow::<u8, ()>::Break(3)}
+    test_flow! {ControlFlow::<u8, ()>::Continue(())}
+    test_flow! {ControlFlow::<(), i64>::Continue(-5)}
+    test_flow! {ControlFlow::<(), i64>::Break(())}
+    test_flow! {ControlFlow::<&str, ()>::Break("fo\no")}
+    test_flow! {ControlFlow::<(), char>::Continue('\'')}
+    test_flow! {ControlFlow::<(), ()>::Continue(())}
+    test_flow! {ControlFlow::<(), ()>::Break(())}
+
+    // the wrapped value is formatted with the passed-in `FmtArg`
+    let flow = ControlFlow::<&str, ()>::Break("fo\no");
+    assert_eq!(
+        trunc_fmt!(1024; StdWrapper(&flow).to_panicvals(FmtArg::DISPLAY)),
+        "Break(fo\no)"
+    );
+    let flow = ControlFlow::<(), u8>::Continue(255);
+    assert_eq!(
+        trunc_fmt!(1024; StdWrapper(&flow).to_panicvals(FmtArg::HEX)),
+        "Continue(FF)"
+    );
+
+    assert_eq!(<ControlFlow<u8, ()> as const_panic::PanicFmt>::PV_COUNT, 5);
+    assert_eq!(
+        <ControlFlow<(), &str> as const_panic::PanicFmt>::PV_COUNT,
+        5
+    );
+    assert_eq!(<ControlFlow<(), ()> as const_panic::PanicFmt>::PV_COUNT, 5);
+    assert_eq!(
+        <ControlFlow<Option<u8>, [u8; 3]> as const_panic::PanicFmt>::PV_COUNT,
+        9
+    );
 }
@@ -122,6 +122,128 @@ fn flatten_panicvals_args_test() {
     );
 }
 
+#[cfg(feature = "non_basic")]
+#[test]
+fn flatten_panicvals_conditional_test() {
+    use const_panic::{fmt, PanicVal};
+
+    const fn with_cond(cond: bool, nested: bool) -> [PanicVal<'static>; 7] {
+        const_panic::flatten_panicvals!(FmtArg::DEBUG;
+            "a",
+            if cond => ("b: ", 3u8, [u8; 2] => [5, 8]),
+            if !cond => (display: "c"),
+            if cond => (if nested => ("d", debug: "e"),),
+        )
+    }
+
+    assert_eq!(
+        const_panic::ArrayString::<64>::from_panicvals(&with_cond(true, true)).unwrap(),
+        r#"ab: 3[5, 8]d"e""#
+    );
+    assert_eq!(
+        const_panic::ArrayString::<64>::from_panicvals(&with_cond(true, false)).unwrap(),
+        "ab: 3[5, 8]"
+    );
+    assert_eq!(
+        const_panic::ArrayString::<64>::from_panicvals(&with_cond(false, true)).unwrap(),
+        "ac"
+    );
+
+    // the grouped arguments always take up the same amount of `PanicVal`s
+    let pvs = with_cond(false, false);
+    assert_eq!(
+        const_panic::ArrayString::<64>::from_panicvals(&pvs[1..4]).unwrap(),
+        ""
+    );
+    assert_eq!(
+        const_panic::ArrayString::<64>::from_panicvals(&pvs[5..]).unwrap(),
+        ""
+    );
+
+    // `if` expressions are still usable as arguments
+    let flag = true;
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; if flag { "yes" } else { "no" }, 10u8),
+        r#""yes"10"#
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; if !flag || flag == (1 == 1) => ("a"), "x"),
+        "ax"
+    );
+    assert_eq!(fmt_flatten!(FmtArg::DEBUG; if flag => (), "x"), "x");
+
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG;
+            "Foo",
+            open: fmt::OpenBrace,
+                if flag => ("x: ", 3u8, fmt::COMMA_TERM),
+            close: fmt::CloseBrace,
+        ),
+        "Foo {\n    x: 3,\n}"
+    );
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn flatten_panicvals_padding_test() {
+    use const_panic::PanicVal;
+
+    const WIDTH: u8 = 6;
+    let string = "foo";
+
+    assert_eq!(fmt_flatten!(FmtArg::DEBUG; width = 5: 13u8, "|"), "   13|");
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; debug width = 7: string),
+        r#"  "foo""#
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; display width = 5 align = left: string, "|"),
+        "foo  |"
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; align = right width = WIDTH: "ab", "|"),
+        "    ab|"
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; {#X} width = 6: 255u8, "|"),
+        "  0xFF|"
+    );
+
+    // values that are longer than the width aren't truncated
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; width = 2: 12345u16, "|"),
+        "12345|"
+    );
+    assert_eq!(fmt_flatten!(FmtArg::DEBUG; width = 0: 3u8), "3");
+
+    // the whole output of multi-`PanicVal` types is padded
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; [u8; 2] => width = 8: [3, 5], "|"),
+        "  [3, 5]|"
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; &str => debug width = 6 align = left: "a", "|"),
+        r#""a"   |"#
+    );
+
+    // padding takes up one extra `PanicVal`
+    const fn padded(cond: bool) -> [PanicVal<'static>; 6] {
+        const_panic::flatten_panicvals!(FmtArg::DEBUG;
+            width = 3: 1u8,
+            if cond => (debug width = 4: "b", "c"),
+            "d",
+        )
+    }
+    assert_eq!(
+        const_panic::ArrayString::<64>::from_panicvals(&padded(true)).unwrap(),
+        r#"  1 "b"cd"#
+    );
+    assert_eq!(
+        const_panic::ArrayString::<64>::from_panicvals(&padded(false)).unwrap(),
+        "  1d"
+    );
+}
+
 #[cfg(feature = "non_basic")]
 #[test]
 fn struct_formatting() {
@@ -176,6 +298,81 @@ lo,
     );
 }
 
+#[cfg(feature = "non_basic")]
+#[test]
+fn array_of_structs_test() {
+    macro_rules! fmt_foos {
+        ($($args:tt)*) => (
+            const_panic::ArrayString::<1024>::from_panicvals(
+                &const_panic::flatten_panicvals!($($args)*)
+            ).unwrap()
+        )
+    }
+
+    const FOOS: [Foo<'static>; 2] = [
+        Foo {
+            x: &[3, 5],
+            y: 8,
+            z: Bar(false, true),
+            w: Baz { h: &["hi"] },
+        },
+        Foo {
+            x: &[],
+            y: 13,
+            z: Bar(true, false),
+            w: Baz { h: &["hel\nlo"] },
+        },
+    ];
+    const NO_FOOS: [Foo<'static>; 0] = [];
+
+    for fmtarg in [FmtArg::DEBUG, FmtArg::ALT_DEBUG] {
+        let expected = if fmtarg.is_alternate {
+            format!("{:#?}", FOOS)
+        } else {
+            format!("{:?}", FOOS)
+        };
+
+        assert_eq!(fmt_foos!(fmtarg; array(Foo; 2) => FOOS), *expected);
+        assert_eq!(fmt_foos!(fmtarg; array(Foo; 2) => &FOOS), *expected);
+        assert_eq!(fmt_foos!(fmtarg; array(Foo; 0) => NO_FOOS), "[]");
+    }
+
+    // nested in another value's delimiters
+    assert_eq!(
+        fmt_foos!(FmtArg::ALT_DEBUG;
+            "Qux",
+            open: const_panic::fmt::OpenParen,
+                array(Foo; 2) => FOOS,
+                const_panic::fmt::COMMA_TERM,
+            close: const_panic::fmt::CloseParen,
+        ),
+        *format!("{:#?}", Qux(FOOS))
+    );
+
+    // the array takes up the same amount of `PanicVal`s when it's conditionally formatted
+    const fn cond_foos(
+        cond: bool,
+    ) -> [const_panic::PanicVal<'static>; 2 * (<Foo as const_panic::PanicFmt>::PV_COUNT + 1) + 3]
+    {
+        const_panic::flatten_panicvals!(FmtArg::DEBUG;
+            if cond => (array(Foo; 2) => FOOS),
+            "|",
+        )
+    }
+    assert_eq!(
+        const_panic::ArrayString::<512>::from_panicvals(&cond_foos(true)).unwrap(),
+        *format!("{:?}|", FOOS)
+    );
+    assert_eq!(
+        const_panic::ArrayString::<512>::from_panicvals(&cond_foos(false)).unwrap(),
+        "|"
+    );
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Qux([Foo<'static>; 2]);
+}
+
 #[cfg(feature = "non_basic")]
 #[derive(Debug)]
 struct Foo<'a> {
@@ -296,6 +493,36 @@ fn enum_formatting() {
     }
 }
 
+#[cfg(feature = "non_basic")]
+#[test]
+fn enum_empty_padding_test() {
+    use const_panic::{test_utils::check_empty_padding, PanicVal};
+
+    for fmtarg in [FmtArg::DEBUG, FmtArg::ALT_DEBUG] {
+        for val in [Qux::<u8>::Up, Qux::Down { x: 21, y: 34 }, Qux::Left(55)] {
+            let pvs = val.to_panicvals(fmtarg);
+            assert_eq!(check_empty_padding(&pvs), Ok(()));
+        }
+    }
+
+    let padded = [PanicVal::write_str("a"), PanicVal::EMPTY, PanicVal::EMPTY];
+    assert_eq!(check_empty_padding(&padded), Ok(()));
+    assert_eq!(check_empty_padding(&[]), Ok(()));
+
+    let interspersed = [
+        PanicVal::write_str("a"),
+        PanicVal::EMPTY,
+        PanicVal::EMPTY,
+        PanicVal::write_str("b"),
+        PanicVal::EMPTY,
+    ];
+    assert_eq!(check_empty_padding(&interspersed), Err(1));
+    assert_eq!(
+        check_empty_padding(&[PanicVal::EMPTY, PanicVal::write_str("b")]),
+        Err(0)
+    );
+}
+
 #[cfg(feature = "non_basic")]
 #[derive(Debug)]
 enum Qux<T> {
@@ -364,6 +591,206 @@ const_panic::inline_macro! {
     }
 }
 
+#[cfg(feature = "non_basic")]
+#[test]
+fn variant_args_test() {
+    use const_panic::{ArrayString, PanicVal};
+
+    #[derive(Debug)]
+    enum Event {
+        Idle,
+        Moved(u8, &'static str),
+        Resized { width: u16, height: u16 },
+    }
+
+    impl Event {
+        const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; 9] {
+            match *self {
+                Self::Idle => const_panic::flatten_panicvals!(f, 9; variant: Idle),
+                Self::Moved(x, y) => const_panic::flatten_panicvals!(f, 9; variant: Moved(x, y)),
+                Self::Resized { width, height } => const_panic::flatten_panicvals!(f, 9;
+                    variant: Resized { width, height: height },
+                ),
+            }
+        }
+    }
+
+    for val in [
+        Event::Idle,
+        Event::Moved(3, "up"),
+        Event::Resized {
+            width: 5,
+            height: 8,
+        },
+    ] {
+        for (fmtarg, expected) in [
+            (FmtArg::DEBUG, format!("{:?}", val)),
+            (FmtArg::ALT_DEBUG, format!("{:#?}", val)),
+        ] {
+            let pvs = val.to_panicvals(fmtarg);
+            assert_eq!(ArrayString::<99>::from_panicvals(&pvs).unwrap(), *expected);
+        }
+    }
+
+    // empty field lists are formatted like unit variants
+    assert_eq!(fmt_flatten!(FmtArg::DEBUG; variant: Foo()), "Foo");
+    assert_eq!(fmt_flatten!(FmtArg::DEBUG; variant: Foo {}), "Foo");
+
+    // variants can be followed by other arguments, and used in conditional groups
+    let flag = true;
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; variant: Foo(3u8 + 5,), "!", if flag => (variant: Bar)),
+        "Foo(8)!Bar"
+    );
+    assert_eq!(
+        fmt_flatten!(FmtArg::ALT_DEBUG; variant: Foo { x: "hi" }),
+        "Foo {\n    x: \"hi\",\n}"
+    );
+
+    // Display formatting can omit the type and field names
+    {
+        use const_panic::fmt::{self, StructStyle};
+
+        let no_names = FmtArg::DISPLAY.set_struct_style(StructStyle::NoFieldNames);
+        let values = FmtArg::DISPLAY.set_struct_style(StructStyle::ValuesOnly);
+        let (x, y) = (3u8, 5u8);
+
+        assert_eq!(
+            fmt_flatten!(no_names; variant: Foo { x, y }),
+            "Foo { 3, 5 }"
+        );
+        assert_eq!(fmt_flatten!(values; variant: Foo { x, y }), "{ 3, 5 }");
+        assert_eq!(fmt_flatten!(values; variant: Foo(x, y)), "(3, 5)");
+        assert_eq!(fmt_flatten!(values; variant: Foo), "Foo");
+        assert_eq!(
+            fmt_flatten!(no_names;
+                "Foo", open: fmt::OpenBrace, fields: { x, y }, close: fmt::CloseBrace
+            ),
+            "Foo { 3, 5 }"
+        );
+    }
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn fields_args_test() {
+    use const_panic::{
+        fmt::{self, ComputePvCount},
+        ArrayString, PanicFmt, PanicVal,
+    };
+
+    #[derive(Debug)]
+    struct Inner {
+        a: u8,
+    }
+
+    #[derive(Debug)]
+    struct Outer {
+        x: u32,
+        inner: Inner,
+        name: &'static str,
+    }
+
+    impl PanicFmt for Inner {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+
+        const PV_COUNT: usize = ComputePvCount {
+            field_amount: 1,
+            summed_pv_count: <u8>::PV_COUNT,
+            delimiter: fmt::TypeDelim::Braced,
+        }
+        .call();
+    }
+
+    impl Inner {
+        const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; Inner::PV_COUNT] {
+            const_panic::flatten_panicvals!(f;
+                "Inner",
+                open: fmt::OpenBrace,
+                fields: { a: self.a },
+                close: fmt::CloseBrace,
+            )
+        }
+    }
+
+    impl Outer {
+        const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; 17] {
+            let name = self.name;
+            const_panic::flatten_panicvals!(f, 17;
+                "Outer",
+                open: fmt::OpenBrace,
+                fields: {
+                    x: self.x,
+                    inner: Inner => self.inner,
+                    name,
+                },
+                close: fmt::CloseBrace,
+            )
+        }
+    }
+
+    let val = Outer {
+        x: 3,
+        inner: Inner { a: 5 },
+        name: "foo",
+    };
+    for (fmtarg, expected) in [
+        (FmtArg::DEBUG, format!("{:?}", val)),
+        (FmtArg::ALT_DEBUG, format!("{:#?}", val)),
+    ] {
+        let pvs = val.to_panicvals(fmtarg);
+        assert_eq!(ArrayString::<199>::from_panicvals(&pvs).unwrap(), *expected);
+    }
+
+    // fields can override the formatting, and be followed by other arguments.
+    // literals are Display formatted, like everywhere else in the macro.
+    let name = "foo";
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; fields: { x: hex: 255u8, y: name, z: "lit" }, "!"),
+        "x: FF, y: \"foo\", z: lit!"
+    );
+
+    // empty field lists write nothing
+    assert_eq!(fmt_flatten!(FmtArg::DEBUG; "a", fields: {}, "b"), "ab");
+
+    let flag = false;
+    assert_eq!(
+        fmt_flatten!(FmtArg::DEBUG; "a", if flag => (fields: { x: 3u8 }), "b"),
+        "ab"
+    );
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn first_line_indentation_test() {
+    use const_panic::PanicVal;
+
+    const ALT: FmtArg = FmtArg::ALT_DEBUG.indent();
+    assert_eq!(
+        fmt_flatten!(ALT; PanicVal::write_indentation(ALT), Qux<u8> => Qux::Down { x: 3, y: 5 }),
+        concat!(
+            "    Down {\n",
+            "        x: 3,\n",
+            "        y: 5,\n",
+            "    }",
+        )
+    );
+
+    const NON_ALT: FmtArg = FmtArg::DEBUG.indent().indent();
+    assert_eq!(
+        fmt_flatten!(NON_ALT; PanicVal::write_indentation(NON_ALT), Qux<u8> => Qux::Left(8)),
+        "        Left(8)"
+    );
+
+    assert_eq!(
+        fmt_flatten!(ALT; PanicVal::write_indentation(FmtArg::ALT_DEBUG), 3u8),
+        "3"
+    );
+
+    assert_eq!(trunc_fmt!(4; PanicVal::write_indentation(ALT)), "    ");
+}
+
 #[cfg(feature = "non_basic")]
 #[test]
 fn empty_delim_test() {
@@ -409,3 +836,87 @@ fn empty_delim_test() {
         );
     }
 }
+
+#[test]
+fn reference_forwarding_test() {
+    use const_panic::PanicFmt;
+
+    let mut num = 3u32;
+    let refref: &&u32 = &&5;
+
+    assert_eq!(trunc_fmt!(999; refref), "5");
+    assert_eq!(trunc_fmt!(999; &&&8u32), "8");
+    assert_eq!(trunc_fmt!(999; hex: &&255u8), "FF");
+    assert_eq!(trunc_fmt!(999; &&"foo"), r#""foo""#);
+    assert_eq!(trunc_fmt!(999; &mut num), "3");
+    assert_eq!(trunc_fmt!(999; &&mut num), "3");
+
+    assert_eq!(<&&u32 as PanicFmt>::PV_COUNT, <u32 as PanicFmt>::PV_COUNT);
+    assert_eq!(
+        <&mut &u32 as PanicFmt>::PV_COUNT,
+        <u32 as PanicFmt>::PV_COUNT
+    );
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn flatten_panicvals_fmtarg_array_test() {
+    use const_panic::{
+        concat_, flatten_panicvals,
+        fmt::{self, ComputePvCount},
+        PanicFmt, PanicVal,
+    };
+
+    struct Reg {
+        addr: u32,
+        value: u8,
+    }
+
+    impl PanicFmt for Reg {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+
+        const PV_COUNT: usize = ComputePvCount {
+            field_amount: 2,
+            summed_pv_count: <u32>::PV_COUNT + <u8>::PV_COUNT,
+            delimiter: fmt::TypeDelim::Braced,
+        }
+        .call();
+    }
+
+    // the formatting for each field, indexed by field position
+    const FIELD_FMTS: [FmtArg; 2] = [FmtArg::ALT_HEX, FmtArg::DEBUG];
+
+    impl Reg {
+        const fn to_panicvals(&self, fmtarg: FmtArg) -> [PanicVal<'static>; Reg::PV_COUNT] {
+            flatten_panicvals! {fmtarg;
+                "Reg",
+                open: fmt::OpenBrace,
+                    "addr: ", (FIELD_FMTS[0]): self.addr, fmt::COMMA_SEP,
+                    "value: ", (FIELD_FMTS[1]): self.value, fmt::COMMA_TERM,
+                close: fmt::CloseBrace,
+            }
+        }
+    }
+
+    let reg = Reg {
+        addr: 0x40,
+        value: 255,
+    };
+
+    assert_eq!(
+        trunc_fmt!(1024; reg.to_panicvals(FmtArg::DEBUG)),
+        "Reg { addr: 0x40, value: 255 }"
+    );
+    assert_eq!(
+        trunc_fmt!(1024; reg.to_panicvals(FmtArg::ALT_DEBUG)),
+        "Reg {\n    addr: 0x40,\n    value: 255,\n}"
+    );
+
+    // also works in the concatenation macros
+    const VALUE: u8 = 255;
+    assert_eq!(
+        concat_!(FmtArg::DEBUG; "value: ", (FIELD_FMTS[0]): VALUE),
+        "value: 0xFF"
+    );
+}
@@ -23,3 +23,50 @@ fn compute_pvcount_test() {
         );
     }
 }
+
+#[test]
+fn assert_pv_count_test() {
+    use const_panic::{assert_pv_count, FmtArg, PanicFmt, PanicVal};
+
+    struct Port(u16);
+
+    impl PanicFmt for Port {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+        const PV_COUNT: usize = 2;
+    }
+
+    impl Port {
+        const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'static>; 2] {
+            [PanicVal::write_str("port "), PanicVal::from_u16(self.0, f)]
+        }
+    }
+
+    struct Wrong;
+
+    impl PanicFmt for Wrong {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+        const PV_COUNT: usize = 3;
+    }
+
+    impl Wrong {
+        const fn to_panicvals(&self, _: FmtArg) -> [PanicVal<'static>; 1] {
+            [PanicVal::write_str("wrong")]
+        }
+    }
+
+    const _: () = assert_pv_count!(Port => Port(8080));
+    const _: () = assert_pv_count!(u8 => 3);
+    const _: () = assert_pv_count!(&str => "hello");
+    const _: () = assert_pv_count!(Option<u8> => Some(3));
+
+    assert_pv_count!(Port => Port(80));
+
+    let payload = std::panic::catch_unwind(|| assert_pv_count!(Wrong => Wrong)).unwrap_err();
+    let msg = payload.downcast::<String>().unwrap();
+    assert_eq!(
+        msg.trim_end_matches('\0'),
+        "\nthe `to_panicvals` method of `Wrong` returned 1 `PanicVal`s, but its `PV_COUNT` is 3\n"
+    );
+}
@@ -39,6 +39,201 @@ fn escaped_string() {
     }
 }
 
+// The `Debug` escape of an ASCII char in a string, written out instead of using std,
+// because std escaped NUL as `\u{0}` before Rust 1.61.
+fn ascii_str_escape(c: char) -> String {
+    match c {
+        '\0' => r"\0".to_string(),
+        '\t' => r"\t".to_string(),
+        '\n' => r"\n".to_string(),
+        '\r' => r"\r".to_string(),
+        '"' => r#"\""#.to_string(),
+        '\\' => r"\\".to_string(),
+        '\x00'..='\x1F' | '\x7F' => format!("\\u{{{:x}}}", c as u32),
+        _ => c.to_string(),
+    }
+}
+
+#[test]
+fn ascii_escapes_test() {
+    let all_ascii: String = (0u8..128).map(char::from).collect();
+
+    for c in all_ascii.chars() {
+        let string = &*format!("a{0}b{0}{0}", c);
+        let esc = ascii_str_escape(c);
+        assert_eq!(trunc_fmt!(MAX_L; string), *format!("\"a{0}b{0}{0}\"", esc));
+    }
+
+    let all_ascii = &*all_ascii;
+    assert_eq!(
+        trunc_fmt!(MAX_L; all_ascii),
+        concat!(
+            r#""\0\u{1}\u{2}\u{3}\u{4}\u{5}\u{6}\u{7}\u{8}\t\n\u{b}\u{c}\r\u{e}\u{f}"#,
+            r#"\u{10}\u{11}\u{12}\u{13}\u{14}\u{15}\u{16}\u{17}\u{18}\u{19}\u{1a}\u{1b}"#,
+            r##"\u{1c}\u{1d}\u{1e}\u{1f} !\"#$%&'()*+,-./0123456789:;<=>?@"##,
+            r#"ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~\u{7f}""#,
+        )
+    );
+    let nuls = "a\0b\0\0";
+    assert_eq!(trunc_fmt!(MAX_L; nuls), r#""a\0b\0\0""#);
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn shell_quote_test() {
+    use const_panic::FmtArg;
+
+    let fmt = FmtArg::DISPLAY.set_shell_quote(true);
+
+    for string in ["hello", "foo-bar_baz", "/tmp/a.txt", "key=val", "ñö个人"] {
+        assert_eq!(trunc_fmt!(MAX_L; fmt; string), string);
+    }
+
+    for string in [
+        "hello world",
+        "",
+        "tab\there",
+        "new\nline",
+        "a$b",
+        "\"quoted\"",
+        "it's",
+        "back\\slash",
+        "*.rs",
+        "a;b",
+    ] {
+        assert_eq!(trunc_fmt!(MAX_L; fmt; string), *format!("{:?}", string));
+    }
+
+    // the flag only affects Display formatting
+    let debug_fmt = FmtArg::DEBUG.set_shell_quote(true);
+    let (hello, hello_world) = ("hello", "hello world");
+    assert_eq!(trunc_fmt!(MAX_L; debug_fmt; hello), "\"hello\"");
+    assert_eq!(
+        trunc_fmt!(MAX_L; FmtArg::DISPLAY; hello_world),
+        "hello world"
+    );
+
+    // literals are always written as-is by the formatting macros
+    assert_eq!(trunc_fmt!(MAX_L; fmt; "hello world"), "hello world");
+
+    assert_eq!(
+        trunc_fmt!(MAX_L; fmt; ["ls", "-l", "my dir"]),
+        "[ls, -l, \"my dir\"]"
+    );
+    assert_eq!(fmt.pack().unpack(), fmt);
+}
+
+#[test]
+fn wrap_width_test() {
+    use const_panic::FmtArg;
+
+    let fmt = FmtArg::DISPLAY.set_wrap_width(Some(10));
+
+    for (string, expected) in [
+        ("", ""),
+        ("short", "short"),
+        ("exactly 10", "exactly 10"),
+        ("wrapped at spaces", "wrapped at\nspaces"),
+        ("one two three four", "one two\nthree four"),
+        ("breaking_long_words", "breaking_l\nong_words"),
+        ("a breaking_long_word", "a\nbreaking_l\nong_word"),
+        ("has\nnewlines\n", "has\nnewlines\n"),
+        (
+            "ñöñöñöñöñö ñ个人ñ个人ñ个人ñ个",
+            "ñöñöñöñöñö\nñ个人ñ个人ñ个人ñ\n个",
+        ),
+    ] {
+        assert_eq!(trunc_fmt!(MAX_L; fmt; string), expected, "{:?}", string);
+    }
+
+    let string = "wrapped at spaces";
+
+    // no wrapping by default, or with a width of 0
+    assert_eq!(trunc_fmt!(MAX_L; FmtArg::DISPLAY; string), string);
+    let zero_width = FmtArg::DISPLAY.set_wrap_width(Some(0));
+    assert_eq!(trunc_fmt!(MAX_L; zero_width; string), string);
+
+    // Debug formatting is not wrapped
+    let debug_fmt = FmtArg::DEBUG.set_wrap_width(Some(10));
+    assert_eq!(
+        trunc_fmt!(MAX_L; debug_fmt; string),
+        *format!("{:?}", string)
+    );
+
+    // literals are always written as-is by the formatting macros
+    assert_eq!(trunc_fmt!(MAX_L; fmt; "wrapped at spaces"), string);
+
+    // wrapped strings are truncated like the unwrapped ones
+    assert_eq!(trunc_fmt!(10; fmt; string), "wrapped at");
+    assert_eq!(trunc_fmt!(11; fmt; string), "wrapped at\n");
+    assert_eq!(trunc_fmt!(13; fmt; string), "wrapped at\nsp");
+    overf_fmt!(16; fmt; string).unwrap_err();
+    assert_eq!(overf_fmt!(17; fmt; string).unwrap(), "wrapped at\nspaces");
+
+    #[cfg(feature = "non_basic")]
+    assert_eq!(fmt.pack().unpack(), FmtArg::DISPLAY);
+
+    // the elements of slices aren't wrapped
+    #[cfg(feature = "non_basic")]
+    {
+        let strings = ["wrapped at spaces", "one two three four"];
+        assert_eq!(
+            trunc_fmt!(MAX_L; fmt; strings),
+            "[wrapped at spaces, one two three four]"
+        );
+        assert_eq!(
+            trunc_fmt!(MAX_L; fmt; &strings[..]),
+            "[wrapped at spaces, one two three four]"
+        );
+    }
+
+    // wrapped strings aren't padded
+    #[cfg(feature = "non_basic")]
+    {
+        let mut pad = FmtArg::DISPLAY;
+        pad.indentation = 2;
+        let wrapped = const_panic::PanicVal::from_str(string, fmt)
+            .with_leftpad(pad)
+            .with_rightpad(pad);
+        assert_eq!(
+            const_panic::ArrayString::<MAX_L>::from_panicvals(&[wrapped]).unwrap(),
+            "wrapped at\nspaces"
+        );
+    }
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn debug_escaped_len_test() {
+    use const_panic::utils::debug_escaped_len;
+
+    for string in [
+        "",
+        "hello",
+        "\\",
+        "\"\"\"",
+        "\t\n\r\'\"\\",
+        "\x00\x01\x1f\x7f",
+        "ñö个人\n",
+        ALL_ASCII,
+        r#"\u\u{}"#,
+    ] {
+        let len = debug_escaped_len(string);
+        assert_eq!(
+            trunc_fmt!(MAX_L; string).as_str().len(),
+            len,
+            "{:?}",
+            string
+        );
+        assert_eq!(overf_fmt!(len; string).unwrap().as_str().len(), len);
+        overf_fmt!(len - 1; string).unwrap_err();
+    }
+
+    assert_eq!(debug_escaped_len(""), 2);
+    assert_eq!(debug_escaped_len("\t\x00"), 6);
+    assert_eq!(debug_escaped_len("\x01\x1f"), 13);
+}
+
 // copied from const_format
 pub const ALL_ASCII: &str = "\
  \x00\x01\x02\x03\x04\x05\x06\x07\x08\t\n\x0b\x0c\r\x0e\x0f\x10\
@@ -47,20 +242,20 @@ pub const ALL_ASCII: &str = "\
  ^_`abcdefghijklmnopqrstuvwxyz{|}~\x7f\u{80}\u{81}\u{90}\u{91}\
 ";
 
-// copied from const_format
+// copied from const_format, updated to match std's escapes
 pub const ALL_ASCII_ESCAPED: &str = "\
  \"\
- \\x00\\x01\\x02\\x03\\x04\\x05\\x06\\x07\\x08\\t\\n\\x0B\\x0C\\r\\x0E\\x0F\
- \\x10\\x11\\x12\\x13\\x14\\x15\\x16\\x17\\x18\\x19\\x1A\\x1B\\x1C\\x1D\\x1E\\x1F \
- !\\\"#$%&\\\'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\\\]\
- ^_`abcdefghijklmnopqrstuvwxyz{|}~\x7f\u{80}\u{81}\u{90}\u{91}\
+ \\0\\u{1}\\u{2}\\u{3}\\u{4}\\u{5}\\u{6}\\u{7}\\u{8}\\t\\n\\u{b}\\u{c}\\r\\u{e}\\u{f}\
+ \\u{10}\\u{11}\\u{12}\\u{13}\\u{14}\\u{15}\\u{16}\\u{17}\\u{18}\\u{19}\\u{1a}\\u{1b}\\u{1c}\\u{1d}\\u{1e}\\u{1f} \
+ !\\\"#$%&\'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\\\]\
+ ^_`abcdefghijklmnopqrstuvwxyz{|}~\\u{7f}\u{80}\u{81}\u{90}\u{91}\
  \"\
 ";
 
 pub const DEBUG_AND_DISPLAY: &[(&str, &str)] = &[
-    (r#"\x00"#, "\x00"),
+    (r#"\0"#, "\x00"),
     (r#"\n"#, "\n"),
-    (r#"\x01"#, "\x01"),
+    (r#"\u{1}"#, "\x01"),
     (r#"\r"#, "\r"),
     (r#"ñ"#, "ñ"),
     (r#"ö"#, "ö"),
@@ -124,6 +319,64 @@ fn test_trunc_overflow() {
     }
 }
 
+#[test]
+fn truncation_boundary_test() {
+    let string = "ab\"人\n";
+
+    // Display: `ab"人\n` is 7 bytes
+    for (len, expected) in [
+        (0, ""),
+        (2, "ab"),
+        (3, "ab\""),
+        (4, "ab\""),
+        (5, "ab\""),
+        (6, "ab\"人"),
+        (7, "ab\"人\n"),
+        (8, "ab\"人\n"),
+    ] {
+        assert_eq!(trunc_fmt!(len; display: string), expected, "len: {}", len);
+        assert_eq!(overf_fmt!(len; display: string).is_ok(), len >= 7);
+    }
+
+    // Debug: `"ab\"人\n"` is 11 bytes,
+    // the closing quote is only written once all the content fits,
+    // so one byte less than the full length fits all of the content.
+    for (len, expected) in [
+        (0, ""),
+        (1, "\""),
+        (2, "\"a"),
+        (3, "\"ab"),
+        (4, "\"ab"),
+        (5, "\"ab\\\""),
+        (7, "\"ab\\\""),
+        (8, "\"ab\\\"人"),
+        (9, "\"ab\\\"人"),
+        (10, "\"ab\\\"人\\n"),
+        (11, "\"ab\\\"人\\n\""),
+        (12, "\"ab\\\"人\\n\""),
+    ] {
+        assert_eq!(trunc_fmt!(len; debug: string), expected, "len: {}", len);
+        assert_eq!(overf_fmt!(len; debug: string).is_ok(), len >= 11);
+    }
+    assert_eq!(trunc_fmt!(11; debug: string), *format!("{:?}", string));
+
+    // the capacity used by the preceding arguments is accounted for
+    for len in 0..16 {
+        assert_eq!(
+            trunc_fmt!(len + 3; "abc", debug: string),
+            *format!(
+                "abc{}",
+                std::str::from_utf8(trunc_fmt!(len; debug: string).get()).unwrap()
+            ),
+            "len: {}",
+            len,
+        );
+    }
+
+    assert_eq!(trunc_fmt!(1; debug: ""), "\"");
+    assert_eq!(trunc_fmt!(2; debug: ""), "\"\"");
+}
+
 #[test]
 fn test_fill_buffer() {
     let mut buffer = String::new();
@@ -194,3 +447,216 @@ fn push_if_fits(buffer: &mut String, char: &str, max_len: usize) -> DidFit {
         DidFit::No
     }
 }
+
+#[test]
+fn display_via_panicvals_test() {
+    use const_panic::{fmt::display_via_panicvals, FmtArg, PanicFmt, PanicVal};
+
+    use std::fmt::{self, Display};
+
+    struct Named<'a>(&'a str, u64);
+
+    impl PanicFmt for Named<'_> {
+        type This = Self;
+        type Kind = const_panic::IsCustomType;
+        const PV_COUNT: usize = 3;
+    }
+
+    impl<'a> Named<'a> {
+        const fn to_panicvals(&self, f: FmtArg) -> [PanicVal<'a>; 3] {
+            [
+                PanicVal::from_str(self.0, f),
+                PanicVal::write_str(" = "),
+                PanicVal::from_u64(self.1, f),
+            ]
+        }
+    }
+
+    impl Display for Named<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            display_via_panicvals(&self.to_panicvals(FmtArg::DEBUG), f)
+        }
+    }
+
+    assert_eq!(Named("foo", 100).to_string(), r#""foo" = 100"#);
+    assert_eq!(Named("", 0).to_string(), r#""" = 0"#);
+
+    // strings long enough to be written in multiple chunks,
+    // with multi-byte chars crossing the chunk boundaries
+    for prefix_len in 0..8 {
+        let prefix = "a".repeat(prefix_len);
+        let string = format!("{}{}", prefix, "ñ个人\n\x00".repeat(50));
+        assert_eq!(
+            Named(&string, u64::MAX).to_string(),
+            format!("\"{}{}\" = {}", prefix, r"ñ个人\n\0".repeat(50), u64::MAX),
+        );
+    }
+}
+
+#[test]
+fn ansi_style_test() {
+    use const_panic::{fmt, PanicVal};
+
+    let styles = [
+        (fmt::RESET, "\x1b[0m"),
+        (fmt::BOLD, "\x1b[1m"),
+        (fmt::DIM, "\x1b[2m"),
+        (fmt::UNDERLINE, "\x1b[4m"),
+        (fmt::RED, "\x1b[31m"),
+        (fmt::GREEN, "\x1b[32m"),
+        (fmt::YELLOW, "\x1b[33m"),
+        (fmt::BLUE, "\x1b[34m"),
+        (fmt::MAGENTA, "\x1b[35m"),
+        (fmt::CYAN, "\x1b[36m"),
+    ];
+
+    for (style, code) in styles {
+        let expected = if cfg!(feature = "style") { code } else { "" };
+        let msg = std::panic::catch_unwind(|| {
+            const_panic::concat_panic(&[&[style, PanicVal::write_str("text"), fmt::RESET]])
+        })
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+
+        let reset = if cfg!(feature = "style") {
+            "\x1b[0m"
+        } else {
+            ""
+        };
+        assert_eq!(
+            msg.trim_end_matches('\0'),
+            format!("{}text{}", expected, reset)
+        );
+    }
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn escape_control_in_display_test() {
+    use const_panic::FmtArg;
+
+    let fmt = FmtArg::DISPLAY.set_escape_control_in_display(true);
+
+    for (string, expected) in [
+        ("hello", "hello"),
+        ("", ""),
+        ("tab\there", "tab\\there"),
+        ("new\nline\r\n", "new\\nline\\r\\n"),
+        ("nul\0", "nul\\0"),
+        ("\x1b[0m\x7F", "\\u{1b}[0m\\u{7f}"),
+        ("\"quoted\" back\\slash", "\"quoted\" back\\slash"),
+        ("ñö个人\n", "ñö个人\\n"),
+    ] {
+        assert_eq!(trunc_fmt!(MAX_L; fmt; string), expected, "{:?}", string);
+    }
+
+    let string = "ab\ncd\x01";
+    assert_eq!(trunc_fmt!(2; fmt; string), "ab");
+    assert_eq!(trunc_fmt!(3; fmt; string), "ab");
+    assert_eq!(trunc_fmt!(4; fmt; string), "ab\\n");
+    assert_eq!(trunc_fmt!(6; fmt; string), "ab\\ncd");
+    assert_eq!(trunc_fmt!(10; fmt; string), "ab\\ncd");
+    assert_eq!(trunc_fmt!(11; fmt; string), "ab\\ncd\\u{1}");
+
+    // the flag only affects Display formatting
+    let debug_fmt = FmtArg::DEBUG.set_escape_control_in_display(true);
+    assert_eq!(
+        trunc_fmt!(MAX_L; debug_fmt; string),
+        *format!("{:?}", string)
+    );
+    assert_eq!(trunc_fmt!(MAX_L; FmtArg::DISPLAY; string), string);
+
+    // literals are always written as-is by the formatting macros
+    assert_eq!(trunc_fmt!(MAX_L; fmt; "a\nb"), "a\nb");
+
+    assert_eq!(fmt.pack().unpack(), fmt);
+}
+
+#[cfg(feature = "non_basic")]
+#[test]
+fn single_quotes_test() {
+    use const_panic::FmtArg;
+
+    let fmt = FmtArg::DEBUG.set_single_quotes(true);
+
+    for (string, expected) in [
+        ("hello", "'hello'"),
+        ("", "''"),
+        ("it's", "'it\\'s'"),
+        ("\"quoted\"", "'\"quoted\"'"),
+        ("tab\t\\", "'tab\\t\\\\'"),
+    ] {
+        assert_eq!(trunc_fmt!(MAX_L; fmt; string), expected, "{:?}", string);
+    }
+
+    // double quotes stay the default
+    let string = "it's \"";
+    assert_eq!(
+        trunc_fmt!(MAX_L; FmtArg::DEBUG; string),
+        *format!("{:?}", string)
+    );
+
+    let string = "a'b";
+    assert_eq!(trunc_fmt!(2; fmt; string), "'a");
+    assert_eq!(trunc_fmt!(3; fmt; string), "'a");
+    assert_eq!(trunc_fmt!(4; fmt; string), "'a\\'");
+    assert_eq!(trunc_fmt!(5; fmt; string), "'a\\'b");
+    assert_eq!(trunc_fmt!(6; fmt; string), "'a\\'b'");
+
+    // the flag only affects Debug formatting
+    let display_fmt = FmtArg::DISPLAY.set_single_quotes(true);
+    assert_eq!(trunc_fmt!(MAX_L; display_fmt; string), string);
+
+    let strings: &[&str] = &["a", "b'"];
+    assert_eq!(trunc_fmt!(MAX_L; fmt; strings), "['a', 'b\\'']");
+
+    assert_eq!(fmt.pack().unpack(), fmt);
+}
+
+#[test]
+fn write_bytes_unchecked_test() {
+    use const_panic::PanicVal;
+
+    for (bytes, expected) in [
+        (&b""[..], ""),
+        (b"hello\n\"world\"", "hello\n\"world\""),
+        ("\u{3B1}\u{1F600}".as_bytes(), "\u{3B1}\u{1F600}"),
+    ] {
+        // SAFETY: all the byte strings are valid UTF-8
+        let pv = unsafe { PanicVal::write_bytes_unchecked(bytes) };
+
+        // written verbatim, without escaping, regardless of the FmtArg
+        assert_eq!(trunc_fmt!(1024; pv), expected);
+        assert_eq!(trunc_fmt!(1024; const_panic::FmtArg::DEBUG; pv), expected);
+    }
+}
+
+#[test]
+fn force_debug_display_test() {
+    use const_panic::{FmtArg, PanicVal};
+
+    let displayed = PanicVal::from_str("a\nb", FmtArg::DISPLAY).force_debug();
+    assert_eq!(trunc_fmt!(MAX_L; displayed), r#""a\nb""#);
+
+    let debugged = PanicVal::from_str("a\nb", FmtArg::DEBUG).force_display();
+    assert_eq!(trunc_fmt!(MAX_L; debugged), "a\nb");
+
+    // the padding is kept
+    let padded = PanicVal::from_str("a", FmtArg::DISPLAY)
+        .with_leftpad(FmtArg::DISPLAY.set_indentation(2))
+        .force_debug();
+    assert_eq!(trunc_fmt!(MAX_L; padded), r#"  "a""#);
+
+    // non-strings are unaffected
+    let int = PanicVal::from_u8(10, FmtArg::HEX).force_debug();
+    assert_eq!(trunc_fmt!(MAX_L; int), "A");
+
+    #[cfg(feature = "non_basic")]
+    {
+        use const_panic::fmt::ShortString;
+
+        let short = PanicVal::write_short_str(ShortString::new("c'd")).force_debug();
+        assert_eq!(trunc_fmt!(MAX_L; short), r#""c'd""#);
+    }
+}